@@ -0,0 +1,182 @@
+use chrono::{DateTime, Utc};
+
+use crate::{json, ValidationFailures, IVMS101};
+
+/// A payload together with the history of the mutations made through
+/// [`Audited::edit`], e.g. to show how a payload evolved from the first
+/// draft to what was transmitted.
+///
+/// The history records hashes of the changed values instead of the
+/// values themselves. The hashes are unkeyed, so short values such as
+/// names can be recovered by brute force: retain the history like the
+/// personal data it derives from. It is not part of the payload:
+/// [`Audited::into_inner`] returns the payload alone for transmission.
+///
+/// ```
+/// use ivms101::{Audited, IVMS101};
+///
+/// let json = r#"{"originator":{"originatorPersons":{"naturalPerson":{"name":{"nameIdentifier":{"primaryIdentifier":"Doe","secondaryIdentifier":"John","nameIdentifierType":"LEGL"}},"customerIdentification":"customer-a"}}}}"#;
+/// let mut audited = Audited::new(IVMS101::from_json(json).unwrap());
+/// audited
+///     .edit(|ivms| {
+///         let mut originator = ivms.originator().unwrap().clone();
+///         originator.add_account_number("account-1").unwrap();
+///         ivms.set_originator(originator);
+///     })
+///     .unwrap();
+/// assert_eq!(audited.history()[0].path, "originator.accountNumber[0]");
+/// let ivms = audited.into_inner();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Audited<T> {
+    inner: T,
+    history: Vec<AuditEntry>,
+}
+
+/// A change of a single value recorded by [`Audited`].
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntry {
+    /// When the change was made.
+    pub timestamp: DateTime<Utc>,
+    /// The field path of the value as in [`IVMS101::flatten`].
+    pub path: String,
+    /// The hash of the previous value, `None` if the value was added.
+    pub old_hash: Option<u64>,
+    /// The hash of the new value, `None` if the value was removed.
+    pub new_hash: Option<u64>,
+}
+
+impl<T> Audited<T> {
+    /// Starts recording the mutations of `inner`.
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            history: Vec::new(),
+        }
+    }
+
+    /// The current payload.
+    #[must_use]
+    pub fn get(&self) -> &T {
+        &self.inner
+    }
+
+    /// The recorded changes in the order they were made.
+    #[must_use]
+    pub fn history(&self) -> &[AuditEntry] {
+        &self.history
+    }
+
+    /// Returns the payload, dropping the history.
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl Audited<IVMS101> {
+    /// Edits the payload like [`IVMS101::edit`] and records every changed
+    /// value with the hashes of its previous and new value, ordered by
+    /// field path. Rolled back edits are not recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns the failures of [`IVMS101::edit`], in which case the payload
+    /// is left unchanged.
+    pub fn edit(&mut self, f: impl FnOnce(&mut IVMS101)) -> Result<(), ValidationFailures> {
+        let before = self.inner.flatten();
+        self.inner.edit(f)?;
+        let mut after = self.inner.flatten();
+        let timestamp = Utc::now();
+        let hash = |value: &String| json::fnv1a(value.as_bytes());
+
+        let mut changes = Vec::new();
+        for (path, old) in before {
+            let new = after.remove(&path);
+            if new.as_ref() != Some(&old) {
+                changes.push((path, Some(hash(&old)), new.as_ref().map(hash)));
+            }
+        }
+        changes.extend(
+            after
+                .into_iter()
+                .map(|(path, new)| (path, None, Some(hash(&new)))),
+        );
+        changes.sort_by(|a, b| a.0.cmp(&b.0));
+
+        self.history.extend(
+            changes
+                .into_iter()
+                .map(|(path, old_hash, new_hash)| AuditEntry {
+                    timestamp,
+                    path,
+                    old_hash,
+                    new_hash,
+                }),
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ZeroToN;
+
+    #[test]
+    fn test_history() {
+        let mut audited = Audited::new(IVMS101::mock());
+        audited
+            .edit(|ivms| {
+                let originator = ivms.originator.as_mut().unwrap();
+                originator.account_number = Some("account-3".try_into().unwrap()).into();
+            })
+            .unwrap();
+        audited
+            .edit(|ivms| {
+                ivms.originator_person_mut().customer_identification =
+                    Some("customer-a".try_into().unwrap());
+            })
+            .unwrap();
+        // Rolled back edits are not recorded.
+        let before = audited.get().clone();
+        audited
+            .edit(|ivms| {
+                let person = ivms.originator_person_mut();
+                person.geographic_address = ZeroToN::None;
+                person.customer_identification = None;
+            })
+            .unwrap_err();
+        assert_eq!(audited.get(), &before);
+        audited
+            .edit(|ivms| ivms.beneficiary.as_mut().unwrap().account_number = None.into())
+            .unwrap();
+
+        let history = audited.history();
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].path, "originator.accountNumber[0]");
+        assert_eq!(
+            history[1].path,
+            "originator.originatorPersons[0].naturalPerson.customerIdentification"
+        );
+        assert_eq!(history[2].path, "beneficiary.accountNumber[0]");
+        assert!(history
+            .windows(2)
+            .all(|entries| entries[0].timestamp <= entries[1].timestamp));
+
+        assert_eq!(
+            history[0].old_hash,
+            Some(json::fnv1a("account-1".as_bytes()))
+        );
+        assert_eq!(
+            history[0].new_hash,
+            Some(json::fnv1a("account-3".as_bytes()))
+        );
+        assert!(history[1].old_hash.is_none() && history[1].new_hash.is_some());
+        assert!(history[2].old_hash.is_some() && history[2].new_hash.is_none());
+
+        let json = audited.into_inner().to_json();
+        assert!(!json.contains("history"));
+    }
+}
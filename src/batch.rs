@@ -0,0 +1,142 @@
+use crate::{Beneficiary, OriginatingVASP, Originator, Validatable, ValidationFailures, IVMS101};
+
+/// Builds the payloads of a batch of transfers from the same originator
+/// and originating VASP to several beneficiaries, one payload per
+/// beneficiary.
+///
+/// The shared sections are cloned into every payload, so changes to one
+/// payload never affect the others.
+///
+/// ```
+/// use ivms101::{Beneficiary, BatchBuilder, NaturalPerson, OriginatingVASP, Originator};
+///
+/// let lei = lei::LEI::try_from("2594007XIACKNMUAW223").unwrap();
+/// let originator = NaturalPerson::new("Friedrich", "Engels", Some("customer-a"), None).unwrap();
+/// let beneficiary = NaturalPerson::new("Karl", "Marx", None, None).unwrap();
+/// let payloads = BatchBuilder::new(
+///     Originator::new(originator.into()).unwrap(),
+///     OriginatingVASP::new("VASP A", &lei).unwrap(),
+/// )
+/// .beneficiary(Beneficiary::new(beneficiary.into(), Some("wallet-1")).unwrap())
+/// .build()
+/// .unwrap();
+/// assert_eq!(payloads.len(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct BatchBuilder {
+    originator: Originator,
+    originating_vasp: OriginatingVASP,
+    beneficiaries: Vec<Beneficiary>,
+}
+
+impl BatchBuilder {
+    /// Constructs a `BatchBuilder` with the sections shared by all
+    /// payloads of the batch.
+    #[must_use]
+    pub fn new(originator: Originator, originating_vasp: OriginatingVASP) -> Self {
+        Self {
+            originator,
+            originating_vasp,
+            beneficiaries: Vec::new(),
+        }
+    }
+
+    /// Adds a beneficiary, along with its account number, to the batch.
+    #[must_use]
+    pub fn beneficiary(mut self, beneficiary: Beneficiary) -> Self {
+        self.beneficiaries.push(beneficiary);
+        self
+    }
+
+    /// Validates the payloads of the batch independently of each other.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Validatable::validate_all`] for every
+    /// invalid payload, along with the index of its beneficiary.
+    pub fn validate_batch(&self) -> Result<(), Vec<(usize, ValidationFailures)>> {
+        self.payloads().map(drop)
+    }
+
+    /// Builds and validates the payloads of the batch, in the order the
+    /// beneficiaries were added.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`BatchBuilder::validate_batch`].
+    pub fn build(&self) -> Result<Vec<IVMS101>, Vec<(usize, ValidationFailures)>> {
+        self.payloads()
+    }
+
+    fn payloads(&self) -> Result<Vec<IVMS101>, Vec<(usize, ValidationFailures)>> {
+        let mut payloads = Vec::with_capacity(self.beneficiaries.len());
+        let mut failures = Vec::new();
+        for (i, beneficiary) in self.beneficiaries.iter().enumerate() {
+            let payload = IVMS101 {
+                originator: Some(Box::new(self.originator.clone())),
+                beneficiary: Some(Box::new(beneficiary.clone())),
+                originating_vasp: Some(Box::new(self.originating_vasp.clone())),
+                beneficiary_vasp: None,
+            };
+            match payload.validate_all() {
+                Ok(()) => payloads.push(payload),
+                Err(e) => failures.push((i, e)),
+            }
+        }
+        if failures.is_empty() {
+            Ok(payloads)
+        } else {
+            Err(failures)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LegalPerson;
+
+    #[test]
+    fn test_batch() {
+        let ivms = IVMS101::mock();
+        let new_builder = || {
+            BatchBuilder::new(
+                ivms.originator().unwrap().clone(),
+                ivms.originating_vasp().unwrap().clone(),
+            )
+        };
+        let beneficiary = |account: &str| {
+            let mut beneficiary = ivms.beneficiary().unwrap().clone();
+            beneficiary.account_number = Some(account.try_into().unwrap()).into();
+            beneficiary
+        };
+
+        // A legal person needs an address or an identification (C4).
+        let invalid = Beneficiary::new(LegalPerson::mock().into(), Some("account-3")).unwrap();
+        let builder = new_builder()
+            .beneficiary(beneficiary("account-2"))
+            .beneficiary(invalid)
+            .beneficiary(beneficiary("account-4"));
+        let failures = builder.validate_batch().unwrap_err();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0, 1);
+        assert_eq!(failures[0].1[0].constraint(), Some("C4"));
+        assert_eq!(
+            failures[0].1[0].path(),
+            Some("beneficiary.beneficiaryPersons[0]")
+        );
+        assert_eq!(builder.build().unwrap_err(), failures);
+
+        let builder = new_builder()
+            .beneficiary(beneficiary("account-2"))
+            .beneficiary(beneficiary("account-3"));
+        builder.validate_batch().unwrap();
+        let payloads = builder.build().unwrap();
+        assert_eq!(payloads.len(), 2);
+        assert_eq!(payloads[0].originator, payloads[1].originator);
+        assert_eq!(
+            payloads[1].beneficiary.as_deref(),
+            Some(&beneficiary("account-3"))
+        );
+    }
+}
@@ -0,0 +1,151 @@
+//! Standalone checks of individual IVMS101 constraints, e.g. to ask whether
+//! an address is complete without building a whole person.
+//!
+//! The [`crate::Validatable`] implementations and the parsing of country
+//! codes use the same checks, while [`c10_valid_ra`] applies the parsing of
+//! registration authorities. The functions are named after the constraints
+//! of the IVMS101 standard:
+//!
+//! | Function                 | Constraint                |
+//! |--------------------------|---------------------------|
+//! | [`c2_birthdate_in_past`] | C2 DateInPast             |
+//! | [`c3_valid_country`]     | C3 ValidCountryCode       |
+//! | [`c8_address_complete`]  | C8 ValidAddress           |
+//! | [`c10_valid_ra`]         | C10 RegistrationAuthority |
+//! | [`c11_valid_lei`]        | C11 ValidLEI              |
+
+use chrono::NaiveDate;
+
+use crate::{country, registration_authorities, validation, Address, Error};
+
+/// Checks that a date of birth lies before `as_of`, usually today.
+///
+/// # Errors
+///
+/// Returns an [`Error::ValidationError`] if the date is not in the past.
+pub fn c2_birthdate_in_past(date_of_birth: NaiveDate, as_of: NaiveDate) -> Result<(), Error> {
+    validation::ensure(
+        "C2",
+        date_of_birth < as_of,
+        "Date of birth must be in the past",
+    )
+}
+
+/// Checks that `code` is an ISO 3166-1 alpha-2 country code, ignoring
+/// case. The placeholder `XX` and the supranational code `EU` are
+/// accepted as well.
+///
+/// # Errors
+///
+/// Returns an [`Error::InvalidCountryCode`] for unknown codes.
+pub fn c3_valid_country(code: &str) -> Result<(), Error> {
+    if country(code).is_none() {
+        return Err(Error::InvalidCountryCode(code.to_string()));
+    }
+    Ok(())
+}
+
+/// Checks that an address either has an address line or a street name
+/// along with a building name or number.
+///
+/// This applies C8 as written, regardless of
+/// [`crate::ValidationOptions::allow_post_box_only`].
+///
+/// # Errors
+///
+/// Returns an [`Error::ValidationError`] for incomplete addresses.
+pub fn c8_address_complete(address: &Address) -> Result<(), Error> {
+    validation::ensure(
+        "C8",
+        !address.address_line.is_empty()
+            || (address.street_name.is_some()
+                && (address.building_name.is_some() || address.building_number.is_some())),
+        "Either 1) address line or 2) street name and either building name or building number are required",
+    )
+}
+
+/// Checks that `ra` is a registration authority code on the GLEIF list,
+/// ignoring case.
+///
+/// # Errors
+///
+/// Returns an [`Error::ValidationError`] for malformed or unknown codes.
+pub fn c10_valid_ra(ra: &str) -> Result<(), Error> {
+    registration_authorities::parse(ra).map(drop)
+}
+
+/// Checks that `lei` is a valid LEI, including its check digits.
+///
+/// # Errors
+///
+/// Returns an [`Error::ValidationError`] for invalid LEIs.
+pub fn c11_valid_lei(lei: &str) -> Result<(), Error> {
+    validation::traced(
+        "C11",
+        lei::LEI::try_from(lei).map(drop).map_err(Error::from),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_c2_birthdate_in_past() {
+        let today = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+        c2_birthdate_in_past(NaiveDate::from_ymd_opt(1946, 11, 5).unwrap(), today).unwrap();
+        let err = c2_birthdate_in_past(today, today).unwrap_err();
+        assert_eq!(err.constraint(), Some("C2"));
+    }
+
+    #[test]
+    fn test_c3_valid_country() {
+        c3_valid_country("CH").unwrap();
+        c3_valid_country("ch").unwrap();
+        assert_eq!(
+            c3_valid_country("ZZ"),
+            Err(Error::InvalidCountryCode("ZZ".into()))
+        );
+    }
+
+    #[test]
+    fn test_c8_address_complete() {
+        let mut address = Address::mock();
+        c8_address_complete(&address).unwrap();
+        address.address_line = None.into();
+        assert_eq!(
+            c8_address_complete(&address).unwrap_err().constraint(),
+            Some("C8")
+        );
+        address.street_name = Some("Main street".try_into().unwrap());
+        address.building_number = Some("1".try_into().unwrap());
+        c8_address_complete(&address).unwrap();
+    }
+
+    #[test]
+    fn test_c10_valid_ra() {
+        c10_valid_ra("RA000001").unwrap();
+        c10_valid_ra("ra000001").unwrap();
+        assert_eq!(c10_valid_ra("RA").unwrap_err().constraint(), Some("C10"));
+        for malformed in ["RA00000A", "RB000001", "RA0000001", "RÄ00001"] {
+            let err = c10_valid_ra(malformed).unwrap_err();
+            assert!(err
+                .to_string()
+                .contains("must consist of RA and six digits"));
+        }
+        let err = c10_valid_ra("RA999999").unwrap_err();
+        assert!(err.to_string().contains("Invalid registration authority"));
+        assert_eq!(err.constraint(), Some("C10"));
+    }
+
+    #[test]
+    fn test_c11_valid_lei() {
+        c11_valid_lei("2594007XIACKNMUAW223").unwrap();
+        assert_eq!(
+            c11_valid_lei("2594007XIACKNMUAW224")
+                .unwrap_err()
+                .constraint(),
+            Some("C11")
+        );
+    }
+}
@@ -0,0 +1,319 @@
+use std::fmt;
+
+use crate::{
+    Error, LegalPerson, LegalPersonName, LegalPersonNameID, LegalPersonNameTypeCode, NaturalPerson,
+    NaturalPersonName, NaturalPersonNameID, NaturalPersonNameTypeCode, OneToN, Validatable,
+    ZeroToN,
+};
+
+/// The order of the names within a legal person name, which determines
+/// how it is split into the primary and secondary identifier of a
+/// natural person name, see [`LegalPerson::try_into_natural`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NameOrder {
+    /// The family name comes last, e.g. "Friedrich Engels". The last word
+    /// becomes the primary identifier.
+    GivenNameFirst,
+    /// The family name comes first, e.g. "Mao Zedong". The first word
+    /// becomes the primary identifier.
+    FamilyNameFirst,
+}
+
+/// The reasons why a person cannot be converted to the other kind of
+/// person without losing data or violating constraints.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConversionLoss {
+    /// The fields that the target kind of person cannot hold.
+    pub dropped: Vec<&'static str>,
+    /// The errors constructing or validating the converted person.
+    pub errors: Vec<Error>,
+}
+
+impl fmt::Display for ConversionLoss {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Conversion failed")?;
+        if !self.dropped.is_empty() {
+            write!(f, ", dropping {}", self.dropped.join(", "))?;
+        }
+        for error in &self.errors {
+            write!(f, "; {error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConversionLoss {}
+
+impl From<ConversionLoss> for Error {
+    /// Reports the dropped fields and the errors as one validation error.
+    fn from(loss: ConversionLoss) -> Self {
+        Self::ValidationError(loss.to_string())
+    }
+}
+
+impl ConversionLoss {
+    /// Fails if anything was dropped or `person` does not validate.
+    fn check<T: Validatable>(mut self, person: T) -> Result<T, Self> {
+        if let Err(errors) = person.validate_all() {
+            self.errors.extend(Vec::from(errors));
+        }
+        if self.dropped.is_empty() && self.errors.is_empty() {
+            Ok(person)
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl LegalPerson {
+    /// Converts a legal person to a natural person, e.g. a sole
+    /// proprietorship that was misclassified.
+    ///
+    /// The fields are mapped as follows:
+    ///
+    /// - Each name is split according to `order` into the primary and
+    ///   secondary identifier. Legal names (`LEGL`) stay legal names,
+    ///   short (`SHRT`) and trading (`TRAD`) names become aliases (`ALIA`).
+    /// - The addresses, customer identification and national
+    ///   identification are carried over.
+    /// - The country of registration becomes the country of residence.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConversionLoss`] if the natural person fails
+    /// validation.
+    pub fn try_into_natural(self, order: NameOrder) -> Result<NaturalPerson, ConversionLoss> {
+        let loss = ConversionLoss {
+            dropped: Vec::new(),
+            errors: Vec::new(),
+        };
+        let name = &self.name;
+        let natural = NaturalPerson {
+            name: NaturalPersonName {
+                name_identifier: OneToN::N(
+                    name.name_identifier
+                        .iter()
+                        .map(|id| to_natural_name(id, order))
+                        .collect::<Vec<_>>()
+                        .try_into()
+                        .expect("names are not empty"),
+                )
+                .normalize(),
+                local_name_identifier: ZeroToN::from(
+                    name.local_name_identifier
+                        .iter()
+                        .map(|id| to_natural_name(id, order))
+                        .collect::<Vec<_>>(),
+                )
+                .normalize(),
+                phonetic_name_identifier: ZeroToN::from(
+                    name.phonetic_name_identifier
+                        .iter()
+                        .map(|id| to_natural_name(id, order))
+                        .collect::<Vec<_>>(),
+                )
+                .normalize(),
+            }
+            .into(),
+            geographic_address: self.geographic_address,
+            national_identification: self.national_identification,
+            customer_identification: self.customer_identification,
+            date_and_place_of_birth: None,
+            country_of_residence: self.country_of_registration,
+        };
+        loss.check(natural)
+    }
+}
+
+impl NaturalPerson {
+    /// Converts a natural person to a legal person, e.g. a company that
+    /// was misclassified.
+    ///
+    /// The fields are mapped as follows:
+    ///
+    /// - The identifiers of all names become names consisting of the
+    ///   secondary identifier followed by the primary identifier. Legal
+    ///   names (`LEGL`) stay legal names, the others become trading names
+    ///   (`TRAD`).
+    /// - The addresses and customer identification are carried over.
+    /// - The national identification is carried over if its type is
+    ///   allowed for legal persons by C7, and dropped otherwise.
+    /// - The country of residence becomes the country of registration.
+    /// - The date and place of birth is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConversionLoss`] if any data is dropped, a name
+    /// exceeds 100 bytes or the legal person fails validation.
+    pub fn try_into_legal(self) -> Result<LegalPerson, ConversionLoss> {
+        let mut loss = ConversionLoss {
+            dropped: Vec::new(),
+            errors: Vec::new(),
+        };
+        let mut convert = |ids: Vec<&NaturalPersonNameID>| -> Vec<LegalPersonNameID> {
+            ids.into_iter()
+                .filter_map(|id| to_legal_name(id).map_err(|e| loss.errors.push(e)).ok())
+                .collect()
+        };
+        let name_identifier = convert(
+            self.name
+                .iter()
+                .flat_map(|name| name.name_identifier.iter())
+                .collect(),
+        );
+        let local_name_identifier = convert(
+            self.name
+                .iter()
+                .flat_map(|name| name.local_name_identifier.iter())
+                .collect(),
+        );
+        let phonetic_name_identifier = convert(
+            self.name
+                .iter()
+                .flat_map(|name| name.phonetic_name_identifier.iter())
+                .collect(),
+        );
+        let Ok(name_identifier) = name_identifier.try_into() else {
+            return Err(loss);
+        };
+
+        let national_identification = self.national_identification.filter(|id| {
+            let allowed = id.is_legal_person_type();
+            if !allowed {
+                loss.dropped.push("nationalIdentification");
+            }
+            allowed
+        });
+        if self.date_and_place_of_birth.is_some() {
+            loss.dropped.push("dateAndPlaceOfBirth");
+        }
+        let legal = LegalPerson {
+            name: LegalPersonName {
+                name_identifier: OneToN::N(name_identifier).normalize(),
+                local_name_identifier: ZeroToN::from(local_name_identifier).normalize(),
+                phonetic_name_identifier: ZeroToN::from(phonetic_name_identifier).normalize(),
+            },
+            geographic_address: self.geographic_address,
+            customer_identification: self.customer_identification,
+            national_identification,
+            country_of_registration: self.country_of_residence,
+        };
+        loss.check(legal)
+    }
+}
+
+fn to_natural_name(id: &LegalPersonNameID, order: NameOrder) -> NaturalPersonNameID {
+    let name = id.legal_person_name.as_str().trim();
+    let split = match order {
+        NameOrder::GivenNameFirst => name.rsplit_once(' ').map(|(given, family)| (family, given)),
+        NameOrder::FamilyNameFirst => name.split_once(' '),
+    };
+    let (primary, secondary) = match split {
+        Some((primary, secondary)) => (primary.trim(), Some(secondary.trim())),
+        None => (name, None),
+    };
+    let name_identifier_type = match id.legal_person_name_identifier_type {
+        LegalPersonNameTypeCode::Legal => NaturalPersonNameTypeCode::LegalName,
+        LegalPersonNameTypeCode::Short | LegalPersonNameTypeCode::Trading => {
+            NaturalPersonNameTypeCode::Alias
+        }
+    };
+    NaturalPersonNameID::new(primary, secondary, name_identifier_type)
+        .expect("parts of a legal person name do not exceed its length")
+}
+
+fn to_legal_name(id: &NaturalPersonNameID) -> Result<LegalPersonNameID, Error> {
+    let name = match &id.secondary_identifier {
+        Some(secondary) => format!("{} {}", secondary.as_str(), id.primary_identifier.as_str()),
+        None => id.primary_identifier.as_str().to_owned(),
+    };
+    let legal_person_name_identifier_type = match id.name_identifier_type {
+        NaturalPersonNameTypeCode::LegalName => LegalPersonNameTypeCode::Legal,
+        _ => LegalPersonNameTypeCode::Trading,
+    };
+    LegalPersonNameID::new(name.trim(), legal_person_name_identifier_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Address, NationalIdentification, NationalIdentifierTypeCode};
+
+    #[test]
+    fn test_try_into_natural() {
+        let mut legal = LegalPerson::mock();
+        legal.name.name_identifier = LegalPersonNameID::legal_name("Friedrich Engels")
+            .unwrap()
+            .into();
+        legal.name.local_name_identifier =
+            Some(LegalPersonNameID::trading_name("Fred").unwrap()).into();
+        legal.geographic_address = Some(Address::mock()).into();
+        legal.country_of_registration = Some("CH".try_into().unwrap());
+
+        let natural = legal
+            .clone()
+            .try_into_natural(NameOrder::GivenNameFirst)
+            .unwrap();
+        let name = natural.name.as_single().unwrap();
+        assert_eq!(
+            name.name_identifier,
+            OneToN::One(NaturalPersonNameID::mock())
+        );
+        assert_eq!(
+            name.local_name_identifier,
+            ZeroToN::One(NaturalPersonNameID::alias("Fred", None).unwrap())
+        );
+        assert_eq!(natural.geographic_address, legal.geographic_address);
+        assert_eq!(natural.country_of_residence, legal.country_of_registration);
+
+        let natural = legal
+            .clone()
+            .try_into_natural(NameOrder::FamilyNameFirst)
+            .unwrap();
+        let id = natural.name.first().name_identifier.first();
+        assert_eq!(id.primary_identifier.as_str(), "Friedrich");
+        assert_eq!(id.secondary_identifier.as_ref().unwrap().as_str(), "Engels");
+
+        // Converting back yields the original person.
+        let natural = legal
+            .clone()
+            .try_into_natural(NameOrder::GivenNameFirst)
+            .unwrap();
+        assert_eq!(natural.try_into_legal().unwrap(), legal);
+    }
+
+    #[test]
+    fn test_try_into_legal() {
+        let mut natural = NaturalPerson::mock();
+        natural.customer_identification = Some("customer-a".try_into().unwrap());
+        let legal = natural.clone().try_into_legal().unwrap();
+        assert_eq!(
+            legal.name.name_identifier,
+            OneToN::One(LegalPersonNameID::legal_name("Friedrich Engels").unwrap())
+        );
+        assert_eq!(
+            legal.customer_identification,
+            natural.customer_identification
+        );
+
+        let mut passport = NationalIdentification::mock();
+        passport.national_identifier_type = NationalIdentifierTypeCode::PassportNumber;
+        natural.national_identification = Some(passport);
+        let loss = natural.try_into_legal().unwrap_err();
+        assert_eq!(loss.dropped, ["nationalIdentification"]);
+        assert!(loss.errors.is_empty());
+        assert_eq!(
+            loss.to_string(),
+            "Conversion failed, dropping nationalIdentification"
+        );
+    }
+
+    #[test]
+    fn test_try_into_legal_invalid() {
+        // Without customer identification, C4 fails.
+        let loss = NaturalPerson::mock().try_into_legal().unwrap_err();
+        assert!(loss.dropped.is_empty());
+        assert_eq!(loss.errors.len(), 1);
+        assert_eq!(loss.errors[0].constraint(), Some("C4"));
+    }
+}
@@ -1,6 +1,9 @@
 /// A ISO 3166-1 Alpha-2 country code.
-#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
-#[serde(try_from = "&str")]
+///
+/// The code is stored in uppercase regardless of the case it
+/// was constructed from.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Deserialize)]
+#[serde(try_from = "String")]
 pub struct CountryCode {
     inner: String,
 }
@@ -14,10 +17,32 @@ impl serde::Serialize for CountryCode {
 impl TryFrom<&str> for CountryCode {
     type Error = crate::Error;
     fn try_from(from: &str) -> Result<Self, Self::Error> {
-        if country(from).is_none() {
-            return Err(crate::Error::InvalidCountryCode(from.to_string()));
-        }
-        Ok(Self { inner: from.into() })
+        crate::constraints::c3_valid_country(from)?;
+        Ok(Self {
+            inner: from.to_uppercase(),
+        })
+    }
+}
+
+impl TryFrom<String> for CountryCode {
+    type Error = crate::Error;
+    fn try_from(from: String) -> Result<Self, Self::Error> {
+        Self::try_from(from.as_str())
+    }
+}
+
+impl crate::Validatable for CountryCode {
+    /// Checks that the code is a valid country code (C3), which always
+    /// holds for codes constructed by this crate.
+    fn validate_with(&self, _options: &crate::ValidationOptions) -> Result<(), crate::Error> {
+        crate::constraints::c3_valid_country(&self.inner)
+    }
+}
+
+impl PartialEq<&str> for CountryCode {
+    /// Compares case-insensitively.
+    fn eq(&self, other: &&str) -> bool {
+        self.inner.eq_ignore_ascii_case(other)
     }
 }
 
@@ -26,271 +51,319 @@ impl CountryCode {
     pub fn as_str(&self) -> &str {
         &self.inner
     }
+
+    /// Indicates whether the code is an ISO 3166-1 alpha-2 code rather
+    /// than the placeholder `XX` or the supranational code `EU`.
+    ///
+    /// ```
+    /// use ivms101::CountryCode;
+    ///
+    /// assert!(CountryCode::try_from("CH").unwrap().is_iso());
+    /// assert!(!CountryCode::try_from("EU").unwrap().is_iso());
+    /// ```
+    #[must_use]
+    pub fn is_iso(&self) -> bool {
+        !NON_ISO_CODES
+            .iter()
+            .any(|(code, _)| self.inner.eq_ignore_ascii_case(code))
+    }
+}
+
+impl CountryCode {
+    /// Returns all valid country codes, ordered alphabetically. Besides
+    /// the ISO 3166-1 alpha-2 codes, this includes the placeholder `XX`
+    /// and the supranational code `EU`.
+    ///
+    /// ```
+    /// use ivms101::CountryCode;
+    ///
+    /// assert!(CountryCode::all().any(|code| code == "CH"));
+    /// ```
+    pub fn all() -> impl Iterator<Item = CountryCode> {
+        let mut codes: Vec<&str> = country_codes().keys().copied().collect();
+        codes.sort_unstable();
+        codes.into_iter().map(|code| Self {
+            inner: code.to_uppercase(),
+        })
+    }
 }
 
 /// Retrieves the full name of the country given a two-letter
 /// ISO 3166-1 alpha-2 country code.
 ///
+/// The placeholder `XX`, which IVMS101 allows for an unknown country,
+/// and the supranational code `EU` are recognized as well.
+///
 /// Returns `None` if the country code is unknown.
 #[must_use]
-#[allow(clippy::too_many_lines)]
 pub fn country(country_code: &str) -> Option<&str> {
-    COUNTRY_CODES
-        .get_or_init(|| {
-            [
-                ("af", "Afghanistan"),
-                ("ax", "Aland Islands"),
-                ("al", "Albania"),
-                ("dz", "Algeria"),
-                ("as", "American Samoa"),
-                ("ad", "Andorra"),
-                ("ao", "Angola"),
-                ("ai", "Anguilla"),
-                ("aq", "Antarctica"),
-                ("ag", "Antigua And Barbuda"),
-                ("ar", "Argentina"),
-                ("am", "Armenia"),
-                ("aw", "Aruba"),
-                ("au", "Australia"),
-                ("at", "Austria"),
-                ("az", "Azerbaijan"),
-                ("bs", "Bahamas"),
-                ("bh", "Bahrain"),
-                ("bd", "Bangladesh"),
-                ("bb", "Barbados"),
-                ("by", "Belarus"),
-                ("be", "Belgium"),
-                ("bz", "Belize"),
-                ("bj", "Benin"),
-                ("bm", "Bermuda"),
-                ("bt", "Bhutan"),
-                ("bo", "Bolivia"),
-                ("ba", "Bosnia And Herzegovina"),
-                ("bw", "Botswana"),
-                ("bv", "Bouvet Island"),
-                ("br", "Brazil"),
-                ("io", "British Indian Ocean Territory"),
-                ("bn", "Brunei Darussalam"),
-                ("bg", "Bulgaria"),
-                ("bf", "Burkina Faso"),
-                ("bi", "Burundi"),
-                ("kh", "Cambodia"),
-                ("cm", "Cameroon"),
-                ("ca", "Canada"),
-                ("cv", "Cape Verde"),
-                ("ky", "Cayman Islands"),
-                ("cf", "Central African Republic"),
-                ("td", "Chad"),
-                ("cl", "Chile"),
-                ("cn", "China"),
-                ("cx", "Christmas Island"),
-                ("cc", "Cocos (Keeling) Islands"),
-                ("co", "Colombia"),
-                ("km", "Comoros"),
-                ("cg", "Congo"),
-                ("cd", "Congo, Democratic Republic"),
-                ("ck", "Cook Islands"),
-                ("cr", "Costa Rica"),
-                ("ci", "Cote D'Ivoire"),
-                ("hr", "Croatia"),
-                ("cu", "Cuba"),
-                ("cy", "Cyprus"),
-                ("cz", "Czech Republic"),
-                ("dk", "Denmark"),
-                ("dj", "Djibouti"),
-                ("dm", "Dominica"),
-                ("do", "Dominican Republic"),
-                ("ec", "Ecuador"),
-                ("eg", "Egypt"),
-                ("sv", "El Salvador"),
-                ("gq", "Equatorial Guinea"),
-                ("er", "Eritrea"),
-                ("ee", "Estonia"),
-                ("et", "Ethiopia"),
-                ("fk", "Falkland Islands (Malvinas)"),
-                ("fo", "Faroe Islands"),
-                ("fj", "Fiji"),
-                ("fi", "Finland"),
-                ("fr", "France"),
-                ("gf", "French Guiana"),
-                ("pf", "French Polynesia"),
-                ("tf", "French Southern Territories"),
-                ("ga", "Gabon"),
-                ("gm", "Gambia"),
-                ("ge", "Georgia"),
-                ("de", "Germany"),
-                ("gh", "Ghana"),
-                ("gi", "Gibraltar"),
-                ("gr", "Greece"),
-                ("gl", "Greenland"),
-                ("gd", "Grenada"),
-                ("gp", "Guadeloupe"),
-                ("gu", "Guam"),
-                ("gt", "Guatemala"),
-                ("gg", "Guernsey"),
-                ("gn", "Guinea"),
-                ("gw", "Guinea-Bissau"),
-                ("gy", "Guyana"),
-                ("ht", "Haiti"),
-                ("hm", "Heard Island & Mcdonald Islands"),
-                ("va", "Holy See (Vatican City State)"),
-                ("hn", "Honduras"),
-                ("hk", "Hong Kong"),
-                ("hu", "Hungary"),
-                ("is", "Iceland"),
-                ("in", "India"),
-                ("id", "Indonesia"),
-                ("ir", "Iran, Islamic Republic Of"),
-                ("iq", "Iraq"),
-                ("ie", "Ireland"),
-                ("im", "Isle Of Man"),
-                ("il", "Israel"),
-                ("it", "Italy"),
-                ("jm", "Jamaica"),
-                ("jp", "Japan"),
-                ("je", "Jersey"),
-                ("jo", "Jordan"),
-                ("kz", "Kazakhstan"),
-                ("ke", "Kenya"),
-                ("ki", "Kiribati"),
-                ("kp", "Democratic People's Republic of Korea"),
-                ("kr", "South Korea"),
-                ("kw", "Kuwait"),
-                ("kg", "Kyrgyzstan"),
-                ("la", "Lao People's Democratic Republic"),
-                ("lv", "Latvia"),
-                ("lb", "Lebanon"),
-                ("ls", "Lesotho"),
-                ("lr", "Liberia"),
-                ("ly", "Libyan Arab Jamahiriya"),
-                ("li", "Liechtenstein"),
-                ("lt", "Lithuania"),
-                ("lu", "Luxembourg"),
-                ("mo", "Macao"),
-                ("mk", "Macedonia"),
-                ("mg", "Madagascar"),
-                ("mw", "Malawi"),
-                ("my", "Malaysia"),
-                ("mv", "Maldives"),
-                ("ml", "Mali"),
-                ("mt", "Malta"),
-                ("mh", "Marshall Islands"),
-                ("mq", "Martinique"),
-                ("mr", "Mauritania"),
-                ("mu", "Mauritius"),
-                ("yt", "Mayotte"),
-                ("mx", "Mexico"),
-                ("fm", "Micronesia, Federated States Of"),
-                ("md", "Moldova"),
-                ("mc", "Monaco"),
-                ("mn", "Mongolia"),
-                ("me", "Montenegro"),
-                ("ms", "Montserrat"),
-                ("ma", "Morocco"),
-                ("mz", "Mozambique"),
-                ("mm", "Myanmar"),
-                ("na", "Namibia"),
-                ("nr", "Nauru"),
-                ("np", "Nepal"),
-                ("nl", "Netherlands"),
-                ("an", "Netherlands Antilles"),
-                ("nc", "New Caledonia"),
-                ("nz", "New Zealand"),
-                ("ni", "Nicaragua"),
-                ("ne", "Niger"),
-                ("ng", "Nigeria"),
-                ("nu", "Niue"),
-                ("nf", "Norfolk Island"),
-                ("mp", "Northern Mariana Islands"),
-                ("no", "Norway"),
-                ("om", "Oman"),
-                ("pk", "Pakistan"),
-                ("pw", "Palau"),
-                ("ps", "Palestinian Territory, Occupied"),
-                ("pa", "Panama"),
-                ("pg", "Papua New Guinea"),
-                ("py", "Paraguay"),
-                ("pe", "Peru"),
-                ("ph", "Philippines"),
-                ("pn", "Pitcairn"),
-                ("pl", "Poland"),
-                ("pt", "Portugal"),
-                ("pr", "Puerto Rico"),
-                ("qa", "Qatar"),
-                ("re", "Reunion"),
-                ("ro", "Romania"),
-                ("ru", "Russian Federation"),
-                ("rw", "Rwanda"),
-                ("bl", "Saint Barthelemy"),
-                ("sh", "Saint Helena"),
-                ("kn", "Saint Kitts And Nevis"),
-                ("lc", "Saint Lucia"),
-                ("mf", "Saint Martin"),
-                ("pm", "Saint Pierre And Miquelon"),
-                ("vc", "Saint Vincent And Grenadines"),
-                ("ws", "Samoa"),
-                ("sm", "San Marino"),
-                ("st", "Sao Tome And Principe"),
-                ("sa", "Saudi Arabia"),
-                ("sn", "Senegal"),
-                ("rs", "Serbia"),
-                ("sc", "Seychelles"),
-                ("sl", "Sierra Leone"),
-                ("sg", "Singapore"),
-                ("sk", "Slovakia"),
-                ("si", "Slovenia"),
-                ("sb", "Solomon Islands"),
-                ("so", "Somalia"),
-                ("za", "South Africa"),
-                ("gs", "South Georgia And Sandwich Isl."),
-                ("es", "Spain"),
-                ("lk", "Sri Lanka"),
-                ("sd", "Sudan"),
-                ("sr", "Suriname"),
-                ("sj", "Svalbard And Jan Mayen"),
-                ("sz", "Swaziland"),
-                ("se", "Sweden"),
-                ("ch", "Switzerland"),
-                ("sy", "Syrian Arab Republic"),
-                ("tw", "Taiwan"),
-                ("tj", "Tajikistan"),
-                ("tz", "Tanzania"),
-                ("th", "Thailand"),
-                ("tl", "Timor-Leste"),
-                ("tg", "Togo"),
-                ("tk", "Tokelau"),
-                ("to", "Tonga"),
-                ("tt", "Trinidad And Tobago"),
-                ("tn", "Tunisia"),
-                ("tr", "Turkey"),
-                ("tm", "Turkmenistan"),
-                ("tc", "Turks And Caicos Islands"),
-                ("tv", "Tuvalu"),
-                ("ug", "Uganda"),
-                ("ua", "Ukraine"),
-                ("ae", "United Arab Emirates"),
-                ("gb", "United Kingdom"),
-                ("us", "United States"),
-                ("um", "United States Outlying Islands"),
-                ("uy", "Uruguay"),
-                ("uz", "Uzbekistan"),
-                ("vu", "Vanuatu"),
-                ("ve", "Venezuela"),
-                ("vn", "Viet Nam"),
-                ("vg", "Virgin Islands, British"),
-                ("vi", "Virgin Islands, U.S."),
-                ("wf", "Wallis And Futuna"),
-                ("eh", "Western Sahara"),
-                ("ye", "Yemen"),
-                ("zm", "Zambia"),
-                ("zw", "Zimbabwe"),
-            ]
-            .into()
-        })
+    country_codes()
         .get(country_code.to_lowercase().as_str())
         .copied()
 }
 
+/// Codes which are not part of ISO 3166-1 but accepted as country codes:
+/// the placeholder for an unknown country and the supranational code
+/// used as issuer region by GLEIF and some registries.
+const NON_ISO_CODES: [(&str, &str); 2] = [("xx", "Unknown Country"), ("eu", "European Union")];
+
+#[allow(clippy::too_many_lines)]
+fn country_codes() -> &'static std::collections::HashMap<&'static str, &'static str> {
+    COUNTRY_CODES.get_or_init(|| {
+        [
+            ("af", "Afghanistan"),
+            ("ax", "Aland Islands"),
+            ("al", "Albania"),
+            ("dz", "Algeria"),
+            ("as", "American Samoa"),
+            ("ad", "Andorra"),
+            ("ao", "Angola"),
+            ("ai", "Anguilla"),
+            ("aq", "Antarctica"),
+            ("ag", "Antigua And Barbuda"),
+            ("ar", "Argentina"),
+            ("am", "Armenia"),
+            ("aw", "Aruba"),
+            ("au", "Australia"),
+            ("at", "Austria"),
+            ("az", "Azerbaijan"),
+            ("bs", "Bahamas"),
+            ("bh", "Bahrain"),
+            ("bd", "Bangladesh"),
+            ("bb", "Barbados"),
+            ("by", "Belarus"),
+            ("be", "Belgium"),
+            ("bz", "Belize"),
+            ("bj", "Benin"),
+            ("bm", "Bermuda"),
+            ("bt", "Bhutan"),
+            ("bo", "Bolivia"),
+            ("ba", "Bosnia And Herzegovina"),
+            ("bw", "Botswana"),
+            ("bv", "Bouvet Island"),
+            ("br", "Brazil"),
+            ("io", "British Indian Ocean Territory"),
+            ("bn", "Brunei Darussalam"),
+            ("bg", "Bulgaria"),
+            ("bf", "Burkina Faso"),
+            ("bi", "Burundi"),
+            ("kh", "Cambodia"),
+            ("cm", "Cameroon"),
+            ("ca", "Canada"),
+            ("cv", "Cape Verde"),
+            ("ky", "Cayman Islands"),
+            ("cf", "Central African Republic"),
+            ("td", "Chad"),
+            ("cl", "Chile"),
+            ("cn", "China"),
+            ("cx", "Christmas Island"),
+            ("cc", "Cocos (Keeling) Islands"),
+            ("co", "Colombia"),
+            ("km", "Comoros"),
+            ("cg", "Congo"),
+            ("cd", "Congo, Democratic Republic"),
+            ("ck", "Cook Islands"),
+            ("cr", "Costa Rica"),
+            ("ci", "Cote D'Ivoire"),
+            ("hr", "Croatia"),
+            ("cu", "Cuba"),
+            ("cy", "Cyprus"),
+            ("cz", "Czech Republic"),
+            ("dk", "Denmark"),
+            ("dj", "Djibouti"),
+            ("dm", "Dominica"),
+            ("do", "Dominican Republic"),
+            ("ec", "Ecuador"),
+            ("eg", "Egypt"),
+            ("sv", "El Salvador"),
+            ("gq", "Equatorial Guinea"),
+            ("er", "Eritrea"),
+            ("ee", "Estonia"),
+            ("et", "Ethiopia"),
+            ("fk", "Falkland Islands (Malvinas)"),
+            ("fo", "Faroe Islands"),
+            ("fj", "Fiji"),
+            ("fi", "Finland"),
+            ("fr", "France"),
+            ("gf", "French Guiana"),
+            ("pf", "French Polynesia"),
+            ("tf", "French Southern Territories"),
+            ("ga", "Gabon"),
+            ("gm", "Gambia"),
+            ("ge", "Georgia"),
+            ("de", "Germany"),
+            ("gh", "Ghana"),
+            ("gi", "Gibraltar"),
+            ("gr", "Greece"),
+            ("gl", "Greenland"),
+            ("gd", "Grenada"),
+            ("gp", "Guadeloupe"),
+            ("gu", "Guam"),
+            ("gt", "Guatemala"),
+            ("gg", "Guernsey"),
+            ("gn", "Guinea"),
+            ("gw", "Guinea-Bissau"),
+            ("gy", "Guyana"),
+            ("ht", "Haiti"),
+            ("hm", "Heard Island & Mcdonald Islands"),
+            ("va", "Holy See (Vatican City State)"),
+            ("hn", "Honduras"),
+            ("hk", "Hong Kong"),
+            ("hu", "Hungary"),
+            ("is", "Iceland"),
+            ("in", "India"),
+            ("id", "Indonesia"),
+            ("ir", "Iran, Islamic Republic Of"),
+            ("iq", "Iraq"),
+            ("ie", "Ireland"),
+            ("im", "Isle Of Man"),
+            ("il", "Israel"),
+            ("it", "Italy"),
+            ("jm", "Jamaica"),
+            ("jp", "Japan"),
+            ("je", "Jersey"),
+            ("jo", "Jordan"),
+            ("kz", "Kazakhstan"),
+            ("ke", "Kenya"),
+            ("ki", "Kiribati"),
+            ("kp", "Democratic People's Republic of Korea"),
+            ("kr", "South Korea"),
+            ("kw", "Kuwait"),
+            ("kg", "Kyrgyzstan"),
+            ("la", "Lao People's Democratic Republic"),
+            ("lv", "Latvia"),
+            ("lb", "Lebanon"),
+            ("ls", "Lesotho"),
+            ("lr", "Liberia"),
+            ("ly", "Libyan Arab Jamahiriya"),
+            ("li", "Liechtenstein"),
+            ("lt", "Lithuania"),
+            ("lu", "Luxembourg"),
+            ("mo", "Macao"),
+            ("mk", "Macedonia"),
+            ("mg", "Madagascar"),
+            ("mw", "Malawi"),
+            ("my", "Malaysia"),
+            ("mv", "Maldives"),
+            ("ml", "Mali"),
+            ("mt", "Malta"),
+            ("mh", "Marshall Islands"),
+            ("mq", "Martinique"),
+            ("mr", "Mauritania"),
+            ("mu", "Mauritius"),
+            ("yt", "Mayotte"),
+            ("mx", "Mexico"),
+            ("fm", "Micronesia, Federated States Of"),
+            ("md", "Moldova"),
+            ("mc", "Monaco"),
+            ("mn", "Mongolia"),
+            ("me", "Montenegro"),
+            ("ms", "Montserrat"),
+            ("ma", "Morocco"),
+            ("mz", "Mozambique"),
+            ("mm", "Myanmar"),
+            ("na", "Namibia"),
+            ("nr", "Nauru"),
+            ("np", "Nepal"),
+            ("nl", "Netherlands"),
+            ("an", "Netherlands Antilles"),
+            ("nc", "New Caledonia"),
+            ("nz", "New Zealand"),
+            ("ni", "Nicaragua"),
+            ("ne", "Niger"),
+            ("ng", "Nigeria"),
+            ("nu", "Niue"),
+            ("nf", "Norfolk Island"),
+            ("mp", "Northern Mariana Islands"),
+            ("no", "Norway"),
+            ("om", "Oman"),
+            ("pk", "Pakistan"),
+            ("pw", "Palau"),
+            ("ps", "Palestinian Territory, Occupied"),
+            ("pa", "Panama"),
+            ("pg", "Papua New Guinea"),
+            ("py", "Paraguay"),
+            ("pe", "Peru"),
+            ("ph", "Philippines"),
+            ("pn", "Pitcairn"),
+            ("pl", "Poland"),
+            ("pt", "Portugal"),
+            ("pr", "Puerto Rico"),
+            ("qa", "Qatar"),
+            ("re", "Reunion"),
+            ("ro", "Romania"),
+            ("ru", "Russian Federation"),
+            ("rw", "Rwanda"),
+            ("bl", "Saint Barthelemy"),
+            ("sh", "Saint Helena"),
+            ("kn", "Saint Kitts And Nevis"),
+            ("lc", "Saint Lucia"),
+            ("mf", "Saint Martin"),
+            ("pm", "Saint Pierre And Miquelon"),
+            ("vc", "Saint Vincent And Grenadines"),
+            ("ws", "Samoa"),
+            ("sm", "San Marino"),
+            ("st", "Sao Tome And Principe"),
+            ("sa", "Saudi Arabia"),
+            ("sn", "Senegal"),
+            ("rs", "Serbia"),
+            ("sc", "Seychelles"),
+            ("sl", "Sierra Leone"),
+            ("sg", "Singapore"),
+            ("sk", "Slovakia"),
+            ("si", "Slovenia"),
+            ("sb", "Solomon Islands"),
+            ("so", "Somalia"),
+            ("za", "South Africa"),
+            ("gs", "South Georgia And Sandwich Isl."),
+            ("es", "Spain"),
+            ("lk", "Sri Lanka"),
+            ("sd", "Sudan"),
+            ("sr", "Suriname"),
+            ("sj", "Svalbard And Jan Mayen"),
+            ("sz", "Swaziland"),
+            ("se", "Sweden"),
+            ("ch", "Switzerland"),
+            ("sy", "Syrian Arab Republic"),
+            ("tw", "Taiwan"),
+            ("tj", "Tajikistan"),
+            ("tz", "Tanzania"),
+            ("th", "Thailand"),
+            ("tl", "Timor-Leste"),
+            ("tg", "Togo"),
+            ("tk", "Tokelau"),
+            ("to", "Tonga"),
+            ("tt", "Trinidad And Tobago"),
+            ("tn", "Tunisia"),
+            ("tr", "Turkey"),
+            ("tm", "Turkmenistan"),
+            ("tc", "Turks And Caicos Islands"),
+            ("tv", "Tuvalu"),
+            ("ug", "Uganda"),
+            ("ua", "Ukraine"),
+            ("ae", "United Arab Emirates"),
+            ("gb", "United Kingdom"),
+            ("us", "United States"),
+            ("um", "United States Outlying Islands"),
+            ("uy", "Uruguay"),
+            ("uz", "Uzbekistan"),
+            ("vu", "Vanuatu"),
+            ("ve", "Venezuela"),
+            ("vn", "Viet Nam"),
+            ("vg", "Virgin Islands, British"),
+            ("vi", "Virgin Islands, U.S."),
+            ("wf", "Wallis And Futuna"),
+            ("eh", "Western Sahara"),
+            ("ye", "Yemen"),
+            ("zm", "Zambia"),
+            ("zw", "Zimbabwe"),
+        ]
+        .into_iter()
+        .chain(NON_ISO_CODES)
+        .collect()
+    })
+}
+
 static COUNTRY_CODES: std::sync::OnceLock<std::collections::HashMap<&'static str, &'static str>> =
     std::sync::OnceLock::new();
 
@@ -305,6 +378,33 @@ mod tests {
         assert_tokens(&de, &[Token::BorrowedStr("DE")]);
     }
 
+    #[test]
+    fn test_country_code_canonical_case() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let lower = CountryCode::try_from("ch").unwrap();
+        let upper = CountryCode::try_from("CH").unwrap();
+        assert_eq!(lower, upper);
+        assert_eq!(lower.as_str(), "CH");
+        assert_eq!(lower, "ch");
+        assert_eq!(lower, "CH");
+        assert_ne!(lower, "DE");
+
+        let hash = |code: &CountryCode| {
+            let mut hasher = DefaultHasher::new();
+            code.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&lower), hash(&upper));
+
+        serde_test::assert_de_tokens(&upper, &[Token::BorrowedStr("ch")]);
+        serde_test::assert_de_tokens(&upper, &[Token::String("ch")]);
+        let lenient: CountryCode = serde_json::from_reader(&br#""cH""#[..]).unwrap();
+        assert_eq!(hash(&lenient), hash(&upper));
+        serde_test::assert_ser_tokens(&lower, &[Token::Str("CH")]);
+    }
+
     #[test]
     fn test_country_code_invalid_length() {
         serde_test::assert_de_tokens_error::<CountryCode>(
@@ -317,6 +417,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate() {
+        use crate::Validatable;
+
+        CountryCode::try_from("ch").unwrap().validate().unwrap();
+        CountryCode::try_from("XX").unwrap().validate().unwrap();
+        let invalid = CountryCode { inner: "ZZ".into() };
+        assert_eq!(
+            invalid.validate(),
+            Err(crate::Error::InvalidCountryCode("ZZ".into()))
+        );
+    }
+
+    #[test]
+    fn test_all() {
+        let all: Vec<CountryCode> = CountryCode::all().collect();
+        assert!(all.contains(&"CH".try_into().unwrap()));
+        assert!(all.contains(&"XX".try_into().unwrap()));
+        assert!((240..=260).contains(&all.len()));
+        assert_eq!(all[0], "AD");
+    }
+
+    #[test]
+    fn test_supranational_code() {
+        let eu = CountryCode::try_from("EU").unwrap();
+        assert_eq!(super::country(eu.as_str()), Some("European Union"));
+        assert!(!eu.is_iso());
+        assert!(CountryCode::try_from("eu").is_ok());
+        assert!(CountryCode::try_from("DE").unwrap().is_iso());
+    }
+
     #[test]
     fn test_invalid_country_code() {
         assert!(CountryCode::try_from("RR").is_err());
@@ -0,0 +1,153 @@
+use chrono::{DateTime, Utc};
+
+use crate::{Error, Validatable, ValidationFailures, ValidationOptions, IVMS101};
+
+/// An IVMS101 message together with the transfer it describes, as
+/// exchanged by travel rule transports.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-fields", serde(deny_unknown_fields))]
+pub struct Envelope {
+    /// The IVMS101 message.
+    pub ivms101: IVMS101,
+    /// The transfer the message describes.
+    pub transfer: TransferInfo,
+}
+
+/// Metadata of a transfer.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-fields", serde(deny_unknown_fields))]
+pub struct TransferInfo {
+    /// The transferred asset, e.g. "BTC".
+    pub asset: String,
+    /// The transferred amount as decimal number, e.g. "0.25", kept as
+    /// string to preserve its precision.
+    pub amount: String,
+    /// The network the transfer takes place on, e.g. "ethereum".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+    /// The hash of the transaction, once it is known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_hash: Option<String>,
+    /// When the transfer was initiated.
+    pub timestamp: DateTime<Utc>,
+}
+
+impl Validatable for Envelope {
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), Error> {
+        self.ivms101
+            .validate_with(options)
+            .map_err(|e| e.at("ivms101"))?;
+        self.transfer
+            .validate_with(options)
+            .map_err(|e| e.at("transfer"))
+    }
+
+    fn validate_all_with(&self, options: &ValidationOptions) -> Result<(), ValidationFailures> {
+        let mut errors = Vec::new();
+        if let Err(e) = self.ivms101.validate_all_with(options) {
+            errors.extend(e.into_iter().map(|e| e.at("ivms101")));
+        }
+        if let Err(e) = self.transfer.validate_with(options) {
+            errors.push(e.at("transfer"));
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.into())
+        }
+    }
+}
+
+impl Validatable for TransferInfo {
+    /// Checks that the asset is given, the amount is a positive decimal
+    /// number and the transaction hash, if any, is not empty.
+    fn validate_with(&self, _options: &ValidationOptions) -> Result<(), Error> {
+        if self.asset.trim().is_empty() {
+            return Err(Error::ValidationError("Asset must not be empty".into()).at("asset"));
+        }
+        if !is_positive_decimal(&self.amount) {
+            return Err(Error::ValidationError(format!(
+                "Amount '{}' is not a positive decimal number",
+                self.amount
+            ))
+            .at("amount"));
+        }
+        if self.tx_hash.as_deref().is_some_and(|h| h.trim().is_empty()) {
+            return Err(
+                Error::ValidationError("Transaction hash must not be empty".into()).at("txHash"),
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Whether `amount` consists of digits with at most one decimal point
+/// and is not zero.
+fn is_positive_decimal(amount: &str) -> bool {
+    let digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    let well_formed = match amount.split_once('.') {
+        Some((integer, fraction)) => digits(integer) && digits(fraction),
+        None => digits(amount),
+    };
+    well_formed && amount.bytes().any(|b| (b'1'..=b'9').contains(&b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock() -> Envelope {
+        Envelope {
+            ivms101: IVMS101::mock(),
+            transfer: TransferInfo {
+                asset: "BTC".into(),
+                amount: "0.25".into(),
+                network: None,
+                tx_hash: Some("9f2c".into()),
+                timestamp: DateTime::parse_from_rfc3339("2024-05-01T12:00:00Z")
+                    .unwrap()
+                    .into(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let envelope = mock();
+        let json = serde_json::to_string(&envelope).unwrap();
+        assert!(json.contains(
+            r#""transfer":{"asset":"BTC","amount":"0.25","txHash":"9f2c","timestamp":"2024-05-01T12:00:00Z"}"#
+        ));
+        assert_eq!(serde_json::from_str::<Envelope>(&json).unwrap(), envelope);
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut envelope = mock();
+        envelope.validate().unwrap();
+
+        for amount in ["0", "0.00", "", ".5", "1.", "1.2.3", "-1", "1e3"] {
+            envelope.transfer.amount = amount.into();
+            let err = envelope.validate().unwrap_err();
+            assert_eq!(err.path(), Some("transfer.amount"), "{amount}");
+        }
+        envelope.transfer.amount = "10".into();
+        envelope.transfer.tx_hash = Some(String::new());
+        assert_eq!(
+            envelope.validate().unwrap_err().path(),
+            Some("transfer.txHash")
+        );
+
+        envelope.transfer.asset = " ".into();
+        let person = envelope.ivms101.originator_person_mut();
+        person.geographic_address = None.into();
+        let errors = envelope.validate_all().unwrap_err();
+        let paths: Vec<_> = errors.iter().filter_map(Error::path).collect();
+        assert_eq!(
+            paths,
+            ["ivms101.originator.originatorPersons[0]", "transfer.asset"]
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{country, format_postal_address, Address};
+
+/// An address decomposed into the fields most geocoders expect, see
+/// [`Address::to_geocodable`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeocodableAddress {
+    /// The street and building number, followed by the address lines.
+    pub street: String,
+    /// The town name.
+    pub city: String,
+    /// The country sub-division, e.g. a state or canton.
+    pub state: String,
+    /// The post code.
+    pub postcode: String,
+    /// The ISO 3166-1 alpha-2 country code.
+    pub country: String,
+}
+
+impl GeocodableAddress {
+    /// Composes a free-text query, which equals the [`Display`] of the
+    /// address without the country name. The state is not part of it.
+    ///
+    /// [`Display`]: std::fmt::Display
+    #[must_use]
+    pub fn query_string(&self) -> String {
+        let mut query = String::new();
+        format_postal_address(
+            &mut query,
+            None,
+            None,
+            non_empty(&self.street),
+            non_empty(&self.postcode),
+            &self.city,
+        )
+        .expect("writing to a string succeeds");
+        query
+    }
+}
+
+fn non_empty(s: &str) -> Option<&str> {
+    (!s.is_empty()).then_some(s)
+}
+
+impl Address {
+    /// Decomposes the address for geocoding. Absent fields are empty.
+    ///
+    /// The street consists of the street name followed by the building
+    /// number, if any. Address lines are joined with commas and appended,
+    /// or used alone if there is no street name. Other fields such as the
+    /// building name are left out, as in the [`Display`] of the address.
+    ///
+    /// [`Display`]: std::fmt::Display
+    #[must_use]
+    pub fn to_geocodable(&self) -> GeocodableAddress {
+        let street = self
+            .street_name
+            .as_ref()
+            .map(|street| match &self.building_number {
+                Some(number) => format!("{street} {number}"),
+                None => street.to_string(),
+            });
+        let street = [street, self.address_lines()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(", ");
+        GeocodableAddress {
+            street,
+            city: self.town_name.to_string(),
+            state: self
+                .country_sub_division
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            postcode: self
+                .post_code
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            country: self.country.as_str().to_owned(),
+        }
+    }
+
+    /// Composes a normalized single-line query for geocoding services in
+    /// the form "street number, postcode town, country name".
+    ///
+    /// The street is composed as in [`Address::to_geocodable`]. Whitespace,
+    /// including line breaks, is collapsed to single spaces and empty
+    /// parts are left out, such that equal addresses yield equal keys.
+    #[must_use]
+    pub fn geocode_key(&self) -> String {
+        let geocodable = self.to_geocodable();
+        let code = self.country.as_str();
+        let country = country(&code.to_lowercase()).unwrap_or(code).to_owned();
+        [
+            geocodable.street,
+            format!("{} {}", geocodable.postcode, geocodable.city),
+            country,
+        ]
+        .iter()
+        .map(|part| part.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_geocodable() {
+        let mut address =
+            Address::new(Some("Main street"), Some("1"), None, "8000", "Zurich", "CH").unwrap();
+        address.country_sub_division = Some("ZH".try_into().unwrap());
+        let geocodable = address.to_geocodable();
+        assert_eq!(
+            geocodable,
+            GeocodableAddress {
+                street: "Main street 1".into(),
+                city: "Zurich".into(),
+                state: "ZH".into(),
+                postcode: "8000".into(),
+                country: "CH".into(),
+            }
+        );
+        assert_eq!(geocodable.query_string(), "Main street 1, 8000 Zurich");
+        assert_eq!(
+            address.to_string(),
+            format!("{}, Switzerland", geocodable.query_string())
+        );
+
+        // Address lines only.
+        let address = Address::mock();
+        let geocodable = address.to_geocodable();
+        assert_eq!(geocodable.street, "Main street");
+        assert_eq!(geocodable.postcode, "");
+        assert_eq!(geocodable.query_string(), "Main street, Zurich");
+        assert!(address.to_string().starts_with(&geocodable.query_string()));
+
+        // Street and address lines.
+        let mut address = Address::new(
+            Some("Main street"),
+            None,
+            Some("c/o Company A"),
+            "8000",
+            "Zurich",
+            "CH",
+        )
+        .unwrap();
+        address.building_name = Some("Tower".try_into().unwrap());
+        let geocodable = address.to_geocodable();
+        assert_eq!(geocodable.street, "Main street, c/o Company A");
+        assert_eq!(geocodable.state, "");
+        assert_eq!(
+            address.to_string(),
+            format!("{}, Switzerland", geocodable.query_string())
+        );
+    }
+
+    #[test]
+    fn test_geocode_key() {
+        let mut address = Address::new(
+            Some("Main  street"),
+            Some("1"),
+            None,
+            "8000",
+            "Zurich",
+            "CH",
+        )
+        .unwrap();
+        assert_eq!(
+            address.geocode_key(),
+            "Main street 1, 8000 Zurich, Switzerland"
+        );
+        address.town_name = "Zurich\nCity".try_into().unwrap();
+        assert_eq!(
+            address.geocode_key(),
+            "Main street 1, 8000 Zurich City, Switzerland"
+        );
+
+        // Without street, the address lines are used.
+        assert_eq!(
+            Address::mock().geocode_key(),
+            "Main street, Zurich, Switzerland"
+        );
+        let mut address = Address::mock();
+        address.address_line = None.into();
+        address.post_box = Some("12".try_into().unwrap());
+        assert_eq!(address.geocode_key(), "Zurich, Switzerland");
+    }
+}
@@ -0,0 +1,234 @@
+use crate::{
+    registration_authorities, Error, NationalIdentification, NationalIdentifierTypeCode, PersonKind,
+};
+
+/// Builds a [`NationalIdentification`], checking the constraints that
+/// otherwise only apply once it is part of a person.
+///
+/// ```
+/// use ivms101::{NationalIdentificationBuilder, NationalIdentifierTypeCode, PersonKind};
+///
+/// let id = NationalIdentificationBuilder::new(
+///     "CHE-123.456.789",
+///     NationalIdentifierTypeCode::RegistrationAuthorityIdentifier,
+/// )
+/// .registration_authority("RA000548")
+/// .for_role(PersonKind::Legal)
+/// .build()
+/// .unwrap();
+/// assert!(id.country_of_issue().is_none());
+///
+/// // C9 forbids a country of issue for legal persons.
+/// assert!(NationalIdentificationBuilder::from(id)
+///     .country_of_issue("CH")
+///     .for_role(PersonKind::Legal)
+///     .build()
+///     .is_err());
+/// ```
+#[derive(Clone, Debug)]
+pub struct NationalIdentificationBuilder {
+    identification: Result<NationalIdentification, Error>,
+    kind: Option<PersonKind>,
+}
+
+impl NationalIdentificationBuilder {
+    /// Constructs a `NationalIdentificationBuilder` for an identifier of
+    /// the given type, without country of issue or registration authority.
+    #[must_use]
+    pub fn new(identifier: &str, identifier_type: NationalIdentifierTypeCode) -> Self {
+        let identification = identifier
+            .try_into()
+            .map(|national_identifier| NationalIdentification {
+                national_identifier,
+                national_identifier_type: identifier_type,
+                country_of_issue: None,
+                registration_authority: None,
+            })
+            .map_err(|e: Error| e.at("nationalIdentifier"));
+        Self {
+            identification,
+            kind: None,
+        }
+    }
+
+    /// Sets the country of issue.
+    #[must_use]
+    pub fn country_of_issue(mut self, country: &str) -> Self {
+        self.identification = self.identification.and_then(|mut id| {
+            id.country_of_issue = Some(
+                country
+                    .try_into()
+                    .map_err(|e: Error| e.at("countryOfIssue"))?,
+            );
+            Ok(id)
+        });
+        self
+    }
+
+    /// Sets the registration authority, accepting lowercase codes and
+    /// canonicalizing them to uppercase.
+    #[must_use]
+    pub fn registration_authority(mut self, ra: &str) -> Self {
+        self.identification = self.identification.and_then(|mut id| {
+            id.registration_authority = Some(
+                registration_authorities::parse(ra).map_err(|e| e.at("registrationAuthority"))?,
+            );
+            Ok(id)
+        });
+        self
+    }
+
+    /// Applies the constraints of the kind of person the identification
+    /// is meant for: any type is allowed for natural persons, while legal
+    /// persons require C7, C9 and C11 to hold. Without a kind, only the
+    /// values are checked.
+    #[must_use]
+    pub fn for_role(mut self, kind: PersonKind) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    /// Builds the national identification.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error setting a value, i.e. an identifier
+    /// exceeding 35 bytes, an invalid country code (C3) or an
+    /// invalid registration authority (C10). For
+    /// [`PersonKind::Legal`], additionally returns an error if the
+    /// identifier type is not allowed (C7), the country of issue or the
+    /// registration authority violate C9 or an LEI is invalid (C11).
+    pub fn build(self) -> Result<NationalIdentification, Error> {
+        let identification = self.identification?;
+        match self.kind {
+            Some(PersonKind::Legal) => {
+                identification.check_legal_person_type()?;
+                identification.check_c9()?;
+            }
+            Some(PersonKind::Natural) | None => {}
+        }
+        Ok(identification)
+    }
+}
+
+impl From<NationalIdentification> for NationalIdentificationBuilder {
+    fn from(identification: NationalIdentification) -> Self {
+        Self {
+            identification: Ok(identification),
+            kind: None,
+        }
+    }
+}
+
+impl From<&lei::LEI> for NationalIdentificationBuilder {
+    fn from(lei: &lei::LEI) -> Self {
+        NationalIdentification::from(lei).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raid() -> NationalIdentificationBuilder {
+        NationalIdentificationBuilder::new(
+            "CHE-123.456.789",
+            NationalIdentifierTypeCode::RegistrationAuthorityIdentifier,
+        )
+    }
+
+    #[test]
+    fn test_build() {
+        let id = raid()
+            .registration_authority("ra000548")
+            .for_role(PersonKind::Legal)
+            .build()
+            .unwrap();
+        assert_eq!(id.national_identifier.as_str(), "CHE-123.456.789");
+        assert_eq!(id.registration_authority.unwrap().to_string(), "RA000548");
+
+        let passport = NationalIdentificationBuilder::new(
+            "AB123456",
+            NationalIdentifierTypeCode::PassportNumber,
+        )
+        .country_of_issue("CH")
+        .for_role(PersonKind::Natural)
+        .build()
+        .unwrap();
+        assert_eq!(passport.country_of_issue.unwrap().as_str(), "CH");
+
+        let lei = lei::LEI::try_from("2594007XIACKNMUAW223").unwrap();
+        let id = NationalIdentificationBuilder::from(&lei)
+            .for_role(PersonKind::Legal)
+            .build()
+            .unwrap();
+        assert_eq!(id, NationalIdentification::from(&lei));
+    }
+
+    #[test]
+    fn test_build_invalid_values() {
+        let err = NationalIdentificationBuilder::new(
+            &"1".repeat(36),
+            NationalIdentifierTypeCode::Unspecified,
+        )
+        .build()
+        .unwrap_err();
+        assert!(err.to_string().contains("nationalIdentifier"), "{err}");
+        let err = raid().country_of_issue("ZZ").build().unwrap_err();
+        assert!(err.to_string().contains("countryOfIssue"), "{err}");
+        let err = raid().registration_authority("RA").build().unwrap_err();
+        assert_eq!(err.constraint(), Some("C10"));
+    }
+
+    #[test]
+    fn test_build_legal_person() {
+        let legal = |builder: NationalIdentificationBuilder| {
+            builder
+                .for_role(PersonKind::Legal)
+                .build()
+                .unwrap_err()
+                .constraint()
+                .map(str::to_owned)
+        };
+        // The registration authority is required for non-LEI identifiers.
+        assert_eq!(legal(raid()), Some("C9".to_owned()));
+        // The country of issue is forbidden.
+        assert_eq!(
+            legal(
+                raid()
+                    .registration_authority("RA000548")
+                    .country_of_issue("CH")
+            ),
+            Some("C9".to_owned())
+        );
+        // The registration authority is forbidden for LEIs.
+        let lei = lei::LEI::try_from("2594007XIACKNMUAW223").unwrap();
+        assert_eq!(
+            legal(NationalIdentificationBuilder::from(&lei).registration_authority("RA000548")),
+            Some("C9".to_owned())
+        );
+        // Identity documents are not allowed.
+        assert_eq!(
+            legal(NationalIdentificationBuilder::new(
+                "AB123456",
+                NationalIdentifierTypeCode::PassportNumber
+            )),
+            Some("C7".to_owned())
+        );
+        // LEIs must be valid.
+        assert_eq!(
+            legal(NationalIdentificationBuilder::new(
+                "2594007XIACKNMUAW224",
+                NationalIdentifierTypeCode::LegalEntityIdentifier
+            )),
+            Some("C11".to_owned())
+        );
+
+        // The same identifications are fine for natural persons.
+        raid()
+            .country_of_issue("CH")
+            .for_role(PersonKind::Natural)
+            .build()
+            .unwrap();
+    }
+}
@@ -0,0 +1,914 @@
+use std::collections::BTreeMap;
+use std::io::Read;
+
+use serde_json::Value;
+
+use crate::{
+    Beneficiary, BeneficiaryVASP, Date, DeserializeLimits, Error, OriginatingVASP, Originator,
+    SerializeOptions, Validatable, IVMS101,
+};
+
+/// An IVMS101 message of which only the sections that could be
+/// parsed without errors are populated.
+///
+/// Returned by [`IVMS101::from_json_partial`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PartialIvms101 {
+    /// The originator, if it could be parsed.
+    pub originator: Option<Originator>,
+    /// The beneficiary, if it could be parsed.
+    pub beneficiary: Option<Beneficiary>,
+    /// The originating VASP, if it could be parsed.
+    pub originating_vasp: Option<OriginatingVASP>,
+    /// The beneficiary VASP, if it could be parsed.
+    pub beneficiary_vasp: Option<BeneficiaryVASP>,
+}
+
+impl IVMS101 {
+    /// Parses a JSON payload, strictly adhering to the IVMS101 data model.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::ParseError`] if the payload cannot be parsed.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        serde_json::from_str(json).map_err(|e| Error::ParseError(e.to_string()))
+    }
+
+    /// Parses a JSON array of payloads, e.g. a batch file, strictly
+    /// adhering to the IVMS101 data model like [`IVMS101::from_json`].
+    ///
+    /// As with [`IVMS101::from_json`], the payloads are not validated,
+    /// which is left to the caller, e.g. with [`Validatable::validate_all`]
+    /// for each of them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::ParseError`] if the input is not a JSON array.
+    /// If an element cannot be parsed, the error of the first such element
+    /// is returned with its index as path, e.g. `[1]`.
+    pub fn from_json_array(json: &str) -> Result<Vec<Self>, Error> {
+        let values: Vec<Value> =
+            serde_json::from_str(json).map_err(|e| Error::ParseError(e.to_string()))?;
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| {
+                serde_json::from_value(value)
+                    .map_err(|e| Error::ParseError(e.to_string()).at(&format!("[{i}]")))
+            })
+            .collect()
+    }
+
+    /// Parses a JSON payload from a reader, strictly adhering to the
+    /// IVMS101 data model like [`IVMS101::from_json`].
+    ///
+    /// The reader is not buffered, so wrap it in a
+    /// [`std::io::BufReader`] if it performs a system call per read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Io`] if reading fails and an
+    /// [`Error::ParseError`] if the payload cannot be parsed.
+    pub fn from_reader<R: std::io::Read>(reader: R) -> Result<Self, Error> {
+        serde_json::from_reader(reader).map_err(json_error)
+    }
+
+    /// Serializes the payload as JSON to a writer, like
+    /// [`IVMS101::to_json`] but without building a string first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Io`] if writing fails.
+    pub fn to_writer<W: std::io::Write>(&self, writer: W) -> Result<(), Error> {
+        serde_json::to_writer(writer, self).map_err(json_error)
+    }
+
+    /// Parses a JSON payload like [`IVMS101::from_json`], rejecting
+    /// payloads exceeding the given limits.
+    ///
+    /// ```
+    /// use ivms101::{DeserializeLimits, IVMS101};
+    ///
+    /// let limits = DeserializeLimits { max_elements: 1000 };
+    /// assert!(IVMS101::from_json_with("{}", limits).is_ok());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::ParseError`] if the payload cannot be parsed,
+    /// including collections exceeding the limits, whose path is given by
+    /// [`Error::path`].
+    pub fn from_json_with(json: &str, limits: DeserializeLimits) -> Result<Self, Error> {
+        limits.check(json)?;
+        serde_json::from_str(json).map_err(|e| Error::ParseError(e.to_string()))
+    }
+
+    /// Parses a JSON payload like [`IVMS101::from_json`], first rejecting
+    /// payloads larger than `max_bytes` or nested deeper than
+    /// [`IVMS101::MAX_DEPTH`] without allocating for their content.
+    /// Collections are limited by the default [`DeserializeLimits`].
+    ///
+    /// Use this for payloads received from untrusted parties. IVMS101
+    /// payloads rarely exceed a few kilobytes, so a limit of 64 KiB is
+    /// generous for most endpoints.
+    ///
+    /// ```
+    /// use ivms101::{Error, IVMS101};
+    ///
+    /// assert!(IVMS101::from_json_bounded("{}", 64 * 1024).is_ok());
+    /// assert_eq!(
+    ///     IVMS101::from_json_bounded("{}", 1),
+    ///     Err(Error::PayloadTooLarge { size: 2, max: 1 })
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::PayloadTooLarge`] or [`Error::TooDeep`] if the
+    /// payload exceeds the bounds and the errors of
+    /// [`IVMS101::from_json_with`] otherwise.
+    pub fn from_json_bounded(json: &str, max_bytes: usize) -> Result<Self, Error> {
+        if json.len() > max_bytes {
+            return Err(Error::PayloadTooLarge {
+                size: json.len(),
+                max: max_bytes,
+            });
+        }
+        if exceeds_depth(json, Self::MAX_DEPTH) {
+            return Err(Error::TooDeep(Self::MAX_DEPTH));
+        }
+        Self::from_json_with(json, DeserializeLimits::default())
+    }
+
+    /// Parses a UTF-8 encoded JSON payload like
+    /// [`IVMS101::from_json_bounded`], e.g. a message body that is
+    /// already in memory. The payload is not validated, see
+    /// [`IVMS101::from_slice_validated`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`IVMS101::from_json_bounded`], and an
+    /// [`Error::ParseError`] if the payload is not valid UTF-8.
+    pub fn from_slice_bounded(bytes: &[u8], max_bytes: usize) -> Result<Self, Error> {
+        if bytes.len() > max_bytes {
+            return Err(Error::PayloadTooLarge {
+                size: bytes.len(),
+                max: max_bytes,
+            });
+        }
+        let json = std::str::from_utf8(bytes)
+            .map_err(|e| Error::ParseError(format!("invalid UTF-8: {e}")))?;
+        Self::from_json_bounded(json, max_bytes)
+    }
+
+    /// Parses a JSON payload from a reader like
+    /// [`IVMS101::from_json_bounded`], reading at most one byte more
+    /// than `max_bytes`.
+    ///
+    /// Use this for message bodies received from untrusted parties,
+    /// which [`IVMS101::from_reader`] would read until their end.
+    ///
+    /// As with [`IVMS101::from_json`], the payload is not validated,
+    /// which is left to the caller, e.g. with [`Validatable::validate`],
+    /// or to [`IVMS101::from_reader_validated`].
+    ///
+    /// ```
+    /// use ivms101::{Error, IVMS101};
+    ///
+    /// assert!(IVMS101::from_reader_bounded(&b"{}"[..], 64 * 1024).is_ok());
+    /// assert_eq!(
+    ///     IVMS101::from_reader_bounded(&b"{}   "[..], 2),
+    ///     Err(Error::PayloadTooLarge { size: 3, max: 2 })
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Io`] if reading fails and the errors of
+    /// [`IVMS101::from_slice_bounded`] otherwise. As the reader is not
+    /// read to its end, the size of a payload exceeding the limit is
+    /// reported as `max_bytes + 1`.
+    pub fn from_reader_bounded<R: std::io::Read>(
+        reader: R,
+        max_bytes: usize,
+    ) -> Result<Self, Error> {
+        let mut bytes = Vec::new();
+        reader
+            .take((max_bytes as u64).saturating_add(1))
+            .read_to_end(&mut bytes)
+            .map_err(|e| Error::Io(e.to_string()))?;
+        Self::from_slice_bounded(&bytes, max_bytes)
+    }
+
+    /// Parses a UTF-8 encoded JSON payload like
+    /// [`IVMS101::from_slice_bounded`] and validates it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`IVMS101::from_slice_bounded`], or the first
+    /// error of [`Validatable::validate_all`], which carries its path.
+    pub fn from_slice_validated(bytes: &[u8], max_bytes: usize) -> Result<Self, Error> {
+        let ivms = Self::from_slice_bounded(bytes, max_bytes)?;
+        first_error(ivms.validate_all())?;
+        Ok(ivms)
+    }
+
+    /// Parses a JSON payload from a reader like
+    /// [`IVMS101::from_reader_bounded`] and validates it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`IVMS101::from_reader_bounded`], or the
+    /// first error of [`Validatable::validate_all`], which carries its
+    /// path.
+    pub fn from_reader_validated<R: std::io::Read>(
+        reader: R,
+        max_bytes: usize,
+    ) -> Result<Self, Error> {
+        let ivms = Self::from_reader_bounded(reader, max_bytes)?;
+        first_error(ivms.validate_all())?;
+        Ok(ivms)
+    }
+
+    /// The maximum nesting of arrays and objects accepted by
+    /// [`IVMS101::from_json_bounded`]. Valid payloads nest at most nine
+    /// levels deep.
+    pub const MAX_DEPTH: usize = 16;
+
+    /// Serializes the payload to JSON, omitting empty fields and
+    /// serializing single elements as scalars.
+    ///
+    /// The JSON is not checked to parse again, use
+    /// [`IVMS101::assert_round_trippable`] for payloads whose fields were
+    /// mutated directly.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        self.to_json_with(SerializeOptions::default())
+    }
+
+    /// Serializes the payload to JSON, shaping collections according to
+    /// `options`, e.g. for counterparties that insist on arrays.
+    #[must_use]
+    pub fn to_json_with(&self, options: SerializeOptions) -> String {
+        options.to_string(self)
+    }
+
+    /// Converts a validated payload to a JSON value, omitting empty
+    /// fields and serializing single elements as scalars.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of [`Validatable::validate_all`], which
+    /// carries the path of the offending person.
+    pub fn to_value(&self) -> Result<Value, Error> {
+        first_error(self.validate_all())?;
+        Ok(serde_json::to_value(self).expect("IVMS101 serializes to JSON"))
+    }
+
+    /// Parses and validates a JSON value, strictly adhering to the
+    /// IVMS101 data model.
+    ///
+    /// Unlike [`IVMS101::from_json`], errors carry the path they
+    /// occurred at, see [`Error::path`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::ParseError`] attributed to the section that
+    /// fails to parse, or the first error of [`Validatable::validate_all`].
+    pub fn from_value(value: Value) -> Result<Self, Error> {
+        let (partial, errors) = parse_partial(&value);
+        if let Some(error) = errors.into_iter().next() {
+            return Err(error);
+        }
+        let ivms = Self {
+            originator: partial.originator.map(Box::new),
+            beneficiary: partial.beneficiary.map(Box::new),
+            originating_vasp: partial.originating_vasp.map(Box::new),
+            beneficiary_vasp: partial.beneficiary_vasp.map(Box::new),
+        };
+        first_error(ivms.validate_all())?;
+        Ok(ivms)
+    }
+
+    /// Flattens the payload into its leaf values keyed by their field
+    /// path, e.g. `originator.originatorPersons[0].naturalPerson.name[0]`
+    /// `.nameIdentifier[0].primaryIdentifier`.
+    ///
+    /// Collections are always indexed, regardless of whether they hold a
+    /// single element, such that paths are stable.
+    #[must_use]
+    pub fn flatten(&self) -> BTreeMap<String, String> {
+        let options = SerializeOptions {
+            always_array: true,
+            empty_as_array: false,
+        };
+        let value = options.to_value(self);
+        let mut leaves = BTreeMap::new();
+        flatten_into(&mut leaves, String::new(), value);
+        leaves
+    }
+
+    /// Returns all dates of the payload keyed by their field path as in
+    /// [`IVMS101::flatten`], e.g. for indexing. Currently, these are the
+    /// dates of birth of natural persons.
+    #[must_use]
+    pub fn dates(&self) -> Vec<(String, Date)> {
+        self.flatten()
+            .into_iter()
+            .filter(|(path, _)| path.ends_with(".dateOfBirth"))
+            .filter_map(|(path, date)| Some((path, date.parse().ok()?)))
+            .collect()
+    }
+
+    /// Returns a hash of the content of the payload, e.g. for idempotency
+    /// keys.
+    ///
+    /// The hash is computed over a canonical form, so payloads that only
+    /// differ in the shape of collections, e.g. a single element given as
+    /// an object or as an array, hash equally. It uses 64-bit FNV-1a and
+    /// is therefore stable across platforms and Rust versions. It is
+    /// stable within a version of this crate but may change with a new
+    /// version if the serialized form changes.
+    #[must_use]
+    pub fn content_hash(&self) -> u64 {
+        let options = SerializeOptions {
+            always_array: true,
+            empty_as_array: false,
+        };
+        let json = options.to_string(self);
+        fnv1a(json.as_bytes())
+    }
+
+    /// Parses a JSON payload section by section, keeping every section
+    /// that parses cleanly.
+    ///
+    /// This is meant for triage of malformed payloads, e.g. to still log
+    /// the originator if only the beneficiary is broken. The returned
+    /// errors are attributed to the section they occurred in.
+    #[must_use]
+    pub fn from_json_partial(json: &str) -> (PartialIvms101, Vec<Error>) {
+        match serde_json::from_str(json) {
+            Ok(value) => parse_partial(&value),
+            Err(e) => (
+                PartialIvms101::default(),
+                vec![Error::ParseError(e.to_string())],
+            ),
+        }
+    }
+
+    /// Checks that the payload parses again after serialization, which
+    /// may not be the case if its public fields were mutated into a state
+    /// the data model does not allow.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::ParseError`] attributed to the section that
+    /// fails to parse.
+    pub fn assert_round_trippable(&self) -> Result<(), Error> {
+        let value = serde_json::to_value(self).expect("IVMS101 serializes to JSON");
+        round_trip(&value)
+    }
+}
+
+/// Hashes `bytes` with 64-bit FNV-1a, which is stable across platforms
+/// and Rust versions.
+pub(crate) fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+/// Checks that a serialized payload parses, see
+/// [`IVMS101::assert_round_trippable`].
+fn round_trip(value: &Value) -> Result<(), Error> {
+    let (_, errors) = parse_partial(value);
+    errors.into_iter().next().map_or(Ok(()), Err)
+}
+
+/// Keeps the first of the errors collected by a validation or parse.
+fn first_error(result: Result<(), impl Into<Vec<Error>>>) -> Result<(), Error> {
+    result.map_err(|errors| {
+        errors
+            .into()
+            .into_iter()
+            .next()
+            .expect("errors are reported as a non-empty vector")
+    })
+}
+
+/// Parses a payload section by section, see [`IVMS101::from_json_partial`].
+fn parse_partial(value: &Value) -> (PartialIvms101, Vec<Error>) {
+    let mut partial = PartialIvms101::default();
+    let Some(sections) = value.as_object() else {
+        return (
+            partial,
+            vec![Error::ParseError("expected a JSON object".into())],
+        );
+    };
+
+    let mut errors = Vec::new();
+    for (key, section) in sections {
+        match key.as_str() {
+            "originator" => partial.originator = parse_section(key, section, &mut errors),
+            "beneficiary" => partial.beneficiary = parse_section(key, section, &mut errors),
+            "originatingVASP" | "originating_vasp" => {
+                partial.originating_vasp = parse_section(key, section, &mut errors);
+            }
+            "beneficiaryVASP" | "beneficiary_vasp" => {
+                partial.beneficiary_vasp = parse_section(key, section, &mut errors);
+            }
+            _ if cfg!(feature = "strict-fields") => {
+                errors.push(Error::ParseError("unknown field".into()).at(key));
+            }
+            _ => {}
+        }
+    }
+    (partial, errors)
+}
+
+fn flatten_into(leaves: &mut BTreeMap<String, String>, path: String, value: Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let path = if path.is_empty() {
+                    key
+                } else {
+                    format!("{path}.{key}")
+                };
+                flatten_into(leaves, path, value);
+            }
+        }
+        Value::Array(values) => {
+            for (i, value) in values.into_iter().enumerate() {
+                flatten_into(leaves, format!("{path}[{i}]"), value);
+            }
+        }
+        Value::String(s) => {
+            leaves.insert(path, s);
+        }
+        Value::Null => {}
+        value => {
+            leaves.insert(path, value.to_string());
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for IVMS101 {
+    type Error = Error;
+
+    /// Parses a UTF-8 encoded JSON payload, see [`IVMS101::from_json`].
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        let json = std::str::from_utf8(bytes)
+            .map_err(|e| Error::ParseError(format!("invalid UTF-8: {e}")))?;
+        Self::from_json(json)
+    }
+}
+
+/// Converts a `serde_json` error, telling I/O failures apart from
+/// malformed payloads.
+fn json_error(error: serde_json::Error) -> Error {
+    if error.is_io() {
+        Error::Io(error.to_string())
+    } else {
+        Error::ParseError(error.to_string())
+    }
+}
+
+fn parse_section<'a, T: serde::Deserialize<'a>>(
+    key: &str,
+    section: &'a Value,
+    errors: &mut Vec<Error>,
+) -> Option<T> {
+    T::deserialize(section)
+        .map_err(|e| errors.push(Error::ParseError(e.to_string()).at(key)))
+        .ok()
+}
+
+/// Indicates whether arrays and objects in `json` are nested deeper than
+/// `max`, ignoring brackets within strings.
+fn exceeds_depth(json: &str, max: usize) -> bool {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for byte in json.bytes() {
+        match byte {
+            _ if escaped => escaped = false,
+            b'\\' if in_string => escaped = true,
+            b'"' => in_string = !in_string,
+            _ if in_string => {}
+            b'[' | b'{' => {
+                depth += 1;
+                if depth > max {
+                    return true;
+                }
+            }
+            b']' | b'}' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::non_empty_vec::NonEmptyVec;
+    use crate::{OneToN, ZeroToN};
+
+    #[test]
+    fn test_from_json_partial() {
+        let account = "1".repeat(101);
+        let json = format!(
+            r#"{{
+                "originator": {{
+                    "originatorPersons": {{
+                        "naturalPerson": {{
+                            "name": {{
+                                "nameIdentifier": {{
+                                    "primaryIdentifier": "Engels",
+                                    "nameIdentifierType": "LEGL"
+                                }}
+                            }}
+                        }}
+                    }}
+                }},
+                "beneficiary": {{
+                    "beneficiaryPersons": {{
+                        "naturalPerson": {{
+                            "name": {{
+                                "nameIdentifier": {{
+                                    "primaryIdentifier": "Marx",
+                                    "nameIdentifierType": "LEGL"
+                                }}
+                            }}
+                        }}
+                    }},
+                    "accountNumber": "{account}"
+                }}
+            }}"#
+        );
+        let (partial, errors) = IVMS101::from_json_partial(&json);
+        assert_eq!(
+            partial
+                .originator
+                .unwrap()
+                .originator_persons
+                .first()
+                .last_name(),
+            "Engels"
+        );
+        assert_eq!(partial.beneficiary, None);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().starts_with("beneficiary: "));
+    }
+
+    #[test]
+    fn test_reader_writer() {
+        let mut ivms = IVMS101::mock();
+        let person = ivms.originator_person_mut();
+        person.national_identification = Some(crate::NationalIdentification::mock());
+
+        let mut buffer = Vec::new();
+        ivms.to_writer(&mut buffer).unwrap();
+        assert_eq!(buffer, ivms.to_json().into_bytes());
+        assert_eq!(IVMS101::from_reader(buffer.as_slice()).unwrap(), ivms);
+
+        assert!(matches!(
+            IVMS101::from_reader(&b"{"[..]),
+            Err(Error::ParseError(_))
+        ));
+        let mut full = [0; 8];
+        assert!(matches!(ivms.to_writer(&mut full[..]), Err(Error::Io(_))));
+    }
+
+    #[test]
+    fn test_from_reader_bounded() {
+        let ivms = IVMS101::mock();
+        let mut cursor = std::io::Cursor::new(Vec::new());
+        ivms.to_writer(&mut cursor).unwrap();
+        let size = cursor.get_ref().len();
+        cursor.set_position(0);
+        assert_eq!(
+            IVMS101::from_reader_bounded(&mut cursor, size).unwrap(),
+            ivms
+        );
+
+        // The reader is not read beyond the limit.
+        cursor.set_position(0);
+        assert_eq!(
+            IVMS101::from_reader_bounded(&mut cursor, 64),
+            Err(Error::PayloadTooLarge { size: 65, max: 64 })
+        );
+        assert_eq!(cursor.position(), 65);
+        let endless = std::io::repeat(b'[');
+        assert_eq!(
+            IVMS101::from_reader_bounded(endless, 1024),
+            Err(Error::PayloadTooLarge {
+                size: 1025,
+                max: 1024
+            })
+        );
+
+        let bytes = cursor.into_inner();
+        assert_eq!(IVMS101::from_slice_bounded(&bytes, size).unwrap(), ivms);
+        assert!(matches!(
+            IVMS101::from_slice_bounded(&[0xff], 1),
+            Err(Error::ParseError(_))
+        ));
+        let deep = "[".repeat(100);
+        assert_eq!(
+            IVMS101::from_slice_bounded(deep.as_bytes(), 1024),
+            Err(Error::TooDeep(IVMS101::MAX_DEPTH))
+        );
+    }
+
+    #[test]
+    fn test_from_reader_validated() {
+        let ivms = IVMS101::mock();
+        let bytes = ivms.to_json().into_bytes();
+        assert_eq!(
+            IVMS101::from_reader_validated(&bytes[..], bytes.len()).unwrap(),
+            ivms
+        );
+        assert_eq!(
+            IVMS101::from_slice_validated(&bytes, bytes.len()).unwrap(),
+            ivms
+        );
+        assert_eq!(
+            IVMS101::from_reader_validated(&bytes[..], 64),
+            Err(Error::PayloadTooLarge { size: 65, max: 64 })
+        );
+
+        let mut invalid = IVMS101::mock();
+        invalid.originator_person_mut().geographic_address = ZeroToN::None;
+        let bytes = invalid.to_json().into_bytes();
+        assert!(IVMS101::from_reader_bounded(&bytes[..], bytes.len()).is_ok());
+        let err = IVMS101::from_reader_validated(&bytes[..], bytes.len()).unwrap_err();
+        assert_eq!(err.path(), Some("originator.originatorPersons[0]"));
+        assert_eq!(IVMS101::from_slice_validated(&bytes, bytes.len()), Err(err));
+    }
+
+    #[test]
+    fn test_try_from_bytes() {
+        let json = br#"{"originatingVASP":{"originatingVASP":{"legalPerson":{"name":{"nameIdentifier":{"legalPersonName":"VASP A","legalPersonNameIdentifierType":"LEGL"}}}}}}"#;
+        let ivms = IVMS101::try_from(&json[..]).unwrap();
+        assert_eq!(
+            ivms.originating_vasp()
+                .unwrap()
+                .originating_vasp
+                .last_name(),
+            "VASP A"
+        );
+
+        let err = IVMS101::try_from(&b"{\"originator\": \"\xff\"}"[..]).unwrap_err();
+        assert!(err.to_string().starts_with("Parse error: invalid UTF-8"));
+
+        assert!(matches!(
+            IVMS101::try_from(&b"{"[..]),
+            Err(Error::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_to_json_with() {
+        let json = r#"{"originator":{"originatorPersons":{"naturalPerson":{"name":{"nameIdentifier":{"primaryIdentifier":"Engels","nameIdentifierType":"LEGL"}}}},"accountNumber":"account-1"}}"#;
+        let ivms = IVMS101::from_json(json).unwrap();
+
+        assert_eq!(ivms.to_json(), json);
+        assert_eq!(
+            ivms.to_json_with(SerializeOptions {
+                always_array: true,
+                empty_as_array: false,
+            }),
+            r#"{"originator":{"originatorPersons":[{"naturalPerson":{"name":[{"nameIdentifier":[{"primaryIdentifier":"Engels","nameIdentifierType":"LEGL"}]}]}}],"accountNumber":["account-1"]}}"#
+        );
+        assert_eq!(
+            ivms.to_json_with(SerializeOptions {
+                always_array: false,
+                empty_as_array: true,
+            }),
+            r#"{"originator":{"originatorPersons":{"naturalPerson":{"name":{"nameIdentifier":{"primaryIdentifier":"Engels","nameIdentifierType":"LEGL"},"localNameIdentifier":[],"phoneticNameIdentifier":[]},"geographicAddress":[]}},"accountNumber":"account-1"}}"#
+        );
+
+        // Plain serialization is not affected.
+        assert_eq!(serde_json::to_string(&ivms).unwrap(), json);
+    }
+
+    #[test]
+    fn test_from_json_array() {
+        let ivms = IVMS101::mock();
+        let json = format!("[{},{{}}]", ivms.to_json());
+        let parsed = IVMS101::from_json_array(&json).unwrap();
+        assert_eq!(parsed, [ivms, IVMS101::from_json("{}").unwrap()]);
+        assert_eq!(IVMS101::from_json_array("[]").unwrap(), []);
+
+        let err = IVMS101::from_json_array(r#"[{},{"originator":{"originatorPersons":[]}}]"#)
+            .unwrap_err();
+        assert_eq!(err.path(), Some("[1]"));
+        assert!(matches!(err.without_path(), Error::ParseError(_)));
+
+        assert!(matches!(
+            IVMS101::from_json_array("{}"),
+            Err(Error::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_to_json_with_empty_account_number() {
+        let json = r#"{"beneficiary":{"beneficiaryPersons":{"legalPerson":{"name":{"nameIdentifier":{"legalPersonName":"Company A","legalPersonNameIdentifierType":"LEGL"}},"customerIdentification":"customer-b"}},"accountNumber":[]}}"#;
+        let ivms = IVMS101::from_json(json).unwrap();
+        assert!(ivms.beneficiary().unwrap().account_number.is_empty());
+
+        let omitted = ivms.to_json();
+        assert!(!omitted.contains("accountNumber"));
+        let options = SerializeOptions {
+            empty_as_array: true,
+            ..Default::default()
+        };
+        let explicit = ivms.to_json_with(options);
+        assert!(explicit.ends_with(r#""accountNumber":[]}}"#));
+
+        // Both forms read back as an empty account number.
+        let reparsed = IVMS101::from_json(&omitted).unwrap();
+        assert_eq!(reparsed.to_json_with(options), explicit);
+    }
+
+    #[test]
+    fn test_numeric_account_number() {
+        let json = r#"{"beneficiary":{"beneficiaryPersons":{"legalPerson":{"name":{"nameIdentifier":{"legalPersonName":"Company A","legalPersonNameIdentifierType":"LEGL"}},"customerIdentification":"customer-b"}},"accountNumber":12345}}"#;
+        let ivms = IVMS101::from_json(json).unwrap();
+        let beneficiary = ivms.beneficiary().unwrap();
+        assert_eq!(
+            beneficiary.account_number,
+            ZeroToN::One("12345".try_into().unwrap())
+        );
+        // Serialization keeps the string form.
+        assert!(ivms.to_json().ends_with(r#""accountNumber":"12345"}}"#));
+
+        let json = json.replace("12345", r#"[12345,"account-2"]"#);
+        let ivms = IVMS101::from_json(&json).unwrap();
+        assert!(ivms
+            .beneficiary()
+            .unwrap()
+            .account_numbers()
+            .eq(["12345", "account-2"]));
+    }
+
+    #[test]
+    fn test_to_value_from_value() {
+        let ivms = IVMS101::mock();
+        let value = ivms.to_value().unwrap();
+        assert_eq!(value, serde_json::to_value(&ivms).unwrap());
+        assert_eq!(IVMS101::from_value(value).unwrap(), ivms);
+
+        let value = serde_json::json!({
+            "originator": {
+                "originatorPersons": {
+                    "naturalPerson": {
+                        "name": {
+                            "nameIdentifier": {
+                                "primaryIdentifier": "Engels",
+                                "nameIdentifierType": "LEGL"
+                            }
+                        },
+                        "geographicAddress": {
+                            "addressType": "GEOG",
+                            "townName": "Barmen",
+                            "country": "DE"
+                        }
+                    }
+                }
+            }
+        });
+        let err = IVMS101::from_value(value.clone()).unwrap_err();
+        assert_eq!(
+            err.path(),
+            Some("originator.originatorPersons[0].geographicAddress[0]")
+        );
+        assert_eq!(err.constraint(), Some("C8"));
+        let err = IVMS101::from_value_lenient(value).unwrap_err();
+        assert_eq!(err.constraint(), Some("C8"));
+
+        let err = IVMS101::from_value(serde_json::json!({"beneficiary": {}})).unwrap_err();
+        assert!(matches!(err.without_path(), Error::ParseError(_)));
+        assert_eq!(err.path(), Some("beneficiary"));
+    }
+
+    #[test]
+    fn test_content_hash() {
+        let single = r#"{"originator":{"originatorPersons":{"naturalPerson":{"name":{"nameIdentifier":{"primaryIdentifier":"Engels","nameIdentifierType":"LEGL"}}}},"accountNumber":"account-1"}}"#;
+        let arrays = r#"{"originator":{"originatorPersons":[{"naturalPerson":{"name":[{"nameIdentifier":[{"primaryIdentifier":"Engels","nameIdentifierType":"LEGL"}]}],"geographicAddress":[]}}],"accountNumber":["account-1"]}}"#;
+        let hash = IVMS101::from_json(single).unwrap().content_hash();
+        assert_eq!(IVMS101::from_json(arrays).unwrap().content_hash(), hash);
+
+        let changed = single.replace("Engels", "Engeln");
+        assert_ne!(IVMS101::from_json(&changed).unwrap().content_hash(), hash);
+    }
+
+    #[test]
+    fn test_assert_round_trippable() {
+        let mut ivms = IVMS101::mock();
+        ivms.assert_round_trippable().unwrap();
+
+        ivms.originator.as_mut().unwrap().originator_persons =
+            OneToN::N(NonEmptyVec::new_unchecked(vec![]));
+        let err = ivms.assert_round_trippable().unwrap_err();
+        assert_eq!(err.path(), Some("originator"));
+        assert!(err
+            .to_string()
+            .contains("originatorPersons must contain at least one element"));
+    }
+
+    #[test]
+    fn test_from_json_partial_invalid_json() {
+        let (partial, errors) = IVMS101::from_json_partial("{");
+        assert_eq!(partial, PartialIvms101::default());
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn test_from_json_with_limits() {
+        let with_lines = |n: usize| {
+            let mut ivms = IVMS101::mock();
+            let person = ivms.originator_person_mut();
+            let address = person.geographic_address.first().unwrap().clone();
+            person.geographic_address = Some(crate::Address {
+                address_line: vec!["line".try_into().unwrap(); n].into(),
+                ..address
+            })
+            .into();
+            serde_json::to_string(&ivms).unwrap()
+        };
+
+        // Plain parsing is not limited.
+        IVMS101::from_json(&with_lines(101)).unwrap();
+
+        IVMS101::from_json_bounded(&with_lines(100), 64 * 1024).unwrap();
+        let err = IVMS101::from_json_bounded(&with_lines(101), 64 * 1024).unwrap_err();
+        assert_eq!(
+            err.path(),
+            Some("originator.originatorPersons.naturalPerson.geographicAddress.addressLine")
+        );
+        assert!(err.to_string().contains("at most 100 elements"), "{err}");
+
+        let limits = DeserializeLimits { max_elements: 2 };
+        IVMS101::from_json_with(&with_lines(2), limits).unwrap();
+        assert!(IVMS101::from_json_with(&with_lines(3), limits).is_err());
+    }
+
+    #[test]
+    fn test_to_json_many_elements() {
+        // Payloads built in memory are not subject to any limits.
+        let mut ivms = IVMS101::mock();
+        ivms.originator.as_mut().unwrap().account_number =
+            vec!["account".try_into().unwrap(); 101].into();
+        let json = ivms.to_json();
+        assert_eq!(IVMS101::from_json(&json).unwrap(), ivms);
+    }
+
+    #[test]
+    fn test_from_json_bounded() {
+        let json = IVMS101::mock().to_json();
+        assert_eq!(
+            IVMS101::from_json_bounded(&json, json.len()),
+            IVMS101::from_json(&json)
+        );
+
+        let large = format!(r#"{{"originator": "{}"}}"#, "a".repeat(10 * 1024 * 1024));
+        assert!(matches!(
+            IVMS101::from_json_bounded(&large, 64 * 1024),
+            Err(Error::PayloadTooLarge { max: 65536, .. })
+        ));
+
+        let deep = format!("{}{}", "[".repeat(100_000), "]".repeat(100_000));
+        assert_eq!(
+            IVMS101::from_json_bounded(&deep, deep.len()),
+            Err(Error::TooDeep(IVMS101::MAX_DEPTH))
+        );
+        let brackets_in_string = format!(r#"{{"originator": "{}"}}"#, "[".repeat(100));
+        assert!(matches!(
+            IVMS101::from_json_bounded(&brackets_in_string, 1024),
+            Err(Error::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_dates() {
+        let mut ivms = IVMS101::mock();
+        assert!(ivms.dates().is_empty());
+
+        let person = ivms.originator_person_mut();
+        person.date_and_place_of_birth = Some(crate::DateAndPlaceOfBirth::mock());
+        assert_eq!(
+            ivms.dates(),
+            [(
+                "originator.originatorPersons[0].naturalPerson.dateAndPlaceOfBirth.dateOfBirth"
+                    .to_owned(),
+                Date::from_ymd_opt(1946, 11, 5).unwrap()
+            )]
+        );
+    }
+}
@@ -0,0 +1,320 @@
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::{Error, IVMS101};
+
+/// Values accepted by lenient parsing that have no place in the
+/// canonical data model, keyed by their JSON path.
+pub type Extensions = std::collections::BTreeMap<String, Value>;
+
+/// The sections holding persons and the fields they are held in.
+const PERSON_FIELDS: [(&str, &str); 4] = [
+    ("originator", "originatorPersons"),
+    ("beneficiary", "beneficiaryPersons"),
+    ("originatingVASP", "originatingVASP"),
+    ("beneficiaryVASP", "beneficiaryVASP"),
+];
+
+impl IVMS101 {
+    /// Parses a JSON payload, tolerating the following deviations
+    /// from the IVMS101 data model:
+    ///
+    /// - A `customerIdentification` given as an array. The first value is
+    ///   kept, the remaining ones are returned in the [`Extensions`].
+    /// - The `originatorPersons`, `beneficiaryPersons` or
+    ///   `geographicAddress` collections given as an object keyed by
+    ///   index, e.g. `{"0": {...}, "1": {...}}`. The elements are ordered
+    ///   by index.
+    /// - A `buildingNumber` or `postCode` given as an integer rather than
+    ///   a string, as often produced from spreadsheets. The integer is
+    ///   converted to its decimal form, which is subject to the usual
+    ///   length limit. Other numbers are rejected.
+    ///
+    /// The parsed message serializes canonically.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::ParseError`] if the payload cannot be parsed
+    /// even with the above deviations tolerated.
+    pub fn from_json_lenient(json: &str) -> Result<(Self, Extensions), Error> {
+        let mut value: Value =
+            serde_json::from_str(json).map_err(|e| Error::ParseError(e.to_string()))?;
+        let extensions = tolerate(&mut value);
+        let ivms = Self::deserialize(&value).map_err(|e| Error::ParseError(e.to_string()))?;
+        Ok((ivms, extensions))
+    }
+
+    /// Parses and validates a JSON value, tolerating the deviations
+    /// listed at [`IVMS101::from_json_lenient`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`IVMS101::from_value`], which carry the
+    /// path they occurred at.
+    pub fn from_value_lenient(mut value: Value) -> Result<(Self, Extensions), Error> {
+        let extensions = tolerate(&mut value);
+        Ok((Self::from_value(value)?, extensions))
+    }
+}
+
+/// Rewrites the tolerated deviations into the canonical data model,
+/// returning the values that have no place in it.
+fn tolerate(value: &mut Value) -> Extensions {
+    let mut extensions = Extensions::new();
+    // Only the originator and beneficiary hold collections of persons.
+    for (section, field) in &PERSON_FIELDS[..2] {
+        if let Some(persons) = value.get_mut(section).and_then(|s| s.get_mut(field)) {
+            index_map_to_array(persons);
+        }
+    }
+    for_each_person(value, |path, person| {
+        first_customer_identification(path, person, &mut extensions);
+        if let Some(addresses) = person.get_mut("geographicAddress") {
+            index_map_to_array(addresses);
+            match addresses {
+                Value::Array(addresses) => addresses.iter_mut().for_each(integers_to_strings),
+                address => integers_to_strings(address),
+            }
+        }
+    });
+    extensions
+}
+
+/// Converts the building number and post code of an address to strings
+/// if they are given as integers.
+fn integers_to_strings(address: &mut Value) {
+    for field in ["buildingNumber", "postCode"] {
+        if let Some(value) = address.get_mut(field) {
+            if value.is_i64() || value.is_u64() {
+                *value = Value::String(value.to_string());
+            }
+        }
+    }
+}
+
+/// Turns an object whose keys are all indices into an array ordered by
+/// index. Other values are left untouched.
+fn index_map_to_array(value: &mut Value) {
+    let Value::Object(map) = value else {
+        return;
+    };
+    let Some(mut indexed) = map
+        .iter()
+        .map(|(key, _)| key.parse::<usize>().ok().map(|i| (i, key.clone())))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return;
+    };
+    if indexed.is_empty() {
+        return;
+    }
+    indexed.sort_unstable();
+    let elements = indexed
+        .into_iter()
+        .filter_map(|(_, key)| map.remove(&key))
+        .collect();
+    *value = Value::Array(elements);
+}
+
+/// Calls `f` with the path and the fields of every natural or
+/// legal person in the payload.
+fn for_each_person(root: &mut Value, mut f: impl FnMut(&str, &mut Map<String, Value>)) {
+    for (section, field) in PERSON_FIELDS {
+        let Some(persons) = root.get_mut(section).and_then(|s| s.get_mut(field)) else {
+            continue;
+        };
+        let path = format!("{section}.{field}");
+        match persons {
+            Value::Array(persons) => {
+                for (i, person) in persons.iter_mut().enumerate() {
+                    visit_person(&format!("{path}[{i}]"), person, &mut f);
+                }
+            }
+            person => visit_person(&path, person, &mut f),
+        }
+    }
+}
+
+fn visit_person(path: &str, person: &mut Value, f: &mut impl FnMut(&str, &mut Map<String, Value>)) {
+    let Some(variants) = person.as_object_mut() else {
+        return;
+    };
+    for (variant, fields) in variants {
+        if let Some(fields) = fields.as_object_mut() {
+            f(&format!("{path}.{variant}"), fields);
+        }
+    }
+}
+
+/// Keeps the first of multiple customer identifications, moving the
+/// remaining ones into the extensions.
+fn first_customer_identification(
+    path: &str,
+    person: &mut Map<String, Value>,
+    extensions: &mut Extensions,
+) {
+    const FIELD: &str = "customerIdentification";
+    let Some(Value::Array(ids)) = person.get_mut(FIELD) else {
+        return;
+    };
+    let mut ids = std::mem::take(ids).into_iter();
+    match ids.next() {
+        Some(first) => person.insert(FIELD.into(), first),
+        None => person.remove(FIELD),
+    };
+    let rest: Vec<Value> = ids.collect();
+    if !rest.is_empty() {
+        extensions.insert(format!("{path}.{FIELD}"), Value::Array(rest));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Person;
+
+    const CUSTOMER_IDS: &str = r#"{
+        "originator": {
+            "originatorPersons": {
+                "naturalPerson": {
+                    "name": {
+                        "nameIdentifier": {
+                            "primaryIdentifier": "Engels",
+                            "nameIdentifierType": "LEGL"
+                        }
+                    },
+                    "customerIdentification": ["internal-1", "external-2"]
+                }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_customer_identification_array_strict() {
+        assert!(IVMS101::from_json(CUSTOMER_IDS).is_err());
+    }
+
+    #[test]
+    fn test_customer_identification_array_lenient() {
+        let (ivms, extensions) = IVMS101::from_json_lenient(CUSTOMER_IDS).unwrap();
+        assert_eq!(
+            ivms.originator()
+                .unwrap()
+                .originator_persons
+                .first()
+                .customer_identification(),
+            Some("internal-1".into())
+        );
+        assert_eq!(
+            extensions,
+            Extensions::from([(
+                "originator.originatorPersons.naturalPerson.customerIdentification".into(),
+                serde_json::json!(["external-2"])
+            )])
+        );
+        assert!(serde_json::to_string(&ivms)
+            .unwrap()
+            .contains(r#""customerIdentification":"internal-1""#));
+    }
+
+    #[test]
+    fn test_index_keyed_collections() {
+        let person = |name: &str| {
+            serde_json::json!({
+                "naturalPerson": {
+                    "name": {
+                        "nameIdentifier": {
+                            "primaryIdentifier": name,
+                            "nameIdentifierType": "LEGL"
+                        }
+                    },
+                    "geographicAddress": {
+                        "0": {
+                            "addressType": "HOME",
+                            "addressLine": "Main street",
+                            "townName": "Zurich",
+                            "country": "CH"
+                        }
+                    }
+                }
+            })
+        };
+        let json = serde_json::json!({
+            "originator": {
+                "originatorPersons": {"1": person("Marx"), "0": person("Engels")}
+            }
+        })
+        .to_string();
+        assert!(IVMS101::from_json(&json).is_err());
+
+        let (ivms, extensions) = IVMS101::from_json_lenient(&json).unwrap();
+        assert!(extensions.is_empty());
+        let persons = &ivms.originator().unwrap().originator_persons;
+        let names: Vec<String> = persons.iter().map(Person::last_name).collect();
+        assert_eq!(names, ["Engels", "Marx"]);
+        assert!(persons.first().address().is_some());
+        assert!(serde_json::to_string(&ivms)
+            .unwrap()
+            .contains(r#""originatorPersons":[{"#));
+
+        let json = serde_json::json!({
+            "originator": {
+                "originatorPersons": {"a": person("Marx"), "b": person("Engels")}
+            }
+        })
+        .to_string();
+        assert!(IVMS101::from_json_lenient(&json).is_err());
+    }
+
+    #[test]
+    fn test_numeric_address_fields() {
+        let json = |building_number: Value, post_code: Value| {
+            serde_json::json!({
+                "originator": {
+                    "originatorPersons": {
+                        "naturalPerson": {
+                            "name": {
+                                "nameIdentifier": {
+                                    "primaryIdentifier": "Engels",
+                                    "nameIdentifierType": "LEGL"
+                                }
+                            },
+                            "geographicAddress": [{
+                                "addressType": "HOME",
+                                "streetName": "Main street",
+                                "buildingNumber": building_number,
+                                "postCode": post_code,
+                                "townName": "Zurich",
+                                "country": "CH"
+                            }]
+                        }
+                    }
+                }
+            })
+            .to_string()
+        };
+        let address = |json: &str| {
+            let (ivms, _) = IVMS101::from_json_lenient(json).unwrap();
+            let person = ivms
+                .originator()
+                .unwrap()
+                .originator_persons
+                .first()
+                .clone();
+            person.address().unwrap().clone()
+        };
+
+        let integers = json(12.into(), 8000.into());
+        assert!(IVMS101::from_json(&integers).is_err());
+        let parsed = address(&integers);
+        assert_eq!(parsed.building_number.unwrap().as_str(), "12");
+        assert_eq!(parsed.post_code.unwrap().as_str(), "8000");
+
+        let strings = json("12".into(), "8000".into());
+        assert_eq!(address(&strings), address(&integers));
+
+        assert!(IVMS101::from_json_lenient(&json(12.5.into(), 8000.into())).is_err());
+        let too_long = json(12.into(), u64::MAX.into());
+        assert!(IVMS101::from_json_lenient(&too_long).is_err());
+    }
+}
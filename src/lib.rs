@@ -9,85 +9,549 @@
 //! let person = ivms101::NaturalPerson::new("John", "Doe", Some("id-273934"), None).unwrap();
 //! assert!(person.validate().is_ok());
 //! ```
+//!
+//! ## Field access
+//!
+//! The fields of the data model are deprecated and will become private in
+//! the next major version, such that values can only be constructed
+//! through checked constructors. The [`v2`] module previews that data
+//! model and converts from and to the current one. Code using the
+//! constructors and accessors only compiles without deprecation warnings:
+//!
+//! ```
+//! #![deny(deprecated)]
+//! use ivms101::{
+//!     Address, Beneficiary, DateAndPlaceOfBirth, NationalIdentificationBuilder,
+//!     NationalIdentifierTypeCode, NaturalPerson, Originator, Person, Validatable, IVMS101,
+//! };
+//!
+//! let address = Address::new(Some("Main street"), Some("1"), None, "8000", "Zurich", "CH");
+//! let mut person = NaturalPerson::new("John", "Doe", None, Some(address.unwrap())).unwrap();
+//! let birth = DateAndPlaceOfBirth::new("1970-01-01".parse().unwrap(), "London").unwrap();
+//! person.set_date_and_place_of_birth(Some(birth));
+//! let id = NationalIdentificationBuilder::new("X1234567", NationalIdentifierTypeCode::PassportNumber)
+//!     .country_of_issue("GB")
+//!     .build()
+//!     .unwrap();
+//! person.set_national_identification(Some(id));
+//! let mut originator = Originator::new(person.into()).unwrap();
+//! originator.add_account_number("account-1").unwrap();
+//! let mut ivms = IVMS101::default();
+//! ivms.set_originator(originator);
+//! ivms.set_beneficiary(Beneficiary::unhosted("Jane", "Roe", "wallet-1").unwrap());
+//! assert!(ivms.validate().is_ok());
+//!
+//! let originator = ivms.originator().unwrap();
+//! assert!(originator.account_numbers().eq(["account-1"]));
+//! let Person::NaturalPerson(person) = originator.persons().first() else {
+//!     panic!("the originator is a natural person");
+//! };
+//! let name = person.names().next().unwrap();
+//! let id = name.name_identifiers().next().unwrap();
+//! assert_eq!((id.primary_identifier(), id.secondary_identifier()), ("Doe", Some("John")));
+//! let address = person.addresses().next().unwrap();
+//! assert_eq!(address.street_name(), Some("Main street"));
+//! assert_eq!(address.building_number(), Some("1"));
+//! assert_eq!(address.post_code(), Some("8000"));
+//! assert_eq!(address.country(), &"CH");
+//! let id = person.national_identification().unwrap();
+//! assert_eq!(id.national_identifier(), "X1234567");
+//! assert_eq!(id.country_of_issue().unwrap(), &"GB");
+//! assert_eq!(person.date_and_place_of_birth().unwrap().place_of_birth(), "London");
+//!
+//! // Fix a typo in the town name and replace the account number.
+//! ivms.edit(|ivms| {
+//!     let mut originator = ivms.originator().unwrap().clone();
+//!     let Person::NaturalPerson(mut person) = originator.persons().first().clone() else {
+//!         panic!("the originator is a natural person");
+//!     };
+//!     let mut address = person.addresses().next().unwrap().clone();
+//!     address.set_town_name("Zürich").unwrap();
+//!     person.set_addresses(vec![address]).unwrap();
+//!     person.set_customer_identification(Some("customer-1")).unwrap();
+//!     originator.set_persons(Person::from(person).into());
+//!     assert!(originator.remove_account_number("account-1"));
+//!     originator.add_account_number("account-2").unwrap();
+//!     ivms.set_originator(originator);
+//!     ivms.clear_beneficiary();
+//! })
+//! .unwrap();
+//! let originator = ivms.originator().unwrap();
+//! assert!(originator.account_numbers().eq(["account-2"]));
+//! let address = originator.persons().first().address().unwrap();
+//! assert_eq!(address.town_name(), "Zürich");
+//! assert!(ivms.beneficiary().is_none());
+//! ```
+//!
+//! Accessing a field instead is reported:
+//!
+//! ```compile_fail
+//! #![deny(deprecated)]
+//! let person = ivms101::NaturalPerson::new("John", "Doe", None, None).unwrap();
+//! let _ = person.customer_identification;
+//! ```
+//!
+//! ## Unknown fields
+//!
+//! With the default `strict-fields` feature, deserialization rejects
+//! fields the data model does not define, which catches misspelt optional
+//! fields that would otherwise be dropped silently. Archival readers that
+//! must parse payloads produced by later versions can disable the feature
+//! to skip unknown fields instead. Note that this affects every crate in
+//! the build using `ivms101`, as Cargo unifies features. To tolerate
+//! unknown fields for a single call only, use
+//! [`IVMS101::from_json_lenient`], which also returns them.
 
+pub use audit::{AuditEntry, Audited};
+pub use batch::BatchBuilder;
+pub use conversion::{ConversionLoss, NameOrder};
 pub use country_codes::{country, CountryCode};
-pub use types::{one_to_n::OneToN, zero_to_n::ZeroToN};
+pub use envelope::{Envelope, TransferInfo};
+pub use geocoding::GeocodableAddress;
+pub use identification::NationalIdentificationBuilder;
+pub use json::PartialIvms101;
+pub use lenient::Extensions;
+pub use projection::FieldProfile;
+pub use provenance::{Provenance, Source};
+#[cfg(feature = "binary")]
+pub use types::seq;
+pub use types::{
+    bounded_string::BoundedString, deserialize_limits::DeserializeLimits, one_to_n::OneToN,
+    serialize_options::SerializeOptions, zero_to_n::ZeroToN,
+};
+pub use validation::{
+    ValidationFailures, ValidationLevel, ValidationOptions, ValidationReport, Warning,
+    DEFAULT_PLACEHOLDERS,
+};
+pub use visitor::{PartyRole, PersonVisitor};
 
+// The fields of the data model are deprecated for dependent crates, see
+// above. The modules and items using them allow it until the fields
+// become private.
+#[allow(deprecated)]
+mod audit;
+#[allow(deprecated)]
+mod batch;
+#[allow(deprecated)]
+pub mod constraints;
+#[allow(deprecated)]
+mod conversion;
 mod country_codes;
+#[allow(deprecated)]
+mod envelope;
+#[allow(deprecated)]
+mod geocoding;
+#[allow(deprecated)]
+mod identification;
+#[allow(deprecated)]
+mod json;
+#[allow(deprecated)]
+mod lenient;
+#[cfg(feature = "unicode-normalization")]
+#[allow(deprecated)]
+mod nfc;
+mod postcodes;
+#[allow(deprecated)]
+pub mod problem;
+#[allow(deprecated)]
+mod projection;
+mod provenance;
+mod registration_authorities;
+#[cfg(feature = "test-utils")]
+#[allow(deprecated)]
+pub mod testkit;
+#[cfg(feature = "ts")]
+mod ts;
 mod types;
+pub mod v2;
+#[allow(deprecated)]
+mod validation;
+#[allow(deprecated)]
+mod visitor;
+#[cfg(feature = "prost")]
+#[allow(deprecated)]
+pub mod wire;
 
 use lei::registration_authority::RegistrationAuthority;
 
 /// The main IVMS101 data structure.
-#[derive(serde::Serialize, serde::Deserialize)]
+///
+/// The sections are boxed to keep the struct small and cheap to move,
+/// e.g. through channels. This does not affect the serialized form.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "strict-fields", serde(deny_unknown_fields))]
 pub struct IVMS101 {
     /// The originator of the transaction.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub originator: Option<Originator>,
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(note = "use `IVMS101::originator`, the field will become private")]
+    pub originator: Option<Box<Originator>>,
     /// The beneficiary of the transaction.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub beneficiary: Option<Beneficiary>,
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(note = "use `IVMS101::beneficiary`, the field will become private")]
+    pub beneficiary: Option<Box<Beneficiary>>,
     /// The originating VASP.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "originatingVASP")]
-    pub originating_vasp: Option<OriginatingVASP>,
+    #[serde(alias = "originating_vasp")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(note = "use `IVMS101::originating_vasp`, the field will become private")]
+    pub originating_vasp: Option<Box<OriginatingVASP>>,
     /// The beneficiary VASP.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "beneficiaryVASP")]
-    pub beneficiary_vasp: Option<BeneficiaryVASP>,
+    #[serde(alias = "beneficiary_vasp")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(note = "use `IVMS101::beneficiary_vasp`, the field will become private")]
+    pub beneficiary_vasp: Option<Box<BeneficiaryVASP>>,
 }
 
-impl Validatable for IVMS101 {
-    fn validate(&self) -> Result<(), Error> {
-        if let Some(o) = &self.originator {
-            o.validate()?;
+// The four boxed sections are pointer-sized each.
+const _: () = assert!(std::mem::size_of::<IVMS101>() == 4 * std::mem::size_of::<usize>());
+
+#[allow(deprecated)]
+impl IVMS101 {
+    /// The originator of the transaction.
+    #[must_use]
+    pub fn originator(&self) -> Option<&Originator> {
+        self.originator.as_deref()
+    }
+
+    /// The beneficiary of the transaction.
+    #[must_use]
+    pub fn beneficiary(&self) -> Option<&Beneficiary> {
+        self.beneficiary.as_deref()
+    }
+
+    /// The originating VASP.
+    #[must_use]
+    pub fn originating_vasp(&self) -> Option<&OriginatingVASP> {
+        self.originating_vasp.as_deref()
+    }
+
+    /// The beneficiary VASP.
+    #[must_use]
+    pub fn beneficiary_vasp(&self) -> Option<&BeneficiaryVASP> {
+        self.beneficiary_vasp.as_deref()
+    }
+
+    /// Whether the beneficiary VASP is known, unknown or not applicable,
+    /// see [`VaspStatus`].
+    #[must_use]
+    pub fn beneficiary_vasp_status(&self) -> VaspStatus<'_> {
+        match self.beneficiary_vasp() {
+            None => VaspStatus::Absent,
+            Some(BeneficiaryVASP {
+                beneficiary_vasp: None,
+            }) => VaspStatus::Unknown,
+            Some(BeneficiaryVASP {
+                beneficiary_vasp: Some(vasp),
+            }) => VaspStatus::Known(vasp),
         }
-        if let Some(b) = &self.beneficiary {
-            b.validate()?;
+    }
+
+    /// Sets the originator.
+    pub fn set_originator(&mut self, originator: Originator) {
+        self.originator = Some(Box::new(originator));
+    }
+
+    /// Sets the beneficiary.
+    pub fn set_beneficiary(&mut self, beneficiary: Beneficiary) {
+        self.beneficiary = Some(Box::new(beneficiary));
+    }
+
+    /// Sets the originating VASP.
+    pub fn set_originating_vasp(&mut self, vasp: OriginatingVASP) {
+        self.originating_vasp = Some(Box::new(vasp));
+    }
+
+    /// Sets the beneficiary VASP.
+    pub fn set_beneficiary_vasp(&mut self, vasp: Person) {
+        self.beneficiary_vasp = Some(Box::new(BeneficiaryVASP {
+            beneficiary_vasp: Some(vasp),
+        }));
+    }
+
+    /// Marks the beneficiary VASP as unknown, which serializes as
+    /// `"beneficiaryVASP": {}`. To mark it as not applicable instead, use
+    /// [`IVMS101::clear_beneficiary_vasp`].
+    pub fn set_beneficiary_vasp_unknown(&mut self) {
+        self.beneficiary_vasp = Some(Box::new(BeneficiaryVASP {
+            beneficiary_vasp: None,
+        }));
+    }
+
+    /// Removes the originator.
+    pub fn clear_originator(&mut self) {
+        self.originator = None;
+    }
+
+    /// Removes the beneficiary.
+    pub fn clear_beneficiary(&mut self) {
+        self.beneficiary = None;
+    }
+
+    /// Removes the originating VASP.
+    pub fn clear_originating_vasp(&mut self) {
+        self.originating_vasp = None;
+    }
+
+    /// Removes the beneficiary VASP, marking it as not applicable, see
+    /// [`VaspStatus::Absent`].
+    pub fn clear_beneficiary_vasp(&mut self) {
+        self.beneficiary_vasp = None;
+    }
+
+    /// Constructs the payload of a withdrawal to an unhosted wallet, i.e.
+    /// with a natural person as the beneficiary, identified by name and
+    /// wallet address only, and without a beneficiary VASP.
+    ///
+    /// The originating VASP is left empty and is to be set by the caller.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the construction or the validation of the
+    /// payload fails.
+    pub fn for_unhosted_withdrawal(
+        originator: Person,
+        originator_account: &str,
+        beneficiary_first_name: &str,
+        beneficiary_last_name: &str,
+        wallet_address: &str,
+    ) -> Result<Self, Error> {
+        let ivms = Self {
+            originator: Some(Box::new(Originator {
+                originator_persons: originator.into(),
+                account_number: Some(originator_account.try_into()?).into(),
+            })),
+            beneficiary: Some(Box::new(Beneficiary::unhosted(
+                beneficiary_first_name,
+                beneficiary_last_name,
+                wallet_address,
+            )?)),
+            originating_vasp: None,
+            beneficiary_vasp: None,
+        };
+        ivms.validate()?;
+        Ok(ivms)
+    }
+
+    /// Applies `f` to the payload and validates the result, rolling back
+    /// the edit if it violates any constraint.
+    ///
+    /// # Errors
+    ///
+    /// Returns the failures of [`Validatable::validate_all`] for the edited
+    /// payload, in which case `self` is left unchanged.
+    pub fn edit(&mut self, f: impl FnOnce(&mut IVMS101)) -> Result<(), ValidationFailures> {
+        let previous = self.clone();
+        f(self);
+        self.validate_all().map_err(|failures| {
+            *self = previous;
+            failures
+        })
+    }
+
+    /// Combines partial payloads, e.g. one holding the originator and
+    /// another one holding the VASPs, into one payload.
+    ///
+    /// Each section is taken from the last payload that has it, i.e. the
+    /// last writer wins and sections are never merged field by field.
+    /// Sections absent from all payloads stay absent. The result is not
+    /// validated.
+    #[must_use]
+    pub fn combine(iter: impl IntoIterator<Item = IVMS101>) -> IVMS101 {
+        let mut combined = IVMS101::default();
+        for ivms in iter {
+            combined.originator = ivms.originator.or(combined.originator);
+            combined.beneficiary = ivms.beneficiary.or(combined.beneficiary);
+            combined.originating_vasp = ivms.originating_vasp.or(combined.originating_vasp);
+            combined.beneficiary_vasp = ivms.beneficiary_vasp.or(combined.beneficiary_vasp);
         }
-        if let Some(ov) = &self.originating_vasp {
-            ov.validate()?;
+        combined
+    }
+}
+
+impl FromIterator<IVMS101> for IVMS101 {
+    /// Combines partial payloads, see [`IVMS101::combine`].
+    fn from_iter<T: IntoIterator<Item = IVMS101>>(iter: T) -> Self {
+        Self::combine(iter)
+    }
+}
+
+#[allow(deprecated)]
+impl Validatable for IVMS101 {
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), Error> {
+        let sections: [(&str, &dyn Validatable); 4] = [
+            ("originator", &self.originator),
+            ("beneficiary", &self.beneficiary),
+            ("originatingVASP", &self.originating_vasp),
+            ("beneficiaryVASP", &self.beneficiary_vasp),
+        ];
+        sections.into_iter().try_for_each(|(section, value)| {
+            value.validate_with(options).map_err(|e| e.at(section))
+        })
+    }
+
+    fn validate_all_with(&self, options: &ValidationOptions) -> Result<(), ValidationFailures> {
+        let sections: [(&str, Result<(), ValidationFailures>); 4] = [
+            ("originator", self.originator.validate_all_with(options)),
+            ("beneficiary", self.beneficiary.validate_all_with(options)),
+            (
+                "originatingVASP",
+                self.originating_vasp.validate_all_with(options),
+            ),
+            (
+                "beneficiaryVASP",
+                self.beneficiary_vasp.validate_all_with(options),
+            ),
+        ];
+        let errors: Vec<Error> = sections
+            .into_iter()
+            .filter_map(|(section, result)| Some((section, result.err()?)))
+            .flat_map(|(section, errors)| errors.into_iter().map(move |e| e.at(section)))
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.into())
         }
-        if let Some(bv) = &self.beneficiary_vasp {
-            bv.validate()?;
+    }
+}
+
+/// Validates the addresses of a person, attributing errors to the index
+/// of the address.
+fn validate_addresses(
+    addresses: &ZeroToN<Address>,
+    options: &ValidationOptions,
+) -> Result<(), Error> {
+    addresses.iter().enumerate().try_for_each(|(i, address)| {
+        address
+            .validate_with(options)
+            .map_err(|e| e.at(&format!("geographicAddress[{i}]")))
+    })
+}
+
+/// Validates each person on its own, attributing errors to the person's
+/// index in `field`.
+fn validate_persons(
+    persons: &OneToN<Person>,
+    field: &str,
+    validate: impl Fn(&Person) -> Result<(), Error>,
+) -> Result<(), ValidationFailures> {
+    let errors: Vec<Error> = persons
+        .iter()
+        .enumerate()
+        .filter_map(|(i, person)| {
+            validate(person)
+                .err()
+                .map(|e| e.at(&format!("{field}[{i}]")))
+        })
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.into())
+    }
+}
+
+#[allow(deprecated)]
+impl std::fmt::Display for IVMS101 {
+    /// Renders a one-line summary of the parties suitable for logs, e.g.
+    /// `Originator: F. Engels (DE) -> Beneficiary: Company A (CH);
+    /// Originating VASP LEI: 2594007XIACKNMUAW223`.
+    ///
+    /// Only initials of first names and the countries of addresses are
+    /// included. Absent sections are rendered as `-`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("Originator: ")?;
+        format_persons(f, self.originator().map(|o| &o.originator_persons))?;
+        f.write_str(" -> Beneficiary: ")?;
+        format_persons(f, self.beneficiary().map(|b| &b.beneficiary_persons))?;
+        if let Some(Ok(Some(lei))) = self.originating_vasp().map(OriginatingVASP::lei) {
+            write!(f, "; Originating VASP LEI: {lei}")?;
         }
         Ok(())
     }
 }
 
+/// Formats the persons of the section, if any, for the summary of
+/// [`IVMS101`].
+#[allow(deprecated)]
+fn format_persons(
+    f: &mut std::fmt::Formatter,
+    persons: Option<&OneToN<Person>>,
+) -> std::fmt::Result {
+    let Some(persons) = persons else {
+        return f.write_str("-");
+    };
+    for (i, person) in persons.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        if let Some(initial) = person.first_name().and_then(|n| n.chars().next()) {
+            write!(f, "{initial}. ")?;
+        }
+        f.write_str(&person.last_name())?;
+        if let Some(address) = person.address() {
+            write!(f, " ({})", address.country.as_str())?;
+        }
+    }
+    Ok(())
+}
+
 /// The transaction originator.
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "strict-fields", serde(deny_unknown_fields))]
 pub struct Originator {
     /// The persons forming the originator.
+    #[serde(deserialize_with = "types::one_to_n::fields::originator_persons")]
+    #[serde(alias = "originator_persons")]
+    #[deprecated(note = "use `Originator::persons`, the field will become private")]
     pub originator_persons: OneToN<Person>,
     /// The account number of the originator.
     #[serde(default, skip_serializing_if = "ZeroToN::is_empty")]
+    #[serde(deserialize_with = "types::zero_to_n::fields::account_number")]
+    #[serde(alias = "account_number")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>", optional))]
+    #[deprecated(note = "use `Originator::account_numbers`, the field will become private")]
     pub account_number: ZeroToN<types::StringMax100>,
 }
 
+#[allow(deprecated)]
 impl Validatable for Originator {
-    fn validate(&self) -> Result<(), Error> {
-        for person in self.originator_persons.clone() {
-            if let Person::NaturalPerson(np) = &person {
-                if np.geographic_address.is_empty()
-                    && np.customer_identification.is_none()
-                    && np.national_identification.is_none()
-                    && np.date_and_place_of_birth.is_none()
-                {
-                    return Err(
-                        "Natural person: one of 1) geographic address 2) customer id 3) national id 4) date and place of birth is required (IVMS101 C1)".into());
-                }
-            };
-            person.validate()?;
-        }
-        Ok(())
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), Error> {
+        self.originator_persons
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, person)| {
+                Self::validate_person(person, options)
+                    .map_err(|e| e.at(&format!("originatorPersons[{i}]")))
+            })
+    }
+
+    fn validate_all_with(&self, options: &ValidationOptions) -> Result<(), ValidationFailures> {
+        validate_persons(&self.originator_persons, "originatorPersons", |person| {
+            Self::validate_person(person, options)
+        })
+    }
+}
+
+impl Originator {
+    fn validate_person(person: &Person, options: &ValidationOptions) -> Result<(), Error> {
+        if let Person::NaturalPerson(np) = person {
+            np.check_c1()?;
+        };
+        person.validate_with(options)
     }
 }
 
+#[allow(deprecated)]
 impl Originator {
     /// Constructs an `Originator` with the given person.
     ///
@@ -100,29 +564,102 @@ impl Originator {
             account_number: None.into(),
         })
     }
+
+    /// The persons forming the originator.
+    #[must_use]
+    pub fn persons(&self) -> &OneToN<Person> {
+        &self.originator_persons
+    }
+
+    /// Replaces the persons forming the originator.
+    pub fn set_persons(&mut self, persons: OneToN<Person>) {
+        self.originator_persons = persons;
+    }
+
+    /// Returns an iterator over the account numbers of the originator.
+    pub fn account_numbers(&self) -> impl Iterator<Item = &str> + '_ {
+        self.account_number.iter().map(types::StringMax100::as_str)
+    }
+
+    /// Adds an account number of the originator.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the account number exceeds 100 bytes.
+    pub fn add_account_number(&mut self, account_number: &str) -> Result<(), Error> {
+        let account_number = account_number.try_into()?;
+        self.account_number = std::mem::take(&mut self.account_number)
+            .into_iter()
+            .chain([account_number])
+            .collect::<Vec<_>>()
+            .into();
+        Ok(())
+    }
+
+    /// Removes an account number of the originator, returning whether it
+    /// was present.
+    pub fn remove_account_number(&mut self, account_number: &str) -> bool {
+        let count = self.account_number.iter().count();
+        self.account_number = std::mem::take(&mut self.account_number)
+            .into_iter()
+            .filter(|number| number.as_str() != account_number)
+            .collect::<Vec<_>>()
+            .into();
+        self.account_number.iter().count() < count
+    }
+
+    /// Returns the number of persons forming the originator.
+    pub fn person_count(&self) -> usize {
+        self.originator_persons.len()
+    }
+
+    /// Indicates whether the originator is formed by a single person.
+    pub fn is_single_person(&self) -> bool {
+        self.person_count() == 1
+    }
 }
 
 /// The transaction beneficiary.
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "strict-fields", serde(deny_unknown_fields))]
 pub struct Beneficiary {
     /// The persons forming the beneficiary.
+    #[serde(deserialize_with = "types::one_to_n::fields::beneficiary_persons")]
+    #[serde(alias = "beneficiary_persons")]
+    #[deprecated(note = "use `Beneficiary::persons`, the field will become private")]
     pub beneficiary_persons: OneToN<Person>,
     /// The account number of the beneficiary.
     #[serde(default, skip_serializing_if = "ZeroToN::is_empty")]
+    #[serde(deserialize_with = "types::zero_to_n::fields::account_number")]
+    #[serde(alias = "account_number")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>", optional))]
+    #[deprecated(note = "use `Beneficiary::account_numbers`, the field will become private")]
     pub account_number: ZeroToN<types::StringMax100>,
 }
 
+#[allow(deprecated)]
 impl Validatable for Beneficiary {
-    fn validate(&self) -> Result<(), Error> {
-        for person in self.beneficiary_persons.clone() {
-            person.validate()?;
-        }
-        Ok(())
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), Error> {
+        self.beneficiary_persons
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, person)| {
+                person
+                    .validate_with(options)
+                    .map_err(|e| e.at(&format!("beneficiaryPersons[{i}]")))
+            })
+    }
+
+    fn validate_all_with(&self, options: &ValidationOptions) -> Result<(), ValidationFailures> {
+        validate_persons(&self.beneficiary_persons, "beneficiaryPersons", |person| {
+            person.validate_with(options)
+        })
     }
 }
 
+#[allow(deprecated)]
 impl Beneficiary {
     /// Constructs a `Beneficiary` with the given person and account number.
     ///
@@ -135,17 +672,95 @@ impl Beneficiary {
             account_number: account_number.map(TryInto::try_into).transpose()?.into(),
         })
     }
+
+    /// Constructs the `Beneficiary` of a transfer to an unhosted wallet,
+    /// which is a natural person identified by name and wallet address.
+    ///
+    /// Other than the originator, such a beneficiary has neither an
+    /// address nor a national identification.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Error`] if the construction or the validation of the
+    /// beneficiary fails.
+    pub fn unhosted(
+        first_name: &str,
+        last_name: &str,
+        wallet_address: &str,
+    ) -> Result<Self, Error> {
+        let person = NaturalPerson::new(first_name, last_name, None, None)?;
+        let beneficiary = Self::new(person.into(), Some(wallet_address))?;
+        beneficiary.validate()?;
+        Ok(beneficiary)
+    }
+
+    /// The persons forming the beneficiary.
+    #[must_use]
+    pub fn persons(&self) -> &OneToN<Person> {
+        &self.beneficiary_persons
+    }
+
+    /// Replaces the persons forming the beneficiary.
+    pub fn set_persons(&mut self, persons: OneToN<Person>) {
+        self.beneficiary_persons = persons;
+    }
+
+    /// Returns an iterator over the account numbers of the beneficiary.
+    pub fn account_numbers(&self) -> impl Iterator<Item = &str> + '_ {
+        self.account_number.iter().map(types::StringMax100::as_str)
+    }
+
+    /// Adds an account number of the beneficiary.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the account number exceeds 100 bytes.
+    pub fn add_account_number(&mut self, account_number: &str) -> Result<(), Error> {
+        let account_number = account_number.try_into()?;
+        self.account_number = std::mem::take(&mut self.account_number)
+            .into_iter()
+            .chain([account_number])
+            .collect::<Vec<_>>()
+            .into();
+        Ok(())
+    }
+
+    /// Removes an account number of the beneficiary, returning whether it
+    /// was present.
+    pub fn remove_account_number(&mut self, account_number: &str) -> bool {
+        let count = self.account_number.iter().count();
+        self.account_number = std::mem::take(&mut self.account_number)
+            .into_iter()
+            .filter(|number| number.as_str() != account_number)
+            .collect::<Vec<_>>()
+            .into();
+        self.account_number.iter().count() < count
+    }
+
+    /// Returns the number of persons forming the beneficiary.
+    pub fn person_count(&self) -> usize {
+        self.beneficiary_persons.len()
+    }
+
+    /// Indicates whether the beneficiary is formed by a single person.
+    pub fn is_single_person(&self) -> bool {
+        self.person_count() == 1
+    }
 }
 
 /// The originating VASP wrapper.
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "strict-fields", serde(deny_unknown_fields))]
 pub struct OriginatingVASP {
     /// The originating VASP.
     #[serde(rename = "originatingVASP")]
+    #[serde(alias = "originating_vasp")]
+    #[deprecated(note = "use `OriginatingVASP::person`, the field will become private")]
     pub originating_vasp: Person,
 }
 
+#[allow(deprecated)]
 impl OriginatingVASP {
     /// Constructs an `OriginatingVASP` with the given name and LEI.
     ///
@@ -154,29 +769,16 @@ impl OriginatingVASP {
     /// Returns a `Error` if the validation of the name fails.
     pub fn new(name: &str, lei: &lei::LEI) -> Result<Self, Error> {
         Ok(Self {
-            originating_vasp: Person::LegalPerson(LegalPerson {
-                name: LegalPersonName {
-                    name_identifier: LegalPersonNameID {
-                        legal_person_name: name.try_into()?,
-                        legal_person_name_identifier_type: LegalPersonNameTypeCode::Legal,
-                    }
-                    .into(),
-                    local_name_identifier: None.into(),
-                    phonetic_name_identifier: None.into(),
-                },
-                geographic_address: ZeroToN::None,
-                customer_identification: None,
-                national_identification: Some(NationalIdentification {
-                    national_identifier: lei.to_string().as_str().try_into().unwrap(),
-                    national_identifier_type: NationalIdentifierTypeCode::LegalEntityIdentifier,
-                    country_of_issue: None,
-                    registration_authority: None,
-                }),
-                country_of_registration: None,
-            }),
+            originating_vasp: LegalPerson::vasp(name, lei)?.into(),
         })
     }
 
+    /// The originating VASP.
+    #[must_use]
+    pub fn person(&self) -> &Person {
+        &self.originating_vasp
+    }
+
     /// Returns the LEI of the originating VASP
     ///
     /// # Errors
@@ -188,41 +790,139 @@ impl OriginatingVASP {
     }
 }
 
+#[allow(deprecated)]
 impl Validatable for OriginatingVASP {
-    fn validate(&self) -> Result<(), Error> {
-        self.originating_vasp.validate()
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), Error> {
+        self.originating_vasp.validate_with(options)?;
+        ensure_legal_person(&self.originating_vasp, "Originating VASP")?;
+        // An invalid LEI already fails C11 above.
+        match self.lei() {
+            Ok(Some(_)) => Ok(()),
+            _ => Err("Originating VASP must be identified by an LEI".into()),
+        }
     }
 }
 
 /// The beneficiary VASP wrapper.
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "strict-fields", serde(deny_unknown_fields))]
 pub struct BeneficiaryVASP {
     /// The beneficiary VASP.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "beneficiaryVASP")]
+    #[serde(alias = "beneficiary_vasp")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(note = "use `BeneficiaryVASP::person`, the field will become private")]
     pub beneficiary_vasp: Option<Person>,
 }
 
+#[allow(deprecated)]
+impl BeneficiaryVASP {
+    /// The beneficiary VASP, if it is known.
+    #[must_use]
+    pub fn person(&self) -> Option<&Person> {
+        self.beneficiary_vasp.as_ref()
+    }
+}
+
+#[allow(deprecated)]
 impl Validatable for BeneficiaryVASP {
-    fn validate(&self) -> Result<(), Error> {
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), Error> {
         match &self.beneficiary_vasp {
             None => Ok(()),
-            Some(p) => p.validate(),
+            Some(p) => {
+                p.validate_with(options)?;
+                ensure_legal_person(p, "Beneficiary VASP")
+            }
         }
     }
 }
 
+/// The status of the beneficiary VASP of a payload, see
+/// [`IVMS101::beneficiary_vasp_status`].
+///
+/// Counterparties interpret the two ways of leaving out the beneficiary
+/// VASP differently: an empty `"beneficiaryVASP": {}` wrapper states that
+/// the beneficiary VASP is unknown, whereas omitting the section states
+/// that there is none, e.g. for a transfer to an unhosted wallet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VaspStatus<'a> {
+    /// The beneficiary VASP is given.
+    Known(&'a Person),
+    /// The section is present but empty.
+    Unknown,
+    /// The section is omitted.
+    Absent,
+}
+
+/// Checks that the VASP in the given role is a legal person, as VASPs
+/// are always legal entities.
+fn ensure_legal_person(vasp: &Person, role: &str) -> Result<(), Error> {
+    match vasp {
+        Person::LegalPerson(_) => Ok(()),
+        Person::NaturalPerson(_) => Err(format!("{role} must be a legal person").as_str().into()),
+    }
+}
+
 /// Either a natural or a legal person.
+///
+/// Both variants share addresses as well as customer and national
+/// identifications. A date and place of birth and a country of residence
+/// only apply to natural persons, a country of registration only to legal
+/// persons.
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "strict-fields", serde(deny_unknown_fields))]
 pub enum Person {
+    #[serde(alias = "natural_person")]
     NaturalPerson(NaturalPerson),
+    #[serde(alias = "legal_person")]
     LegalPerson(LegalPerson),
 }
 
+/// The kind of a [`Person`], e.g. to label metrics or log entries.
+///
+/// It is displayed in lowercase, i.e. as "natural" or "legal".
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PersonKind {
+    /// A [`NaturalPerson`].
+    Natural,
+    /// A [`LegalPerson`].
+    Legal,
+}
+
+impl std::fmt::Display for PersonKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Natural => "natural",
+            Self::Legal => "legal",
+        })
+    }
+}
+
+#[allow(deprecated)]
 impl Person {
+    /// Returns whether the person is a natural or a legal person.
+    #[must_use]
+    pub fn kind(&self) -> PersonKind {
+        match self {
+            Self::NaturalPerson(_) => PersonKind::Natural,
+            Self::LegalPerson(_) => PersonKind::Legal,
+        }
+    }
+
+    /// Returns the advisory findings for the person, see
+    /// [`NaturalPerson::warnings`] and [`LegalPerson::warnings`].
+    #[must_use]
+    pub fn warnings(&self) -> Vec<Warning> {
+        match self {
+            Self::NaturalPerson(p) => p.warnings(),
+            Self::LegalPerson(p) => p.warnings(),
+        }
+    }
+
     /// The first name of the person.
     #[must_use]
     pub fn first_name(&self) -> Option<String> {
@@ -237,11 +937,23 @@ impl Person {
     pub fn last_name(&self) -> String {
         match self {
             Self::NaturalPerson(p) => p.last_name(),
-            Self::LegalPerson(p) => p.name(),
+            Self::LegalPerson(p) => p.legal_name(),
         }
     }
 
-    /// The address of the person.
+    /// The full name of the person: the first and last name of a natural
+    /// person, or the last name only if there is no first name, and the
+    /// name of a legal person.
+    #[must_use]
+    pub fn full_name(&self) -> String {
+        match self.first_name().filter(|first| !first.is_empty()) {
+            Some(first) => format!("{first} {}", self.last_name()),
+            None => self.last_name(),
+        }
+    }
+
+    /// The first address of the person, regardless of its type. See
+    /// [`Person::primary_address`] to prefer addresses by type.
     #[must_use]
     pub fn address(&self) -> Option<&Address> {
         match self {
@@ -250,6 +962,75 @@ impl Person {
         }
     }
 
+    /// Returns an iterator over the addresses of the person of the given
+    /// type.
+    pub fn addresses_of_type(
+        &self,
+        address_type: AddressTypeCode,
+    ) -> impl Iterator<Item = &Address> + '_ {
+        let addresses = match self {
+            Self::NaturalPerson(p) => &p.geographic_address,
+            Self::LegalPerson(p) => &p.geographic_address,
+        };
+        addresses_of_type(addresses, address_type)
+    }
+
+    /// The primary address of the person, see
+    /// [`NaturalPerson::primary_address`].
+    #[must_use]
+    pub fn primary_address(&self) -> Option<&Address> {
+        match self {
+            Self::NaturalPerson(p) => p.primary_address(),
+            Self::LegalPerson(p) => p.primary_address(),
+        }
+    }
+
+    /// Returns the countries associated with the person without
+    /// duplicates: the country of residence or registration, followed by
+    /// the countries of the addresses and the country of issue of the
+    /// national identification.
+    #[must_use]
+    pub fn associated_countries(&self) -> Vec<&CountryCode> {
+        let (own, addresses, identification) = match self {
+            Self::NaturalPerson(p) => (
+                p.country_of_residence.as_ref(),
+                &p.geographic_address,
+                &p.national_identification,
+            ),
+            Self::LegalPerson(p) => (
+                p.country_of_registration.as_ref(),
+                &p.geographic_address,
+                &p.national_identification,
+            ),
+        };
+        let mut countries = Vec::new();
+        for country in own
+            .into_iter()
+            .chain(addresses.iter().map(|a| &a.country))
+            .chain(
+                identification
+                    .iter()
+                    .filter_map(|i| i.country_of_issue.as_ref()),
+            )
+        {
+            if !countries.contains(&country) {
+                countries.push(country);
+            }
+        }
+        countries
+    }
+
+    /// Indicates whether any of the [associated countries] is in `set`,
+    /// e.g. a list of sanctioned countries for screening.
+    ///
+    /// [associated countries]: Person::associated_countries
+    #[must_use]
+    pub fn touches_any(&self, set: &[CountryCode]) -> bool {
+        self.associated_countries()
+            .into_iter()
+            .any(|country| set.contains(country))
+    }
+
     /// The customer identification of the person.
     #[must_use]
     pub fn customer_identification(&self) -> Option<String> {
@@ -269,60 +1050,437 @@ impl Person {
     }
 }
 
+impl From<NaturalPerson> for Person {
+    fn from(person: NaturalPerson) -> Self {
+        Self::NaturalPerson(person)
+    }
+}
+
+impl From<LegalPerson> for Person {
+    fn from(person: LegalPerson) -> Self {
+        Self::LegalPerson(person)
+    }
+}
+
 impl Validatable for Person {
-    fn validate(&self) -> Result<(), Error> {
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), Error> {
         match self {
-            Person::NaturalPerson(p) => p.validate(),
-            Person::LegalPerson(p) => p.validate(),
+            Person::NaturalPerson(p) => p.validate_with(options),
+            Person::LegalPerson(p) => p.validate_with(options),
         }
     }
 }
 
 /// A natural person.
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "strict-fields", serde(deny_unknown_fields))]
 pub struct NaturalPerson {
     /// The name.
+    #[serde(deserialize_with = "types::one_to_n::fields::name")]
+    #[deprecated(note = "use `NaturalPerson::names`, the field will become private")]
     pub name: OneToN<NaturalPersonName>,
     /// The geographic address.
     #[serde(default, skip_serializing_if = "ZeroToN::is_empty")]
+    #[serde(deserialize_with = "types::zero_to_n::fields::geographic_address")]
+    #[serde(alias = "geographic_address")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>", optional))]
+    #[deprecated(note = "use `NaturalPerson::addresses`, the field will become private")]
     pub geographic_address: ZeroToN<Address>,
     /// The national identification.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "national_identification")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(
+        note = "use `NaturalPerson::national_identification`, the field will become private"
+    )]
     pub national_identification: Option<NationalIdentification>,
     /// The customer identification.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "customer_identification")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(
+        note = "use `NaturalPerson::customer_identification`, the field will become private"
+    )]
     pub customer_identification: Option<types::StringMax50>,
     /// The date and place of birth.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "date_and_place_of_birth")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(
+        note = "use `NaturalPerson::date_and_place_of_birth`, the field will become private"
+    )]
     pub date_and_place_of_birth: Option<DateAndPlaceOfBirth>,
     /// The country of residence.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "country_of_residence")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(
+        note = "use `NaturalPerson::country_of_residence`, the field will become private"
+    )]
     pub country_of_residence: Option<CountryCode>,
 }
 
+#[allow(deprecated)]
 impl NaturalPerson {
-    /// Constructs a `NaturalPerson`.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the validation of the first name, last name
-    /// or customer identification fails.
-    pub fn new(
+    /// Returns an iterator over the names.
+    pub fn names(&self) -> impl Iterator<Item = &NaturalPersonName> + '_ {
+        self.name.iter()
+    }
+
+    /// Returns an iterator over the geographic addresses.
+    pub fn addresses(&self) -> impl Iterator<Item = &Address> + '_ {
+        self.geographic_address.iter()
+    }
+
+    /// The national identification.
+    #[must_use]
+    pub fn national_identification(&self) -> Option<&NationalIdentification> {
+        self.national_identification.as_ref()
+    }
+
+    /// Sets the national identification.
+    pub fn set_national_identification(&mut self, id: Option<NationalIdentification>) {
+        self.national_identification = id;
+    }
+
+    /// Replaces the names of the person with `name`.
+    pub fn set_name(&mut self, name: NaturalPersonName) {
+        self.name = name.into();
+    }
+
+    /// Adds a name of the person, e.g. a former name.
+    pub fn add_name(&mut self, name: NaturalPersonName) {
+        self.name = self.name.clone().union(name.into());
+    }
+
+    /// The customer identification.
+    #[must_use]
+    pub fn customer_identification(&self) -> Option<&str> {
+        self.customer_identification
+            .as_ref()
+            .map(types::StringMax50::as_str)
+    }
+
+    /// Sets or, given `None`, removes the customer identification.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the customer identification exceeds 50 bytes,
+    /// or if it is removed and the person would no longer satisfy C1. The
+    /// customer identification is left unchanged in either case.
+    pub fn set_customer_identification(&mut self, id: Option<&str>) -> Result<(), Error> {
+        let id = id
+            .map(|id| BoundedString::for_field(id, "Customer identification"))
+            .transpose()?;
+        let previous = std::mem::replace(&mut self.customer_identification, id);
+        self.check_c1().map_err(|e| {
+            self.customer_identification = previous;
+            e
+        })
+    }
+
+    /// The date and place of birth.
+    #[must_use]
+    pub fn date_and_place_of_birth(&self) -> Option<&DateAndPlaceOfBirth> {
+        self.date_and_place_of_birth.as_ref()
+    }
+
+    /// Sets the date and place of birth.
+    pub fn set_date_and_place_of_birth(&mut self, birth: Option<DateAndPlaceOfBirth>) {
+        self.date_and_place_of_birth = birth;
+    }
+
+    /// The country of residence.
+    #[must_use]
+    pub fn country_of_residence(&self) -> Option<&CountryCode> {
+        self.country_of_residence.as_ref()
+    }
+
+    /// Sets the country of residence.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `country` is not a valid country code, in which
+    /// case the country of residence is left unchanged.
+    pub fn set_country_of_residence(&mut self, country: &str) -> Result<(), Error> {
+        self.country_of_residence = Some(country.try_into()?);
+        Ok(())
+    }
+
+    /// Returns an iterator over the addresses of the given type.
+    pub fn addresses_of_type(
+        &self,
+        address_type: AddressTypeCode,
+    ) -> impl Iterator<Item = &Address> + '_ {
+        addresses_of_type(&self.geographic_address, address_type)
+    }
+
+    /// Returns the primary address of the natural person, which is the first
+    /// geographic (`GEOG`) address, or else the first business (`BIZZ`)
+    /// or residential (`HOME`) address, in this order.
+    #[must_use]
+    pub fn primary_address(&self) -> Option<&Address> {
+        primary_address(&self.geographic_address)
+    }
+
+    /// Returns the advisory findings for the natural person:
+    ///
+    /// - [`Warning::AddressCountryMismatch`] if none of the addresses is
+    ///   in the country of residence.
+    /// - [`Warning::NonLatinNameWithoutPhoneticName`] if a name is given
+    ///   in a non-Latin script only.
+    /// - [`Warning::IdentityDocumentWithoutCountryOfIssue`] if the national
+    ///   identification is a passport, identity card or driver's license
+    ///   number without a country of issue. This is an error when
+    ///   validating at [`ValidationLevel::Strict`].
+    /// - [`Warning::MalformedPassportNumber`] if the national
+    ///   identification is a passport number that is not alphanumeric or
+    ///   not between 5 and 15 characters long.
+    #[must_use]
+    pub fn warnings(&self) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        if self
+            .national_identification
+            .as_ref()
+            .is_some_and(NationalIdentification::lacks_country_of_issue)
+        {
+            warnings.push(Warning::IdentityDocumentWithoutCountryOfIssue);
+        }
+        if self
+            .national_identification
+            .as_ref()
+            .is_some_and(NationalIdentification::is_malformed_passport_number)
+        {
+            warnings.push(Warning::MalformedPassportNumber);
+        }
+        if validation::address_country_mismatch(
+            &self.geographic_address,
+            self.country_of_residence.as_ref(),
+        ) {
+            warnings.push(Warning::AddressCountryMismatch);
+        }
+        if self.name.iter().any(|name| {
+            name.phonetic_name_identifier.is_empty()
+                && name
+                    .name_identifier
+                    .iter()
+                    .chain(name.local_name_identifier.iter())
+                    .any(|id| {
+                        validation::is_non_latin(id.primary_identifier.as_str())
+                            || id
+                                .secondary_identifier
+                                .as_ref()
+                                .is_some_and(|s| validation::is_non_latin(s.as_str()))
+                    })
+        }) {
+            warnings.push(Warning::NonLatinNameWithoutPhoneticName);
+        }
+        warnings
+    }
+
+    /// Makes the person satisfy C1 by attaching a placeholder address in
+    /// `fallback_country` if it has neither a geographic address, a
+    /// customer identification, a national identification nor a date and
+    /// place of birth. Otherwise, the person is left unchanged.
+    ///
+    /// This is a last resort for originators of whom only the name is
+    /// known. The address carries no information and its placeholder
+    /// values are rejected by [`ValidationLevel::Strict`] validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `fallback_country` is not a valid country code.
+    pub fn ensure_c1(&mut self, fallback_country: &str) -> Result<(), Error> {
+        if self.geographic_address.is_empty()
+            && self.customer_identification.is_none()
+            && self.national_identification.is_none()
+            && self.date_and_place_of_birth.is_none()
+        {
+            let address = Address {
+                address_type: AddressTypeCode::Geographic,
+                department: None,
+                sub_department: None,
+                street_name: None,
+                building_number: None,
+                building_name: None,
+                floor: None,
+                post_box: None,
+                room: None,
+                post_code: None,
+                town_name: "UNKNOWN".try_into()?,
+                town_location_name: None,
+                district_name: None,
+                country_sub_division: None,
+                address_line: Some("UNKNOWN".try_into()?).into(),
+                country: fallback_country.try_into()?,
+            };
+            self.geographic_address = Some(address).into();
+        }
+        Ok(())
+    }
+
+    /// Replaces the geographic addresses of the person.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `addresses` is empty and the person would no
+    /// longer satisfy C1, which applies to originators. The addresses are
+    /// left unchanged in that case.
+    pub fn set_addresses(&mut self, addresses: Vec<Address>) -> Result<(), Error> {
+        let addresses = ZeroToN::from(addresses).normalize();
+        if addresses.is_empty() {
+            return self.clear_addresses();
+        }
+        self.geographic_address = addresses;
+        Ok(())
+    }
+
+    /// Removes the geographic addresses of the person.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the person would no longer satisfy C1, which
+    /// applies to originators, in which case the addresses are left
+    /// unchanged.
+    pub fn clear_addresses(&mut self) -> Result<(), Error> {
+        let addresses = std::mem::replace(&mut self.geographic_address, ZeroToN::None);
+        self.check_c1().map_err(|e| {
+            self.geographic_address = addresses;
+            e
+        })
+    }
+
+    /// Checks C1, which requires a natural person originator to have one
+    /// of a geographic address, customer identification, national
+    /// identification or date and place of birth.
+    fn check_c1(&self) -> Result<(), Error> {
+        validation::ensure(
+            "C1",
+            !self.geographic_address.is_empty()
+                || self.customer_identification.is_some()
+                || self.national_identification.is_some()
+                || self.date_and_place_of_birth.is_some(),
+            "Natural person: one of 1) geographic address 2) customer id 3) national id 4) date and place of birth is required",
+        )
+    }
+
+    /// Merges another record of the same person into this one, e.g. when
+    /// two data sources describe the same party.
+    ///
+    /// The name identifiers of all names of `other` are added to the
+    /// first name of this person, and the addresses of `other` are added
+    /// to the addresses of this person, skipping duplicates. The customer
+    /// identification, national identification, date and place of birth
+    /// and country of residence are taken from `other` only if missing
+    /// here. If both records have differing values, this person's are
+    /// kept.
+    pub fn merge(&mut self, other: &NaturalPerson) {
+        let mut names = self.name.to_vec();
+        let first = &mut names[0];
+        for name in other.name.iter() {
+            first.name_identifier = first
+                .name_identifier
+                .clone()
+                .union(name.name_identifier.clone());
+            first.local_name_identifier = first
+                .local_name_identifier
+                .clone()
+                .union(name.local_name_identifier.clone());
+            first.phonetic_name_identifier = first
+                .phonetic_name_identifier
+                .clone()
+                .union(name.phonetic_name_identifier.clone());
+        }
+        self.name = OneToN::N(names.try_into().expect("names are not empty")).normalize();
+        self.geographic_address = self
+            .geographic_address
+            .clone()
+            .union(other.geographic_address.clone());
+        if self.customer_identification.is_none() {
+            self.customer_identification = other.customer_identification.clone();
+        }
+        if self.national_identification.is_none() {
+            self.national_identification = other.national_identification.clone();
+        }
+        if self.date_and_place_of_birth.is_none() {
+            self.date_and_place_of_birth = other.date_and_place_of_birth.clone();
+        }
+        if self.country_of_residence.is_none() {
+            self.country_of_residence = other.country_of_residence.clone();
+        }
+    }
+
+    /// Minimizes the data sent about the person by keeping only one of
+    /// the data satisfying C1, in this order of preference: the national
+    /// identification, the date and place of birth, the geographic
+    /// addresses and the customer identification. The others are cleared.
+    ///
+    /// This is a data minimization strategy for counterparties that do
+    /// not require more than C1. Data unrelated to C1, such as the name or
+    /// the country of residence, is left unchanged, as is a person without
+    /// any of the above.
+    pub fn minimize(&mut self) {
+        if self.national_identification.is_some() {
+            self.date_and_place_of_birth = None;
+            self.geographic_address = ZeroToN::None;
+            self.customer_identification = None;
+        } else if self.date_and_place_of_birth.is_some() {
+            self.geographic_address = ZeroToN::None;
+            self.customer_identification = None;
+        } else if !self.geographic_address.is_empty() {
+            self.customer_identification = None;
+        }
+    }
+
+    /// Constructs a `NaturalPerson`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the validation of the first name, last name
+    /// or customer identification fails.
+    pub fn new(
         first_name: &str,
         last_name: &str,
         customer_identification: Option<&str>,
         address: Option<Address>,
+    ) -> Result<Self, Error> {
+        Self::with_legal_name(
+            NaturalPersonNameID::legal_name(last_name, Some(first_name))?,
+            customer_identification,
+            address,
+        )
+    }
+
+    /// Constructs a `NaturalPerson` with a single legal name, as common
+    /// in cultures without family names. Unlike passing an empty first
+    /// name to [`NaturalPerson::new`], no secondary identifier is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the validation of the name or customer
+    /// identification fails.
+    pub fn new_mononym(
+        name: &str,
+        customer_identification: Option<&str>,
+        address: Option<Address>,
+    ) -> Result<Self, Error> {
+        Self::with_legal_name(
+            NaturalPersonNameID::legal_name(name, None)?,
+            customer_identification,
+            address,
+        )
+    }
+
+    fn with_legal_name(
+        name: NaturalPersonNameID,
+        customer_identification: Option<&str>,
+        address: Option<Address>,
     ) -> Result<Self, Error> {
         Ok(Self {
             name: NaturalPersonName {
-                name_identifier: NaturalPersonNameID {
-                    primary_identifier: last_name.try_into()?,
-                    secondary_identifier: Some(first_name.try_into()?),
-                    name_identifier_type: NaturalPersonNameTypeCode::LegalName,
-                }
-                .into(),
+                name_identifier: name.into(),
                 local_name_identifier: None.into(),
                 phonetic_name_identifier: None.into(),
             }
@@ -364,16 +1522,28 @@ impl NaturalPerson {
     }
 }
 
+#[allow(deprecated)]
 impl Validatable for NaturalPerson {
-    fn validate(&self) -> Result<(), Error> {
-        self.name
-            .clone()
-            .into_iter()
-            .try_for_each(|name| name.validate())?;
-        self.geographic_address
-            .clone()
-            .into_iter()
-            .try_for_each(|addr| addr.validate())?;
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), Error> {
+        self.name.iter().enumerate().try_for_each(|(i, name)| {
+            name.validate_with(options)
+                .map_err(|e| e.at(&format!("name[{i}]")))
+        })?;
+        validate_addresses(&self.geographic_address, options)?;
+        if let Some(date_and_place_of_birth) = &self.date_and_place_of_birth {
+            date_and_place_of_birth.validate_with(options)?;
+        }
+        if options.level == ValidationLevel::Strict
+            && self
+                .national_identification
+                .as_ref()
+                .is_some_and(NationalIdentification::lacks_country_of_issue)
+        {
+            return Err(
+                Error::from("Identity document must specify a country of issue")
+                    .at("nationalIdentification.countryOfIssue"),
+            );
+        }
 
         Ok(())
     }
@@ -381,26 +1551,109 @@ impl Validatable for NaturalPerson {
 
 /// The name of a natural person.
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "strict-fields", serde(deny_unknown_fields))]
 pub struct NaturalPersonName {
     /// The name.
+    #[serde(deserialize_with = "OneToN::deserialize_name_identifier")]
+    #[serde(alias = "name_identifier")]
+    #[deprecated(
+        note = "use `NaturalPersonName::name_identifiers`, the field will become private"
+    )]
     pub name_identifier: OneToN<NaturalPersonNameID>,
-    #[serde(default, skip_serializing_if = "ZeroToN::is_empty")]
+    #[serde(
+        default,
+        deserialize_with = "types::zero_to_n::fields::local_name_identifier",
+        skip_serializing_if = "ZeroToN::is_empty"
+    )]
+    #[serde(alias = "local_name_identifier")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>", optional))]
+    #[deprecated(
+        note = "use `NaturalPersonName::local_name_identifiers`, the field will become private"
+    )]
     pub local_name_identifier: ZeroToN<NaturalPersonNameID>,
-    #[serde(default, skip_serializing_if = "ZeroToN::is_empty")]
+    #[serde(
+        default,
+        deserialize_with = "types::zero_to_n::fields::phonetic_name_identifier",
+        skip_serializing_if = "ZeroToN::is_empty"
+    )]
+    #[serde(alias = "phonetic_name_identifier")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>", optional))]
+    #[deprecated(
+        note = "use `NaturalPersonName::phonetic_name_identifiers`, the field will become private"
+    )]
     pub phonetic_name_identifier: ZeroToN<NaturalPersonNameID>,
 }
 
+#[allow(deprecated)]
+impl NaturalPersonName {
+    /// Constructs a name with the given name identifier.
+    #[must_use]
+    pub fn new(name_identifier: NaturalPersonNameID) -> Self {
+        Self {
+            name_identifier: name_identifier.into(),
+            local_name_identifier: ZeroToN::None,
+            phonetic_name_identifier: ZeroToN::None,
+        }
+    }
+
+    /// Adds a name identifier, e.g. an alias.
+    pub fn add_name_identifier(&mut self, id: NaturalPersonNameID) {
+        self.name_identifier = self.name_identifier.clone().union(id.into());
+    }
+
+    /// Adds a local name identifier, i.e. the name in a local script.
+    pub fn add_local_name_identifier(&mut self, id: NaturalPersonNameID) {
+        self.local_name_identifier =
+            std::mem::take(&mut self.local_name_identifier).union(Some(id).into());
+    }
+
+    /// Adds a phonetic name identifier.
+    pub fn add_phonetic_name_identifier(&mut self, id: NaturalPersonNameID) {
+        self.phonetic_name_identifier =
+            std::mem::take(&mut self.phonetic_name_identifier).union(Some(id).into());
+    }
+
+    /// Returns an iterator over the name identifiers.
+    pub fn name_identifiers(&self) -> impl Iterator<Item = &NaturalPersonNameID> + '_ {
+        self.name_identifier.iter()
+    }
+
+    /// Returns an iterator over the local name identifiers.
+    pub fn local_name_identifiers(&self) -> impl Iterator<Item = &NaturalPersonNameID> + '_ {
+        self.local_name_identifier.iter()
+    }
+
+    /// Returns an iterator over the phonetic name identifiers.
+    pub fn phonetic_name_identifiers(&self) -> impl Iterator<Item = &NaturalPersonNameID> + '_ {
+        self.phonetic_name_identifier.iter()
+    }
+}
+
+#[allow(deprecated)]
 impl Validatable for NaturalPersonName {
-    fn validate(&self) -> Result<(), Error> {
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), Error> {
         let has_legl = self
             .name_identifier
             .clone()
             .into_iter()
             .any(|ni| ni.name_identifier_type == NaturalPersonNameTypeCode::LegalName);
-        if !has_legl {
-            return Err("Natural person must have a legal name id (IVMS101 C6)".into());
+        validation::ensure("C6", has_legl, "Natural person must have a legal name id")?;
+        for (field, ids) in [
+            ("nameIdentifier", self.name_identifier.to_vec()),
+            ("localNameIdentifier", self.local_name_identifier.to_vec()),
+            (
+                "phoneticNameIdentifier",
+                self.phonetic_name_identifier.to_vec(),
+            ),
+        ] {
+            for (i, id) in ids.iter().enumerate() {
+                options.check_placeholder(
+                    &format!("{field}[{i}].primaryIdentifier"),
+                    id.primary_identifier.as_str(),
+                )?;
+            }
         }
         Ok(())
     }
@@ -408,76 +1661,216 @@ impl Validatable for NaturalPersonName {
 
 /// The natural person name ID.
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "strict-fields", serde(deny_unknown_fields))]
 pub struct NaturalPersonNameID {
     /// The primary name.
+    #[serde(alias = "primary_identifier")]
+    #[deprecated(
+        note = "use `NaturalPersonNameID::primary_identifier`, the field will become private"
+    )]
     pub primary_identifier: types::StringMax100,
     #[serde(skip_serializing_if = "Option::is_none")]
     /// The secondary name.
+    #[serde(alias = "secondary_identifier")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(
+        note = "use `NaturalPersonNameID::secondary_identifier`, the field will become private"
+    )]
     pub secondary_identifier: Option<types::StringMax100>,
     /// The type of name.
+    #[serde(alias = "name_identifier_type")]
+    #[deprecated(
+        note = "use `NaturalPersonNameID::name_identifier_type`, the field will become private"
+    )]
     pub name_identifier_type: NaturalPersonNameTypeCode,
 }
 
-/// A localized natural person name.
+#[allow(deprecated)]
+impl NaturalPersonNameID {
+    /// Constructs a name identifier of the given type from the primary
+    /// identifier, e.g. the last name, and the optional secondary
+    /// identifier, e.g. the first names.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a name exceeds 100 bytes.
+    pub fn new(
+        primary_identifier: &str,
+        secondary_identifier: Option<&str>,
+        name_identifier_type: NaturalPersonNameTypeCode,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            primary_identifier: primary_identifier.try_into()?,
+            secondary_identifier: secondary_identifier.map(TryInto::try_into).transpose()?,
+            name_identifier_type,
+        })
+    }
+
+    /// Constructs a legal name identifier (`LEGL`), as required for the
+    /// `nameIdentifier` of a natural person by C6.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a name exceeds 100 bytes.
+    pub fn legal_name(
+        primary_identifier: &str,
+        secondary_identifier: Option<&str>,
+    ) -> Result<Self, Error> {
+        Self::new(
+            primary_identifier,
+            secondary_identifier,
+            NaturalPersonNameTypeCode::LegalName,
+        )
+    }
+
+    /// The primary identifier, e.g. the last name.
+    #[must_use]
+    pub fn primary_identifier(&self) -> &str {
+        self.primary_identifier.as_str()
+    }
+
+    /// The secondary identifier, e.g. the first names.
+    #[must_use]
+    pub fn secondary_identifier(&self) -> Option<&str> {
+        self.secondary_identifier
+            .as_ref()
+            .map(types::StringMax100::as_str)
+    }
+
+    /// The type of name.
+    #[must_use]
+    pub fn name_identifier_type(&self) -> &NaturalPersonNameTypeCode {
+        &self.name_identifier_type
+    }
+
+    /// Constructs an alias name identifier (`ALIA`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a name exceeds 100 bytes.
+    pub fn alias(
+        primary_identifier: &str,
+        secondary_identifier: Option<&str>,
+    ) -> Result<Self, Error> {
+        Self::new(
+            primary_identifier,
+            secondary_identifier,
+            NaturalPersonNameTypeCode::Alias,
+        )
+    }
+}
+
+/// A geographic address.
+///
+/// By default, validation requires an address line or a street name
+/// along with a building name or number (C8). An address consisting only
+/// of a post box, town and country is accepted if
+/// [`ValidationOptions::allow_post_box_only`] is set.
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "strict-fields", serde(deny_unknown_fields))]
 pub struct Address {
     /// The address type.
+    #[serde(alias = "address_type")]
+    #[deprecated(note = "use `Address::address_type`, the field will become private")]
     pub address_type: AddressTypeCode,
     /// The department.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(note = "use `Address::department`, the field will become private")]
     pub department: Option<types::StringMax50>,
     /// The sub-department.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "sub_department")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(note = "use `Address::sub_department`, the field will become private")]
     pub sub_department: Option<types::StringMax70>,
     /// The street name.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "street_name")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(note = "use `Address::street_name`, the field will become private")]
     pub street_name: Option<types::StringMax70>,
     /// The building number.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "building_number")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(note = "use `Address::building_number`, the field will become private")]
     pub building_number: Option<types::StringMax16>,
     /// The building name.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "building_name")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(note = "use `Address::building_name`, the field will become private")]
     pub building_name: Option<types::StringMax35>,
     /// The floor.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(note = "use `Address::floor`, the field will become private")]
     pub floor: Option<types::StringMax70>,
     /// The post box.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "post_box")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(note = "use `Address::post_box`, the field will become private")]
     pub post_box: Option<types::StringMax16>,
     /// The room.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(note = "use `Address::room`, the field will become private")]
     pub room: Option<types::StringMax70>,
     /// The postal code.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "post_code")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(note = "use `Address::post_code`, the field will become private")]
     pub post_code: Option<types::StringMax16>,
     /// The name of the town.
+    #[serde(alias = "town_name")]
+    #[deprecated(note = "use `Address::town_name`, the field will become private")]
     pub town_name: types::StringMax35,
     /// The town location name.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "town_location_name")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(note = "use `Address::town_location_name`, the field will become private")]
     pub town_location_name: Option<types::StringMax35>,
     /// The district name.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "district_name")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(note = "use `Address::district_name`, the field will become private")]
     pub district_name: Option<types::StringMax35>,
     /// The country sub-division.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "country_sub_division")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(note = "use `Address::country_sub_division`, the field will become private")]
     pub country_sub_division: Option<types::StringMax35>,
     /// The address lines.
     #[serde(default, skip_serializing_if = "ZeroToN::is_empty")]
+    #[serde(deserialize_with = "types::zero_to_n::fields::address_line")]
+    #[serde(alias = "address_line")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>", optional))]
+    #[deprecated(note = "use `Address::address_line`, the field will become private")]
     pub address_line: ZeroToN<types::StringMax70>,
     /// The country.
+    #[deprecated(note = "use `Address::country`, the field will become private")]
     pub country: CountryCode,
 }
 
+#[allow(deprecated)]
 impl Address {
     /// Constructs an `Address`.
     ///
     /// # Errors
     ///
-    /// Returns an error if the validation of the passed arguments fails.
+    /// Returns an error if the validation of the passed arguments fails,
+    /// naming the field that is too long, e.g. "Building number exceeds
+    /// 16 bytes".
     pub fn new(
         street: Option<&str>,
         number: Option<&str>,
@@ -490,603 +1883,2927 @@ impl Address {
             address_type: AddressTypeCode::Residential,
             department: None,
             sub_department: None,
-            street_name: street.map(TryInto::try_into).transpose()?,
-            building_number: number.map(TryInto::try_into).transpose()?,
+            street_name: street
+                .map(|street| BoundedString::for_field(street, "Street name"))
+                .transpose()?,
+            building_number: number
+                .map(|number| BoundedString::for_field(number, "Building number"))
+                .transpose()?,
             building_name: None,
             floor: None,
             post_box: None,
             room: None,
-            post_code: Some(postal_code.try_into()?),
-            town_name: town.try_into()?,
+            post_code: Some(BoundedString::for_field(postal_code, "Post code")?),
+            town_name: BoundedString::for_field(town, "Town name")?,
             town_location_name: None,
             district_name: None,
             country_sub_division: None,
-            address_line: address_line.map(TryInto::try_into).transpose()?.into(),
+            address_line: address_line
+                .map(|line| BoundedString::for_field(line, "Address line"))
+                .transpose()?
+                .into(),
             country: country.try_into()?,
         })
     }
 
-    /// Returns a string where all address lines have
-    /// been joined with a comma.
+    /// The type of the address.
     #[must_use]
-    pub fn address_lines(&self) -> Option<String> {
-        if self.address_line.is_empty() {
-            None
-        } else {
-            Some(
-                self.address_line
-                    .clone()
-                    .into_iter()
-                    .map(Into::into)
-                    .collect::<Vec<String>>()
-                    .join(", "),
-            )
-        }
+    pub fn address_type(&self) -> &AddressTypeCode {
+        &self.address_type
     }
-}
 
-impl std::fmt::Display for Address {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        format_address(
-            f,
-            self.street_name.as_ref().map(types::StringMax70::as_str),
-            self.building_number
-                .as_ref()
-                .map(types::StringMax16::as_str),
-            self.address_lines().as_deref(),
-            self.post_code.as_ref().map(types::StringMax16::as_str),
-            self.town_name.as_str(),
-            self.country.as_str(),
-        )
+    /// The department.
+    #[must_use]
+    pub fn department(&self) -> Option<&str> {
+        self.department.as_ref().map(types::StringMax50::as_str)
     }
-}
 
-/// Formats the address into a single formatter.
-///
-/// Will smartly handle absent parts to join everything
-/// into a comma-delimited string.
-pub fn format_address(
-    f: &mut std::fmt::Formatter,
-    street: Option<&str>,
-    number: Option<&str>,
-    address_line: Option<&str>,
-    postcode: Option<&str>,
-    town: &str,
-    country_code: &str,
-) -> std::fmt::Result {
-    if let Some(s) = street {
-        write!(f, "{s}")?;
-        if let Some(n) = number {
-            write!(f, " {n}")?;
-        }
-        write!(f, ", ")?;
+    /// The sub-department.
+    #[must_use]
+    pub fn sub_department(&self) -> Option<&str> {
+        self.sub_department.as_ref().map(types::StringMax70::as_str)
     }
-    if let Some(al) = address_line {
-        write!(f, "{al}, ")?;
+
+    /// The street name.
+    #[must_use]
+    pub fn street_name(&self) -> Option<&str> {
+        self.street_name.as_ref().map(types::StringMax70::as_str)
     }
-    if let Some(pc) = postcode {
-        write!(f, "{pc} ")?;
+
+    /// The building number.
+    #[must_use]
+    pub fn building_number(&self) -> Option<&str> {
+        self.building_number
+            .as_ref()
+            .map(types::StringMax16::as_str)
     }
-    write!(
-        f,
-        "{town}, {}",
-        country(country_code.to_lowercase().as_str()).unwrap_or(country_code)
-    )
-}
 
-impl Validatable for Address {
-    fn validate(&self) -> Result<(), Error> {
-        if self.address_line.is_empty()
-            && (self.street_name.is_none()
-                || (self.building_name.is_none() && self.building_number.is_none()))
-        {
-            return Err("Either 1) address line or 2) street name and either building name or building number are required (IVMS101 C8)".into());
-        }
-        Ok(())
+    /// The building name.
+    #[must_use]
+    pub fn building_name(&self) -> Option<&str> {
+        self.building_name.as_ref().map(types::StringMax35::as_str)
     }
-}
 
-/// The date and place of birth.
-#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
-#[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
-pub struct DateAndPlaceOfBirth {
-    /// The date of birth.
-    pub date_of_birth: Date,
-    /// The place of birth.
-    pub place_of_birth: types::StringMax70,
-}
+    /// The floor.
+    #[must_use]
+    pub fn floor(&self) -> Option<&str> {
+        self.floor.as_ref().map(types::StringMax70::as_str)
+    }
 
-impl Validatable for DateAndPlaceOfBirth {
-    fn validate(&self) -> Result<(), Error> {
-        if self.date_of_birth >= chrono::prelude::Utc::now().date_naive() {
-            return Err("Date of birth must be in the past (IVMS101 C2)".into());
-        }
-        Ok(())
+    /// The post box.
+    #[must_use]
+    pub fn post_box(&self) -> Option<&str> {
+        self.post_box.as_ref().map(types::StringMax16::as_str)
     }
-}
 
-/// National identification information.
-#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
-#[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
-pub struct NationalIdentification {
-    /// The national identifier.
-    pub national_identifier: types::StringMax35,
-    /// The national identifier type.
-    pub national_identifier_type: NationalIdentifierTypeCode,
-    /// The country of issuance.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub country_of_issue: Option<CountryCode>,
-    /// The registration authority.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub registration_authority: Option<RegistrationAuthority>,
-}
+    /// The room.
+    #[must_use]
+    pub fn room(&self) -> Option<&str> {
+        self.room.as_ref().map(types::StringMax70::as_str)
+    }
 
-/// A legal person.
-#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
-#[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
-pub struct LegalPerson {
-    /// The name of the legal person.
-    pub name: LegalPersonName,
-    /// The address.
-    #[serde(default, skip_serializing_if = "ZeroToN::is_empty")]
-    pub geographic_address: ZeroToN<Address>,
-    /// The customer identification.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub customer_identification: Option<types::StringMax50>,
-    /// The national identification.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub national_identification: Option<NationalIdentification>,
-    /// The country of registration.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub country_of_registration: Option<CountryCode>,
-}
+    /// The postal code.
+    #[must_use]
+    pub fn post_code(&self) -> Option<&str> {
+        self.post_code.as_ref().map(types::StringMax16::as_str)
+    }
 
-impl LegalPerson {
-    /// Constructs a `LegalPerson`.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the validation of the name or customer identificaiton
-    /// fails.
-    pub fn new(
-        name: &str,
-        customer_identification: &str,
-        address: Address,
-        lei: &lei::LEI,
-    ) -> Result<Self, Error> {
-        Ok(Self {
-            name: LegalPersonName {
-                name_identifier: LegalPersonNameID {
-                    legal_person_name: name.try_into()?,
-                    legal_person_name_identifier_type: LegalPersonNameTypeCode::Legal,
-                }
-                .into(),
-                local_name_identifier: None.into(),
-                phonetic_name_identifier: None.into(),
-            },
-            geographic_address: Some(address).into(),
-            customer_identification: Some(customer_identification.try_into()?),
-            national_identification: Some(NationalIdentification {
-                national_identifier: lei.to_string().as_str().try_into().unwrap(),
-                national_identifier_type: NationalIdentifierTypeCode::LegalEntityIdentifier,
-                country_of_issue: None,
-                registration_authority: None,
-            }),
-            country_of_registration: None,
-        })
+    /// The name of the town.
+    #[must_use]
+    pub fn town_name(&self) -> &str {
+        self.town_name.as_str()
     }
 
-    fn lei(&self) -> Result<Option<lei::LEI>, lei::Error> {
-        self.national_identification
+    /// The town location name.
+    #[must_use]
+    pub fn town_location_name(&self) -> Option<&str> {
+        self.town_location_name
             .as_ref()
-            .map(|ni| lei::LEI::try_from(ni.national_identifier.to_string().as_str()))
-            .transpose()
+            .map(types::StringMax35::as_str)
     }
-}
 
-impl LegalPerson {
+    /// The district name.
     #[must_use]
-    fn name(&self) -> String {
-        self.name
-            .name_identifier
-            .first()
-            .legal_person_name
-            .to_string()
+    pub fn district_name(&self) -> Option<&str> {
+        self.district_name.as_ref().map(types::StringMax35::as_str)
     }
 
+    /// The country sub-division.
     #[must_use]
-    fn address(&self) -> Option<&Address> {
-        self.geographic_address.first()
+    pub fn country_sub_division(&self) -> Option<&str> {
+        self.country_sub_division
+            .as_ref()
+            .map(types::StringMax35::as_str)
     }
-}
 
-impl Validatable for LegalPerson {
-    fn validate(&self) -> Result<(), Error> {
-        let has_geog = self
-            .geographic_address
-            .clone()
-            .into_iter()
-            .any(|addr| addr.address_type == AddressTypeCode::Residential);
-        if !has_geog
-            && self.national_identification.is_none()
-            && self.customer_identification.is_none()
+    /// Returns an iterator over the address lines.
+    pub fn address_line(&self) -> impl Iterator<Item = &str> + '_ {
+        self.address_line.iter().map(types::StringMax70::as_str)
+    }
+
+    /// The country.
+    #[must_use]
+    pub fn country(&self) -> &CountryCode {
+        &self.country
+    }
+
+    /// Checks that the address does not combine address lines with the
+    /// structured street fields, i.e. the street name, building number
+    /// or building name. IVMS101 allows this, but some validators reject
+    /// it, see [`ValidationOptions::reject_mixed_address`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::ValidationError`] for mixed addresses.
+    pub fn validate_no_mixing(&self) -> Result<(), Error> {
+        if !self.address_line.is_empty()
+            && (self.street_name.is_some()
+                || self.building_number.is_some()
+                || self.building_name.is_some())
         {
-            return Err(
-                "Legal person needs either geographic address, customer number or national identification (IVMS101 C4)"
+            return Err(Error::ValidationError(
+                "Address lines must not be combined with street name, building number or building name"
                     .into(),
-            );
-        }
-        if let Some(ni) = &self.national_identification {
-            if !matches!(
-                ni.national_identifier_type,
-                NationalIdentifierTypeCode::RegistrationAuthorityIdentifier
-                    | NationalIdentifierTypeCode::Unspecified
-                    | NationalIdentifierTypeCode::LegalEntityIdentifier
-                    | NationalIdentifierTypeCode::TaxIdentificationNumber
-            ) {
-                return Err("Legal person must have a 'RAID', 'MISC', 'LEIX' or 'TXID' identification (IVMS101 C7)".into());
-            }
-        };
-        if let Some(ni) = &self.national_identification {
-            if ni.national_identifier_type == NationalIdentifierTypeCode::LegalEntityIdentifier {
-                if let Err(e) = lei::LEI::try_from(ni.national_identifier.as_str()) {
-                    return Err(format!("Invalid LEI: {e} (IVMS101 C11)").as_str().into());
-                }
-            }
-        };
-        self.name.validate()?;
-        self.geographic_address
-            .clone()
-            .into_iter()
-            .try_for_each(|addr| addr.validate())?;
-        match &self.national_identification {
-            Some(ni) => {
-                if ni.country_of_issue.is_some() {
-                    return Err("Legal person must not have a country of issue (IVMS101 C9)".into());
-                }
-                if ni.national_identifier_type != NationalIdentifierTypeCode::LegalEntityIdentifier
-                    && ni.registration_authority.is_none()
-                {
-                    return Err("Legal person must specify registration authority for non-'LEIX' identification (IVMS101 C9)".into());
-                }
-                if ni.national_identifier_type == NationalIdentifierTypeCode::LegalEntityIdentifier
-                    && ni.registration_authority.is_some()
-                {
-                    return Err("Legal person must not specify registration authority for 'LEIX' identification (IVMS101 C9)".into());
-                }
-            }
-            None => (),
+            )
+            .at("addressLine"));
         }
         Ok(())
     }
-}
 
-/// The name of a legal person.
-#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
-#[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
-pub struct LegalPersonName {
-    /// The primary name identifier.
-    pub name_identifier: OneToN<LegalPersonNameID>,
-    /// The localized version of the name.
-    #[serde(default, skip_serializing_if = "ZeroToN::is_empty")]
-    pub local_name_identifier: ZeroToN<LegalPersonNameID>,
-    /// The phonetic version of the name.
-    #[serde(default, skip_serializing_if = "ZeroToN::is_empty")]
-    pub phonetic_name_identifier: ZeroToN<LegalPersonNameID>,
-}
+    /// Changes the type of the address.
+    pub fn set_address_type(&mut self, address_type: AddressTypeCode) {
+        self.address_type = address_type;
+    }
 
-impl Validatable for LegalPersonName {
-    fn validate(&self) -> Result<(), Error> {
-        let has_legl = self
-            .name_identifier
-            .clone()
-            .into_iter()
-            .any(|ni| ni.legal_person_name_identifier_type == LegalPersonNameTypeCode::Legal);
-        if !has_legl {
-            return Err("Legal person must have a legal name id (IVMS101 C5)".into());
-        }
+    /// Sets or, given `None`, removes the department.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the department is too long, in which case it is
+    /// left unchanged.
+    pub fn set_department(&mut self, department: Option<&str>) -> Result<(), Error> {
+        self.department = department
+            .map(|value| BoundedString::for_field(value, "Department"))
+            .transpose()?;
         Ok(())
     }
-}
 
-/// A legal person name ID.
-#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
-#[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
-pub struct LegalPersonNameID {
-    /// The legal person name.
-    pub legal_person_name: types::StringMax100,
-    /// The type of name.
-    pub legal_person_name_identifier_type: LegalPersonNameTypeCode,
-}
+    /// Sets or, given `None`, removes the sub-department.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sub-department is too long, in which case it is
+    /// left unchanged.
+    pub fn set_sub_department(&mut self, sub_department: Option<&str>) -> Result<(), Error> {
+        self.sub_department = sub_department
+            .map(|value| BoundedString::for_field(value, "Sub-department"))
+            .transpose()?;
+        Ok(())
+    }
 
-/// An intermediary VASP.
-#[derive(serde::Serialize, serde::Deserialize)]
-#[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
-pub struct IntermediaryVASP {
-    /// The intermediary VASP person.
-    pub intermediary_vasp: Person,
-    /// The sequence number.
-    pub sequence: u32,
-}
+    /// Sets or, given `None`, removes the street name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the street name is too long, in which case it is
+    /// left unchanged.
+    pub fn set_street_name(&mut self, street_name: Option<&str>) -> Result<(), Error> {
+        self.street_name = street_name
+            .map(|value| BoundedString::for_field(value, "Street name"))
+            .transpose()?;
+        Ok(())
+    }
 
-// Validating C12 (sequentialIntegrity) requires surrounding context
-impl Validatable for IntermediaryVASP {
-    fn validate(&self) -> Result<(), Error> {
-        self.intermediary_vasp.validate()?;
+    /// Sets or, given `None`, removes the building number.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the building number is too long, in which case it is
+    /// left unchanged.
+    pub fn set_building_number(&mut self, building_number: Option<&str>) -> Result<(), Error> {
+        self.building_number = building_number
+            .map(|value| BoundedString::for_field(value, "Building number"))
+            .transpose()?;
         Ok(())
     }
-}
 
-/// The type of natural person name.
-#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
-pub enum NaturalPersonNameTypeCode {
-    #[serde(rename = "ALIA")]
-    Alias,
-    #[serde(rename = "BIRT")]
-    NameAtBirth,
-    #[serde(rename = "MAID")]
-    MaidenName,
-    #[serde(rename = "LEGL")]
-    LegalName,
-    #[serde(rename = "MISC")]
-    Unspecified,
-}
+    /// Sets or, given `None`, removes the building name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the building name is too long, in which case it is
+    /// left unchanged.
+    pub fn set_building_name(&mut self, building_name: Option<&str>) -> Result<(), Error> {
+        self.building_name = building_name
+            .map(|value| BoundedString::for_field(value, "Building name"))
+            .transpose()?;
+        Ok(())
+    }
 
-/// The type of legal person name.
-#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
-pub enum LegalPersonNameTypeCode {
-    #[serde(rename = "LEGL")]
-    Legal,
-    #[serde(rename = "SHRT")]
-    Short,
-    #[serde(rename = "TRAD")]
-    Trading,
+    /// Sets or, given `None`, removes the floor.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the floor is too long, in which case it is
+    /// left unchanged.
+    pub fn set_floor(&mut self, floor: Option<&str>) -> Result<(), Error> {
+        self.floor = floor
+            .map(|value| BoundedString::for_field(value, "Floor"))
+            .transpose()?;
+        Ok(())
+    }
+
+    /// Sets or, given `None`, removes the post box.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the post box is too long, in which case it is
+    /// left unchanged.
+    pub fn set_post_box(&mut self, post_box: Option<&str>) -> Result<(), Error> {
+        self.post_box = post_box
+            .map(|value| BoundedString::for_field(value, "Post box"))
+            .transpose()?;
+        Ok(())
+    }
+
+    /// Sets or, given `None`, removes the room.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the room is too long, in which case it is
+    /// left unchanged.
+    pub fn set_room(&mut self, room: Option<&str>) -> Result<(), Error> {
+        self.room = room
+            .map(|value| BoundedString::for_field(value, "Room"))
+            .transpose()?;
+        Ok(())
+    }
+
+    /// Sets or, given `None`, removes the postal code.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the postal code is too long, in which case it is
+    /// left unchanged.
+    pub fn set_post_code(&mut self, post_code: Option<&str>) -> Result<(), Error> {
+        self.post_code = post_code
+            .map(|value| BoundedString::for_field(value, "Post code"))
+            .transpose()?;
+        Ok(())
+    }
+
+    /// Sets the name of the town.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the town name exceeds 35 bytes, in which case it
+    /// is left unchanged.
+    pub fn set_town_name(&mut self, town_name: &str) -> Result<(), Error> {
+        self.town_name = BoundedString::for_field(town_name, "Town name")?;
+        Ok(())
+    }
+
+    /// Sets or, given `None`, removes the town location name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the town location name is too long, in which case it is
+    /// left unchanged.
+    pub fn set_town_location_name(
+        &mut self,
+        town_location_name: Option<&str>,
+    ) -> Result<(), Error> {
+        self.town_location_name = town_location_name
+            .map(|value| BoundedString::for_field(value, "Town location name"))
+            .transpose()?;
+        Ok(())
+    }
+
+    /// Sets or, given `None`, removes the district name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the district name is too long, in which case it is
+    /// left unchanged.
+    pub fn set_district_name(&mut self, district_name: Option<&str>) -> Result<(), Error> {
+        self.district_name = district_name
+            .map(|value| BoundedString::for_field(value, "District name"))
+            .transpose()?;
+        Ok(())
+    }
+
+    /// Sets or, given `None`, removes the country sub-division.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the country sub-division is too long, in which case it is
+    /// left unchanged.
+    pub fn set_country_sub_division(
+        &mut self,
+        country_sub_division: Option<&str>,
+    ) -> Result<(), Error> {
+        self.country_sub_division = country_sub_division
+            .map(|value| BoundedString::for_field(value, "Country sub-division"))
+            .transpose()?;
+        Ok(())
+    }
+
+    /// Replaces the address lines.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an address line exceeds 70 bytes, in which case
+    /// the address lines are left unchanged.
+    pub fn set_address_line(&mut self, address_line: &[&str]) -> Result<(), Error> {
+        self.address_line = address_line
+            .iter()
+            .map(|line| BoundedString::for_field(line, "Address line"))
+            .collect::<Result<Vec<_>, _>>()?
+            .into();
+        Ok(())
+    }
+
+    /// Sets the country.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `country` is not a valid country code, in which
+    /// case the country is left unchanged.
+    pub fn set_country(&mut self, country: &str) -> Result<(), Error> {
+        self.country = country.try_into()?;
+        Ok(())
+    }
+
+    /// Returns a string where all address lines have
+    /// been joined with a comma.
+    #[must_use]
+    pub fn address_lines(&self) -> Option<String> {
+        if self.address_line.is_empty() {
+            None
+        } else {
+            Some(
+                self.address_line
+                    .clone()
+                    .into_iter()
+                    .map(Into::into)
+                    .collect::<Vec<String>>()
+                    .join(", "),
+            )
+        }
+    }
 }
 
-type Date = chrono::NaiveDate;
+#[allow(deprecated)]
+fn addresses_of_type(
+    addresses: &ZeroToN<Address>,
+    address_type: AddressTypeCode,
+) -> impl Iterator<Item = &Address> + '_ {
+    addresses
+        .iter()
+        .filter(move |address| address.address_type == address_type)
+}
 
-/// The type of address.
-#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
-pub enum AddressTypeCode {
-    #[serde(rename = "HOME")]
-    Residential,
-    #[serde(rename = "BIZZ")]
-    Business,
-    #[serde(rename = "GEOG")]
-    Geographic,
+/// The address types in order of precedence for `primary_address`.
+const PRIMARY_ADDRESS_TYPES: [AddressTypeCode; 3] = [
+    AddressTypeCode::Geographic,
+    AddressTypeCode::Business,
+    AddressTypeCode::Residential,
+];
+
+fn primary_address(addresses: &ZeroToN<Address>) -> Option<&Address> {
+    PRIMARY_ADDRESS_TYPES
+        .into_iter()
+        .find_map(|address_type| addresses_of_type(addresses, address_type).next())
 }
 
-/// The type of national identifier.
+#[allow(deprecated)]
+impl Address {
+    /// Composes the address into a single free-text line and the country,
+    /// as required by some card networks.
+    ///
+    /// The line lists the parts in postal order, like the [`Display`]
+    /// implementation but without the country name, and is truncated to
+    /// at most `max_len` characters.
+    ///
+    /// Note that [`Address::from_unstructured`] is only an approximate
+    /// inverse: structured fields such as the street end up in the address
+    /// lines, and fields beyond those used by [`Display`] are lost.
+    ///
+    /// [`Display`]: std::fmt::Display
+    #[must_use]
+    pub fn to_unstructured(&self, max_len: usize) -> (String, CountryCode) {
+        let mut line = String::new();
+        format_postal_address(
+            &mut line,
+            self.street_name.as_ref().map(types::StringMax70::as_str),
+            self.building_number
+                .as_ref()
+                .map(types::StringMax16::as_str),
+            self.address_lines().as_deref(),
+            self.post_code.as_ref().map(types::StringMax16::as_str),
+            self.town_name.as_str(),
+        )
+        .expect("writing to a String cannot fail");
+        if let Some((end, _)) = line.char_indices().nth(max_len) {
+            line.truncate(end);
+        }
+        (line, self.country.clone())
+    }
+
+    /// Parses a free-text address line as composed by
+    /// [`Address::to_unstructured`].
+    ///
+    /// The last comma-separated part is taken as the town, preceded by the
+    /// postal code if its first word contains a digit. All other parts
+    /// become address lines. The address type is set to residential.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a part is too long for its field.
+    pub fn from_unstructured(line: &str, country: CountryCode) -> Result<Self, Error> {
+        let mut parts: Vec<&str> = line.split(',').map(str::trim).collect();
+        let town = parts.pop().unwrap_or_default();
+        let (post_code, town) = match town.split_once(' ') {
+            Some((code, town)) if code.chars().any(|c| c.is_ascii_digit()) => (
+                Some(BoundedString::for_field(code, "Post code")?),
+                town.trim(),
+            ),
+            _ => (None, town),
+        };
+        Ok(Self {
+            address_type: AddressTypeCode::Residential,
+            department: None,
+            sub_department: None,
+            street_name: None,
+            building_number: None,
+            building_name: None,
+            floor: None,
+            post_box: None,
+            room: None,
+            post_code,
+            town_name: BoundedString::for_field(town, "Town name")?,
+            town_location_name: None,
+            district_name: None,
+            country_sub_division: None,
+            address_line: parts
+                .into_iter()
+                .filter(|part| !part.is_empty())
+                .map(|part| BoundedString::for_field(part, "Address line"))
+                .collect::<Result<Vec<_>, _>>()?
+                .into(),
+            country,
+        })
+    }
+}
+
+#[allow(deprecated)]
+impl std::fmt::Display for Address {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        format_address(
+            f,
+            self.street_name.as_ref().map(types::StringMax70::as_str),
+            self.building_number
+                .as_ref()
+                .map(types::StringMax16::as_str),
+            self.address_lines().as_deref(),
+            self.post_code.as_ref().map(types::StringMax16::as_str),
+            self.town_name.as_str(),
+            self.country.as_str(),
+        )
+    }
+}
+
+/// Formats the address into a single formatter.
+///
+/// Will smartly handle absent parts to join everything
+/// into a comma-delimited string.
+pub fn format_address(
+    f: &mut std::fmt::Formatter,
+    street: Option<&str>,
+    number: Option<&str>,
+    address_line: Option<&str>,
+    postcode: Option<&str>,
+    town: &str,
+    country_code: &str,
+) -> std::fmt::Result {
+    format_postal_address(f, street, number, address_line, postcode, town)?;
+    write!(
+        f,
+        ", {}",
+        country(country_code.to_lowercase().as_str()).unwrap_or(country_code)
+    )
+}
+
+/// Formats the address like [`format_address`], but without the country.
+fn format_postal_address(
+    f: &mut impl std::fmt::Write,
+    street: Option<&str>,
+    number: Option<&str>,
+    address_line: Option<&str>,
+    postcode: Option<&str>,
+    town: &str,
+) -> std::fmt::Result {
+    if let Some(s) = street {
+        write!(f, "{s}")?;
+        if let Some(n) = number {
+            write!(f, " {n}")?;
+        }
+        write!(f, ", ")?;
+    }
+    if let Some(al) = address_line {
+        write!(f, "{al}, ")?;
+    }
+    if let Some(pc) = postcode {
+        write!(f, "{pc} ")?;
+    }
+    write!(f, "{town}")
+}
+
+#[allow(deprecated)]
+impl Validatable for Address {
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), Error> {
+        if !(options.allow_post_box_only && self.post_box.is_some()) {
+            constraints::c8_address_complete(self)?;
+        }
+        if options.reject_mixed_address {
+            self.validate_no_mixing()?;
+        }
+        options.check_placeholder("townName", self.town_name.as_str())?;
+        for (i, line) in self.address_line.iter().enumerate() {
+            options.check_placeholder(&format!("addressLine[{i}]"), line.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+/// The date and place of birth.
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
-pub enum NationalIdentifierTypeCode {
-    #[serde(rename = "ARNU")]
-    AlienRegistrationNumber,
-    #[serde(rename = "CCPT")]
-    PassportNumber,
-    #[serde(rename = "RAID")]
-    RegistrationAuthorityIdentifier,
-    #[serde(rename = "DRLC")]
-    DriverLicenseNumber,
-    #[serde(rename = "FIIN")]
-    ForeignInvestmentIdentityNumber,
-    #[serde(rename = "TXID")]
-    TaxIdentificationNumber,
-    #[serde(rename = "SOCS")]
-    SocialSecurityNumber,
-    #[serde(rename = "IDCD")]
-    IdentityCardNumber,
-    #[serde(rename = "LEIX")]
-    LegalEntityIdentifier,
-    #[serde(rename = "MISC")]
-    Unspecified,
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-fields", serde(deny_unknown_fields))]
+pub struct DateAndPlaceOfBirth {
+    /// The date of birth.
+    #[serde(alias = "date_of_birth")]
+    #[deprecated(note = "use `DateAndPlaceOfBirth::date_of_birth`, the field will become private")]
+    pub date_of_birth: Date,
+    /// The place of birth.
+    #[serde(alias = "place_of_birth")]
+    #[deprecated(
+        note = "use `DateAndPlaceOfBirth::place_of_birth`, the field will become private"
+    )]
+    pub place_of_birth: types::StringMax70,
+}
+
+#[allow(deprecated)]
+impl DateAndPlaceOfBirth {
+    /// Constructs a `DateAndPlaceOfBirth`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the place of birth exceeds 70 bytes.
+    pub fn new(date_of_birth: Date, place_of_birth: &str) -> Result<Self, Error> {
+        Ok(Self {
+            date_of_birth,
+            place_of_birth: place_of_birth.try_into()?,
+        })
+    }
+
+    /// The date of birth.
+    #[must_use]
+    pub fn date_of_birth(&self) -> Date {
+        self.date_of_birth
+    }
+
+    /// The place of birth.
+    #[must_use]
+    pub fn place_of_birth(&self) -> &str {
+        self.place_of_birth.as_str()
+    }
+}
+
+#[allow(deprecated)]
+impl Validatable for DateAndPlaceOfBirth {
+    fn validate_with(&self, _options: &ValidationOptions) -> Result<(), Error> {
+        constraints::c2_birthdate_in_past(
+            self.date_of_birth,
+            chrono::prelude::Utc::now().date_naive(),
+        )
+    }
 }
 
-/// Implements validation for a data structure according
-/// to the rules of the IVMS101 standard.
-pub trait Validatable {
-    fn validate(&self) -> Result<(), Error>;
-}
+/// National identification information.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-fields", serde(deny_unknown_fields))]
+pub struct NationalIdentification {
+    /// The national identifier.
+    #[serde(alias = "national_identifier")]
+    #[deprecated(
+        note = "use `NationalIdentification::national_identifier`, the field will become private"
+    )]
+    pub national_identifier: types::StringMax35,
+    /// The national identifier type.
+    #[serde(alias = "national_identifier_type")]
+    #[deprecated(
+        note = "use `NationalIdentification::national_identifier_type`, the field will become private"
+    )]
+    pub national_identifier_type: NationalIdentifierTypeCode,
+    /// The country of issuance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "country_of_issue")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(
+        note = "use `NationalIdentification::country_of_issue`, the field will become private"
+    )]
+    pub country_of_issue: Option<CountryCode>,
+    /// The registration authority.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "registration_authority")]
+    #[serde(default, deserialize_with = "registration_authorities::deserialize")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<String>", optional))]
+    #[deprecated(
+        note = "use `NationalIdentification::registration_authority`, the field will become private"
+    )]
+    pub registration_authority: Option<RegistrationAuthority>,
+}
+
+#[allow(deprecated)]
+impl NationalIdentification {
+    /// The national identifier.
+    #[must_use]
+    pub fn national_identifier(&self) -> &str {
+        self.national_identifier.as_str()
+    }
+
+    /// The type of the national identifier.
+    #[must_use]
+    pub fn national_identifier_type(&self) -> &NationalIdentifierTypeCode {
+        &self.national_identifier_type
+    }
+
+    /// The country of issue.
+    #[must_use]
+    pub fn country_of_issue(&self) -> Option<&CountryCode> {
+        self.country_of_issue.as_ref()
+    }
+
+    /// The registration authority.
+    #[must_use]
+    pub fn registration_authority(&self) -> Option<&RegistrationAuthority> {
+        self.registration_authority.as_ref()
+    }
+
+    /// Parses the national identifier as LEI if the identifier type is
+    /// `LEIX`. Returns `None` for any other identifier type.
+    #[must_use]
+    pub fn as_lei(&self) -> Option<Result<lei::LEI, lei::Error>> {
+        (self.national_identifier_type == NationalIdentifierTypeCode::LegalEntityIdentifier)
+            .then(|| lei::LEI::try_from(self.national_identifier.as_str()))
+    }
+
+    /// Indicates whether the identifier is the number of an identity
+    /// document, i.e. a passport, identity card or driver's license,
+    /// that does not specify the country of issue.
+    fn lacks_country_of_issue(&self) -> bool {
+        matches!(
+            self.national_identifier_type,
+            NationalIdentifierTypeCode::PassportNumber
+                | NationalIdentifierTypeCode::IdentityCardNumber
+                | NationalIdentifierTypeCode::DriverLicenseNumber
+        ) && self.country_of_issue.is_none()
+    }
+
+    /// Indicates whether the identifier is a passport number that does
+    /// not have the shape common to all passport numbers, i.e. 5 to 15
+    /// ASCII letters and digits.
+    fn is_malformed_passport_number(&self) -> bool {
+        let number = self.national_identifier.as_str();
+        self.national_identifier_type == NationalIdentifierTypeCode::PassportNumber
+            && !((5..=15).contains(&number.len())
+                && number.bytes().all(|b| b.is_ascii_alphanumeric()))
+    }
+
+    /// Whether the identifier type is allowed for legal persons (C7).
+    pub(crate) fn is_legal_person_type(&self) -> bool {
+        matches!(
+            self.national_identifier_type,
+            NationalIdentifierTypeCode::RegistrationAuthorityIdentifier
+                | NationalIdentifierTypeCode::Unspecified
+                | NationalIdentifierTypeCode::LegalEntityIdentifier
+                | NationalIdentifierTypeCode::TaxIdentificationNumber
+        )
+    }
+
+    /// Checks that the identifier type is allowed for legal persons (C7)
+    /// and that an LEI is valid (C11).
+    pub(crate) fn check_legal_person_type(&self) -> Result<(), Error> {
+        validation::ensure(
+            "C7",
+            self.is_legal_person_type(),
+            "Legal person must have a 'RAID', 'MISC', 'LEIX' or 'TXID' identification",
+        )?;
+        if self.national_identifier_type == NationalIdentifierTypeCode::LegalEntityIdentifier {
+            constraints::c11_valid_lei(self.national_identifier.as_str())?;
+        }
+        Ok(())
+    }
+
+    /// Checks the country of issue and the registration authority of the
+    /// identification of a legal person (C9).
+    pub(crate) fn check_c9(&self) -> Result<(), Error> {
+        let is_lei =
+            self.national_identifier_type == NationalIdentifierTypeCode::LegalEntityIdentifier;
+        validation::ensure(
+            "C9",
+            self.country_of_issue.is_none(),
+            "Legal person must not have a country of issue",
+        )?;
+        validation::ensure(
+            "C9",
+            is_lei || self.registration_authority.is_some(),
+            "Legal person must specify registration authority for non-'LEIX' identification",
+        )?;
+        validation::ensure(
+            "C9",
+            !is_lei || self.registration_authority.is_none(),
+            "Legal person must not specify registration authority for 'LEIX' identification",
+        )
+    }
+
+    /// Sets the registration authority, accepting lowercase codes and
+    /// canonicalizing them to uppercase.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `ra` is not "RA" followed by six digits or not
+    /// on the GLEIF list (C10).
+    pub fn set_registration_authority(&mut self, ra: &str) -> Result<(), Error> {
+        self.registration_authority = Some(registration_authorities::parse(ra)?);
+        Ok(())
+    }
+
+    /// Sets the LEI as national identifier, along with the `LEIX`
+    /// identifier type.
+    ///
+    /// As required for `LEIX` identifications, the country of issue
+    /// and the registration authority are cleared.
+    pub fn set_lei(&mut self, lei: &lei::LEI) {
+        *self = lei.into();
+    }
+}
+
+#[allow(deprecated)]
+impl From<&lei::LEI> for NationalIdentification {
+    fn from(lei: &lei::LEI) -> Self {
+        Self {
+            // An LEI always has 20 characters.
+            national_identifier: lei.to_string().as_str().try_into().unwrap(),
+            national_identifier_type: NationalIdentifierTypeCode::LegalEntityIdentifier,
+            country_of_issue: None,
+            registration_authority: None,
+        }
+    }
+}
+
+/// A legal person.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-fields", serde(deny_unknown_fields))]
+pub struct LegalPerson {
+    /// The name of the legal person.
+    #[deprecated(note = "use `LegalPerson::name`, the field will become private")]
+    pub name: LegalPersonName,
+    /// The address.
+    #[serde(default, skip_serializing_if = "ZeroToN::is_empty")]
+    #[serde(deserialize_with = "types::zero_to_n::fields::geographic_address")]
+    #[serde(alias = "geographic_address")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>", optional))]
+    #[deprecated(note = "use `LegalPerson::addresses`, the field will become private")]
+    pub geographic_address: ZeroToN<Address>,
+    /// The customer identification.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "customer_identification")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(
+        note = "use `LegalPerson::customer_identification`, the field will become private"
+    )]
+    pub customer_identification: Option<types::StringMax50>,
+    /// The national identification.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "national_identification")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(
+        note = "use `LegalPerson::national_identification`, the field will become private"
+    )]
+    pub national_identification: Option<NationalIdentification>,
+    /// The country of registration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "country_of_registration")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    #[deprecated(
+        note = "use `LegalPerson::country_of_registration`, the field will become private"
+    )]
+    pub country_of_registration: Option<CountryCode>,
+}
+
+#[allow(deprecated)]
+impl LegalPerson {
+    /// Constructs a `LegalPerson`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the validation of the name or customer identificaiton
+    /// fails.
+    pub fn new(
+        name: &str,
+        customer_identification: &str,
+        address: Address,
+        lei: &lei::LEI,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            name: LegalPersonName {
+                name_identifier: LegalPersonNameID::legal_name(name)?.into(),
+                local_name_identifier: None.into(),
+                phonetic_name_identifier: None.into(),
+            },
+            geographic_address: Some(address).into(),
+            customer_identification: Some(customer_identification.try_into()?),
+            national_identification: Some(lei.into()),
+            country_of_registration: None,
+        })
+    }
+
+    /// Constructs a VASP identified by its name and LEI only.
+    fn vasp(name: &str, lei: &lei::LEI) -> Result<Self, Error> {
+        Ok(Self {
+            name: LegalPersonName {
+                name_identifier: LegalPersonNameID::legal_name(name)?.into(),
+                local_name_identifier: None.into(),
+                phonetic_name_identifier: None.into(),
+            },
+            geographic_address: ZeroToN::None,
+            customer_identification: None,
+            national_identification: Some(lei.into()),
+            country_of_registration: None,
+        })
+    }
+
+    fn lei(&self) -> Result<Option<lei::LEI>, lei::Error> {
+        self.national_identification
+            .as_ref()
+            .and_then(NationalIdentification::as_lei)
+            .transpose()
+    }
+}
+
+#[allow(deprecated)]
+impl LegalPerson {
+    /// The name.
+    #[must_use]
+    pub fn name(&self) -> &LegalPersonName {
+        &self.name
+    }
+
+    /// Replaces the name of the person.
+    pub fn set_name(&mut self, name: LegalPersonName) {
+        self.name = name;
+    }
+
+    /// Returns an iterator over the geographic addresses.
+    pub fn addresses(&self) -> impl Iterator<Item = &Address> + '_ {
+        self.geographic_address.iter()
+    }
+
+    /// The customer identification.
+    #[must_use]
+    pub fn customer_identification(&self) -> Option<&str> {
+        self.customer_identification
+            .as_ref()
+            .map(types::StringMax50::as_str)
+    }
+
+    /// Sets or, given `None`, removes the customer identification.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the customer identification exceeds 50 bytes,
+    /// or if it is removed and the person would no longer satisfy C4. The
+    /// customer identification is left unchanged in either case.
+    pub fn set_customer_identification(&mut self, id: Option<&str>) -> Result<(), Error> {
+        let id = id
+            .map(|id| BoundedString::for_field(id, "Customer identification"))
+            .transpose()?;
+        let previous = std::mem::replace(&mut self.customer_identification, id);
+        self.check_c4().map_err(|e| {
+            self.customer_identification = previous;
+            e
+        })
+    }
+
+    /// The national identification.
+    #[must_use]
+    pub fn national_identification(&self) -> Option<&NationalIdentification> {
+        self.national_identification.as_ref()
+    }
+
+    /// Sets the national identification.
+    pub fn set_national_identification(&mut self, id: Option<NationalIdentification>) {
+        self.national_identification = id;
+    }
+
+    /// Returns an iterator over the addresses of the given type.
+    pub fn addresses_of_type(
+        &self,
+        address_type: AddressTypeCode,
+    ) -> impl Iterator<Item = &Address> + '_ {
+        addresses_of_type(&self.geographic_address, address_type)
+    }
+
+    /// Returns the primary address of the legal person, which is the first
+    /// geographic (`GEOG`) address, or else the first business (`BIZZ`)
+    /// or residential (`HOME`) address, in this order.
+    #[must_use]
+    pub fn primary_address(&self) -> Option<&Address> {
+        primary_address(&self.geographic_address)
+    }
+
+    /// The country of registration.
+    #[must_use]
+    pub fn country_of_registration(&self) -> Option<&CountryCode> {
+        self.country_of_registration.as_ref()
+    }
+
+    /// Sets the country of registration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `country` is not a valid country code, in which
+    /// case the country of registration is left unchanged.
+    pub fn set_country_of_registration(&mut self, country: &str) -> Result<(), Error> {
+        self.country_of_registration = Some(country.try_into()?);
+        Ok(())
+    }
+
+    /// Replaces the geographic addresses of the person.
+    ///
+    /// Residential addresses satisfy C4 as any other address type, but
+    /// having residential addresses only is reported by
+    /// [`LegalPerson::warnings`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `addresses` is empty and the person would no
+    /// longer satisfy C4. The addresses are left unchanged in that case.
+    pub fn set_addresses(&mut self, addresses: Vec<Address>) -> Result<(), Error> {
+        let addresses = ZeroToN::from(addresses).normalize();
+        if addresses.is_empty() {
+            return self.clear_addresses();
+        }
+        self.geographic_address = addresses;
+        Ok(())
+    }
+
+    /// Removes the geographic addresses of the person.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the person would no longer satisfy C4, in which
+    /// case the addresses are left unchanged.
+    pub fn clear_addresses(&mut self) -> Result<(), Error> {
+        let addresses = std::mem::replace(&mut self.geographic_address, ZeroToN::None);
+        self.check_c4().map_err(|e| {
+            self.geographic_address = addresses;
+            e
+        })
+    }
+
+    /// Checks C4, which requires a legal person to have a geographic
+    /// address, customer identification or national identification.
+    fn check_c4(&self) -> Result<(), Error> {
+        validation::ensure(
+            "C4",
+            !self.geographic_address.is_empty()
+                || self.national_identification.is_some()
+                || self.customer_identification.is_some(),
+            "Legal person needs either geographic address, customer number or national identification",
+        )
+    }
+
+    /// Checks the country of registration of a legal person identified
+    /// by an LEI against the jurisdiction of the LEI, as looked up by
+    /// `resolver`, e.g. in the GLEIF database.
+    ///
+    /// This crate does not perform the lookup itself. The check is
+    /// stricter than [`Validatable::validate`] and meant to accompany
+    /// [`ValidationLevel::Strict`] validation. Legal persons without a
+    /// valid LEI and LEIs that `resolver` does not know pass the check.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the country of registration is missing or
+    /// differs from the jurisdiction of the LEI.
+    pub fn check_registration_consistency(
+        &self,
+        resolver: &dyn Fn(&lei::LEI) -> Option<CountryCode>,
+    ) -> Result<(), Error> {
+        let Ok(Some(lei)) = self.lei() else {
+            return Ok(());
+        };
+        let Some(country) = &self.country_of_registration else {
+            return Err(Error::ValidationError(
+                "Country of registration is required for a legal person identified by an LEI"
+                    .into(),
+            )
+            .at("countryOfRegistration"));
+        };
+        match resolver(&lei) {
+            Some(jurisdiction) if jurisdiction != *country => Err(Error::ValidationError(format!(
+                "Country of registration {} does not match the jurisdiction {} of the LEI {lei}",
+                country.as_str(),
+                jurisdiction.as_str()
+            ))
+            .at("countryOfRegistration")),
+            _ => Ok(()),
+        }
+    }
+
+    /// Indicates whether the registration authority of the national
+    /// identification maintains a register in the country of
+    /// registration.
+    ///
+    /// Returns `None` if either is missing or the jurisdiction of the
+    /// registration authority is not known.
+    #[must_use]
+    pub fn ra_jurisdiction_matches(&self) -> Option<bool> {
+        let ra = self
+            .national_identification
+            .as_ref()?
+            .registration_authority
+            .as_ref()?;
+        let country = self.country_of_registration.as_ref()?;
+        registration_authorities::jurisdiction(ra).map(|jurisdiction| jurisdiction == *country)
+    }
+
+    /// Returns the advisory findings for the legal person.
+    ///
+    /// A legal person's address should be of type `GEOG` or `BIZZ`,
+    /// so having residential (`HOME`) addresses only is reported as
+    /// [`Warning::LegalPersonResidentialAddressOnly`]. Furthermore,
+    /// [`Warning::AddressCountryMismatch`] is reported if none of the
+    /// addresses is in the country of registration and
+    /// [`Warning::NonLatinNameWithoutPhoneticName`] if a name is given in
+    /// a non-Latin script only and
+    /// [`Warning::RegistrationAuthorityJurisdictionMismatch`] if the
+    /// registration authority is known to maintain a register in another
+    /// country than the country of registration.
+    #[must_use]
+    pub fn warnings(&self) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        if !self.geographic_address.is_empty()
+            && self
+                .geographic_address
+                .clone()
+                .into_iter()
+                .all(|addr| addr.address_type == AddressTypeCode::Residential)
+        {
+            warnings.push(Warning::LegalPersonResidentialAddressOnly);
+        }
+        if validation::address_country_mismatch(
+            &self.geographic_address,
+            self.country_of_registration.as_ref(),
+        ) {
+            warnings.push(Warning::AddressCountryMismatch);
+        }
+        if self.name.phonetic_name_identifier.is_empty()
+            && self
+                .name
+                .name_identifier
+                .iter()
+                .chain(self.name.local_name_identifier.iter())
+                .any(|id| validation::is_non_latin(id.legal_person_name.as_str()))
+        {
+            warnings.push(Warning::NonLatinNameWithoutPhoneticName);
+        }
+        if self.ra_jurisdiction_matches() == Some(false) {
+            warnings.push(Warning::RegistrationAuthorityJurisdictionMismatch);
+        }
+        warnings
+    }
+
+    #[must_use]
+    fn legal_name(&self) -> String {
+        self.name
+            .name_identifier
+            .first()
+            .legal_person_name
+            .to_string()
+    }
+
+    #[must_use]
+    fn address(&self) -> Option<&Address> {
+        self.geographic_address.first()
+    }
+}
+
+#[allow(deprecated)]
+impl Validatable for LegalPerson {
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), Error> {
+        // Any address type satisfies C4, a residential-only address
+        // is reported by `LegalPerson::warnings` instead.
+        self.check_c4()?;
+        if let Some(ni) = &self.national_identification {
+            ni.check_legal_person_type()?;
+        }
+        self.name.validate_with(options).map_err(|e| e.at("name"))?;
+        validate_addresses(&self.geographic_address, options)?;
+        if let Some(ni) = &self.national_identification {
+            ni.check_c9()?;
+        }
+        Ok(())
+    }
+}
+
+/// The name of a legal person.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-fields", serde(deny_unknown_fields))]
+pub struct LegalPersonName {
+    /// The primary name identifier.
+    #[serde(deserialize_with = "OneToN::deserialize_name_identifier")]
+    #[serde(alias = "name_identifier")]
+    #[deprecated(note = "use `LegalPersonName::name_identifiers`, the field will become private")]
+    pub name_identifier: OneToN<LegalPersonNameID>,
+    /// The localized version of the name.
+    #[serde(
+        default,
+        deserialize_with = "types::zero_to_n::fields::local_name_identifier",
+        skip_serializing_if = "ZeroToN::is_empty"
+    )]
+    #[serde(alias = "local_name_identifier")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>", optional))]
+    #[deprecated(
+        note = "use `LegalPersonName::local_name_identifiers`, the field will become private"
+    )]
+    pub local_name_identifier: ZeroToN<LegalPersonNameID>,
+    /// The phonetic version of the name.
+    #[serde(
+        default,
+        deserialize_with = "types::zero_to_n::fields::phonetic_name_identifier",
+        skip_serializing_if = "ZeroToN::is_empty"
+    )]
+    #[serde(alias = "phonetic_name_identifier")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>", optional))]
+    #[deprecated(
+        note = "use `LegalPersonName::phonetic_name_identifiers`, the field will become private"
+    )]
+    pub phonetic_name_identifier: ZeroToN<LegalPersonNameID>,
+}
+
+#[allow(deprecated)]
+impl LegalPersonName {
+    /// Constructs a name with the given name identifier.
+    #[must_use]
+    pub fn new(name_identifier: LegalPersonNameID) -> Self {
+        Self {
+            name_identifier: name_identifier.into(),
+            local_name_identifier: ZeroToN::None,
+            phonetic_name_identifier: ZeroToN::None,
+        }
+    }
+
+    /// Adds a name identifier, e.g. an alias.
+    pub fn add_name_identifier(&mut self, id: LegalPersonNameID) {
+        self.name_identifier = self.name_identifier.clone().union(id.into());
+    }
+
+    /// Adds a local name identifier, i.e. the name in a local script.
+    pub fn add_local_name_identifier(&mut self, id: LegalPersonNameID) {
+        self.local_name_identifier =
+            std::mem::take(&mut self.local_name_identifier).union(Some(id).into());
+    }
+
+    /// Adds a phonetic name identifier.
+    pub fn add_phonetic_name_identifier(&mut self, id: LegalPersonNameID) {
+        self.phonetic_name_identifier =
+            std::mem::take(&mut self.phonetic_name_identifier).union(Some(id).into());
+    }
+
+    /// Returns an iterator over the name identifiers.
+    pub fn name_identifiers(&self) -> impl Iterator<Item = &LegalPersonNameID> + '_ {
+        self.name_identifier.iter()
+    }
+
+    /// Returns an iterator over the local name identifiers.
+    pub fn local_name_identifiers(&self) -> impl Iterator<Item = &LegalPersonNameID> + '_ {
+        self.local_name_identifier.iter()
+    }
+
+    /// Returns an iterator over the phonetic name identifiers.
+    pub fn phonetic_name_identifiers(&self) -> impl Iterator<Item = &LegalPersonNameID> + '_ {
+        self.phonetic_name_identifier.iter()
+    }
+}
+
+#[allow(deprecated)]
+impl Validatable for LegalPersonName {
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), Error> {
+        let has_legl = self
+            .name_identifier
+            .clone()
+            .into_iter()
+            .any(|ni| ni.legal_person_name_identifier_type == LegalPersonNameTypeCode::Legal);
+        validation::ensure("C5", has_legl, "Legal person must have a legal name id")?;
+        for (field, ids) in [
+            ("nameIdentifier", self.name_identifier.to_vec()),
+            ("localNameIdentifier", self.local_name_identifier.to_vec()),
+            (
+                "phoneticNameIdentifier",
+                self.phonetic_name_identifier.to_vec(),
+            ),
+        ] {
+            for (i, id) in ids.iter().enumerate() {
+                options.check_placeholder(
+                    &format!("{field}[{i}].legalPersonName"),
+                    id.legal_person_name.as_str(),
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A legal person name ID.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-fields", serde(deny_unknown_fields))]
+pub struct LegalPersonNameID {
+    /// The legal person name.
+    #[serde(alias = "legal_person_name")]
+    #[deprecated(
+        note = "use `LegalPersonNameID::legal_person_name`, the field will become private"
+    )]
+    pub legal_person_name: types::StringMax100,
+    /// The type of name.
+    #[serde(alias = "legal_person_name_identifier_type")]
+    #[deprecated(
+        note = "use `LegalPersonNameID::legal_person_name_identifier_type`, the field will become private"
+    )]
+    pub legal_person_name_identifier_type: LegalPersonNameTypeCode,
+}
+
+#[allow(deprecated)]
+impl LegalPersonNameID {
+    /// Constructs a name identifier of the given type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name exceeds 100 bytes.
+    pub fn new(
+        legal_person_name: &str,
+        legal_person_name_identifier_type: LegalPersonNameTypeCode,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            legal_person_name: legal_person_name.try_into()?,
+            legal_person_name_identifier_type,
+        })
+    }
+
+    /// Constructs a legal name identifier (`LEGL`), as required for the
+    /// `nameIdentifier` of a legal person by C5.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name exceeds 100 bytes.
+    pub fn legal_name(legal_person_name: &str) -> Result<Self, Error> {
+        Self::new(legal_person_name, LegalPersonNameTypeCode::Legal)
+    }
+
+    /// The name.
+    #[must_use]
+    pub fn legal_person_name(&self) -> &str {
+        self.legal_person_name.as_str()
+    }
+
+    /// The type of name.
+    #[must_use]
+    pub fn legal_person_name_identifier_type(&self) -> &LegalPersonNameTypeCode {
+        &self.legal_person_name_identifier_type
+    }
+
+    /// Constructs a trading name identifier (`TRAD`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name exceeds 100 bytes.
+    pub fn trading_name(legal_person_name: &str) -> Result<Self, Error> {
+        Self::new(legal_person_name, LegalPersonNameTypeCode::Trading)
+    }
+}
+
+/// An intermediary VASP.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-fields", serde(deny_unknown_fields))]
+pub struct IntermediaryVASP {
+    /// The intermediary VASP person.
+    #[serde(alias = "intermediary_vasp")]
+    #[deprecated(note = "use `IntermediaryVASP::person`, the field will become private")]
+    pub intermediary_vasp: Person,
+    /// The sequence number.
+    #[deprecated(note = "use `IntermediaryVASP::sequence`, the field will become private")]
+    pub sequence: u32,
+}
+
+#[allow(deprecated)]
+impl IntermediaryVASP {
+    /// Constructs an `IntermediaryVASP` with the given name, LEI and
+    /// position in the transfer path.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `Error` if the validation of the name fails.
+    pub fn new(name: &str, lei: &lei::LEI, sequence: u32) -> Result<Self, Error> {
+        Ok(Self {
+            intermediary_vasp: LegalPerson::vasp(name, lei)?.into(),
+            sequence,
+        })
+    }
+
+    /// The intermediary VASP.
+    #[must_use]
+    pub fn person(&self) -> &Person {
+        &self.intermediary_vasp
+    }
+
+    /// The position of the intermediary VASP in the transfer path.
+    #[must_use]
+    pub fn sequence(&self) -> u32 {
+        self.sequence
+    }
+
+    /// The name of the intermediary VASP.
+    #[must_use]
+    pub fn name(&self) -> String {
+        self.intermediary_vasp.last_name()
+    }
+
+    /// Returns the LEI of the intermediary VASP.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the national identification
+    /// of the legal person is not a valid LEI.
+    pub fn lei(&self) -> Result<Option<lei::LEI>, lei::Error> {
+        self.intermediary_vasp.lei()
+    }
+}
+
+// Validating C12 (sequentialIntegrity) requires surrounding context
+#[allow(deprecated)]
+impl Validatable for IntermediaryVASP {
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), Error> {
+        self.intermediary_vasp.validate_with(options)?;
+        ensure_legal_person(&self.intermediary_vasp, "Intermediary VASP")
+    }
+}
+
+/// The type of natural person name.
+///
+/// Lowercase codes are accepted when deserializing, but codes are always
+/// serialized in uppercase.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum NaturalPersonNameTypeCode {
+    #[serde(rename = "ALIA")]
+    #[serde(alias = "alia")]
+    Alias,
+    #[serde(rename = "BIRT")]
+    #[serde(alias = "birt")]
+    NameAtBirth,
+    #[serde(rename = "MAID")]
+    #[serde(alias = "maid")]
+    MaidenName,
+    #[serde(rename = "LEGL")]
+    #[serde(alias = "legl")]
+    LegalName,
+    #[serde(rename = "MISC")]
+    #[serde(alias = "misc")]
+    Unspecified,
+}
+
+impl NaturalPersonNameTypeCode {
+    /// Returns the four-letter code of the name type, e.g. `"LEGL"`.
+    #[must_use]
+    pub fn wire_code(&self) -> &'static str {
+        match self {
+            Self::Alias => "ALIA",
+            Self::NameAtBirth => "BIRT",
+            Self::MaidenName => "MAID",
+            Self::LegalName => "LEGL",
+            Self::Unspecified => "MISC",
+        }
+    }
+}
+
+/// The type of legal person name.
+///
+/// Lowercase codes are accepted when deserializing, but codes are always
+/// serialized in uppercase.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum LegalPersonNameTypeCode {
+    #[serde(rename = "LEGL")]
+    #[serde(alias = "legl")]
+    Legal,
+    #[serde(rename = "SHRT")]
+    #[serde(alias = "shrt")]
+    Short,
+    #[serde(rename = "TRAD")]
+    #[serde(alias = "trad")]
+    Trading,
+}
+
+impl LegalPersonNameTypeCode {
+    /// Returns the four-letter code of the name type, e.g. `"LEGL"`.
+    #[must_use]
+    pub fn wire_code(&self) -> &'static str {
+        match self {
+            Self::Legal => "LEGL",
+            Self::Short => "SHRT",
+            Self::Trading => "TRAD",
+        }
+    }
+}
+
+type Date = chrono::NaiveDate;
+
+/// The type of address.
+///
+/// Lowercase codes are accepted when deserializing, but codes are always
+/// serialized in uppercase.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum AddressTypeCode {
+    #[serde(rename = "HOME")]
+    #[serde(alias = "home")]
+    Residential,
+    #[serde(rename = "BIZZ")]
+    #[serde(alias = "bizz")]
+    Business,
+    #[serde(rename = "GEOG")]
+    #[serde(alias = "geog")]
+    Geographic,
+}
+
+impl AddressTypeCode {
+    /// Returns the four-letter code of the address type, e.g. `"GEOG"`.
+    #[must_use]
+    pub fn wire_code(&self) -> &'static str {
+        match self {
+            Self::Residential => "HOME",
+            Self::Business => "BIZZ",
+            Self::Geographic => "GEOG",
+        }
+    }
+}
+
+/// The type of national identifier.
+///
+/// Lowercase codes are accepted when deserializing, but codes are always
+/// serialized in uppercase.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum NationalIdentifierTypeCode {
+    #[serde(rename = "ARNU")]
+    #[serde(alias = "arnu")]
+    AlienRegistrationNumber,
+    #[serde(rename = "CCPT")]
+    #[serde(alias = "ccpt")]
+    PassportNumber,
+    #[serde(rename = "RAID")]
+    #[serde(alias = "raid")]
+    RegistrationAuthorityIdentifier,
+    #[serde(rename = "DRLC")]
+    #[serde(alias = "drlc")]
+    DriverLicenseNumber,
+    #[serde(rename = "FIIN")]
+    #[serde(alias = "fiin")]
+    ForeignInvestmentIdentityNumber,
+    #[serde(rename = "TXID")]
+    #[serde(alias = "txid")]
+    TaxIdentificationNumber,
+    #[serde(rename = "SOCS")]
+    #[serde(alias = "socs")]
+    SocialSecurityNumber,
+    #[serde(rename = "IDCD")]
+    #[serde(alias = "idcd")]
+    IdentityCardNumber,
+    #[serde(rename = "LEIX")]
+    #[serde(alias = "leix")]
+    LegalEntityIdentifier,
+    #[serde(rename = "MISC")]
+    #[serde(alias = "misc")]
+    Unspecified,
+}
+
+impl NationalIdentifierTypeCode {
+    /// Returns the four-letter code of the identifier type, e.g.
+    /// `"LEIX"`.
+    #[must_use]
+    pub fn wire_code(&self) -> &'static str {
+        match self {
+            Self::AlienRegistrationNumber => "ARNU",
+            Self::PassportNumber => "CCPT",
+            Self::RegistrationAuthorityIdentifier => "RAID",
+            Self::DriverLicenseNumber => "DRLC",
+            Self::ForeignInvestmentIdentityNumber => "FIIN",
+            Self::TaxIdentificationNumber => "TXID",
+            Self::SocialSecurityNumber => "SOCS",
+            Self::IdentityCardNumber => "IDCD",
+            Self::LegalEntityIdentifier => "LEIX",
+            Self::Unspecified => "MISC",
+        }
+    }
+}
+
+/// Implements validation for a data structure according
+/// to the rules of the IVMS101 standard.
+pub trait Validatable {
+    /// Validates the data structure using the default [`ValidationOptions`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] describing the first violated rule.
+    fn validate(&self) -> Result<(), Error> {
+        self.validate_with(&ValidationOptions::default())
+    }
+
+    /// Validates the data structure using the given [`ValidationOptions`].
+    ///
+    /// Implementors not depending on the options ignore them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] describing the first violated rule.
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), Error>;
+
+    /// Validates the data structure using the default [`ValidationOptions`],
+    /// reporting the violations of all its parts instead of the first one.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`Error`]s of all parts violating a rule, attributed
+    /// to their field path where applicable. Their
+    /// [`Display`](std::fmt::Display) groups them by section for human
+    /// review.
+    fn validate_all(&self) -> Result<(), ValidationFailures> {
+        self.validate_all_with(&ValidationOptions::default())
+    }
+
+    /// Validates the data structure using the given [`ValidationOptions`],
+    /// reporting the violations of all its parts instead of the first one.
+    ///
+    /// By default, this reports the error of [`Validatable::validate_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`Error`]s of all parts violating a rule, attributed
+    /// to their field path where applicable.
+    fn validate_all_with(&self, options: &ValidationOptions) -> Result<(), ValidationFailures> {
+        self.validate_with(options)
+            .map_err(|e| ValidationFailures::from(vec![e]))
+    }
+
+    /// Indicates whether the data structure is valid using the default
+    /// [`ValidationOptions`].
+    fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+}
+
+impl<T: Validatable + ?Sized> Validatable for &T {
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), Error> {
+        (**self).validate_with(options)
+    }
+
+    fn validate_all_with(&self, options: &ValidationOptions) -> Result<(), ValidationFailures> {
+        (**self).validate_all_with(options)
+    }
+}
+
+impl<T: Validatable + ?Sized> Validatable for Box<T> {
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), Error> {
+        (**self).validate_with(options)
+    }
+
+    fn validate_all_with(&self, options: &ValidationOptions) -> Result<(), ValidationFailures> {
+        (**self).validate_all_with(options)
+    }
+}
+
+/// An absent value is valid, a present one is validated.
+impl<T: Validatable> Validatable for Option<T> {
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), Error> {
+        match self {
+            Some(t) => t.validate_with(options),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_all_with(&self, options: &ValidationOptions) -> Result<(), ValidationFailures> {
+        match self {
+            Some(t) => t.validate_all_with(options),
+            None => Ok(()),
+        }
+    }
+}
+
+/// An error while validating an IVMS data structure.
+///
+/// Most fallible functions of the crate return this error.
+/// [`Validatable::validate_all`] and [`IVMS101::edit`] report
+/// [`ValidationFailures`] and the person conversions a [`ConversionLoss`]
+/// instead. The LEI accessors return a [`lei::Error`]. All of them convert
+/// into this error, so `?` works across them:
+///
+/// ```
+/// use ivms101::{Beneficiary, Error, NameOrder, Person, Validatable, IVMS101};
+///
+/// fn originating_vasp_lei(json: &str) -> Result<Option<String>, Error> {
+///     let ivms = IVMS101::from_json(json)?;
+///     ivms.validate_all()?;
+///     let Some(vasp) = ivms.originating_vasp() else {
+///         return Ok(None);
+///     };
+///     Ok(vasp.lei()?.map(|lei| lei.to_string()))
+/// }
+///
+/// fn reclassify_beneficiary(ivms: &mut IVMS101) -> Result<(), Error> {
+///     let Some(Person::LegalPerson(company)) = ivms.beneficiary().map(|b| b.persons().first())
+///     else {
+///         return Ok(());
+///     };
+///     let person = company.clone().try_into_natural(NameOrder::GivenNameFirst)?;
+///     let beneficiary = Beneficiary::new(person.into(), None)?;
+///     ivms.edit(|ivms| ivms.set_beneficiary(beneficiary))?;
+///     Ok(())
+/// }
+///
+/// assert_eq!(originating_vasp_lei("{}"), Ok(None));
+/// assert!(originating_vasp_lei("[]").is_err());
+/// assert_eq!(reclassify_beneficiary(&mut IVMS101::default()), Ok(()));
+/// ```
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+    #[error("invalid country code: {0}")]
+    InvalidCountryCode(String),
+    #[error("Parse error: {0}")]
+    ParseError(String),
+    #[error("{path}: {source}")]
+    AtPath { path: String, source: Box<Error> },
+    #[error("Payload of {size} bytes exceeds the limit of {max} bytes")]
+    PayloadTooLarge { size: usize, max: usize },
+    #[error("Payload is nested deeper than {0} levels")]
+    TooDeep(usize),
+    #[error("I/O error: {0}")]
+    Io(String),
+}
+
+impl Error {
+    /// Attributes the error to the given field path, prepending it
+    /// to any path the error already carries.
+    pub(crate) fn at(self, path: &str) -> Self {
+        match self {
+            Self::AtPath {
+                path: inner,
+                source,
+            } => Self::AtPath {
+                path: if inner.starts_with('[') {
+                    format!("{path}{inner}")
+                } else {
+                    format!("{path}.{inner}")
+                },
+                source,
+            },
+            other => Self::AtPath {
+                path: path.to_owned(),
+                source: Box::new(other),
+            },
+        }
+    }
+}
+
+impl Error {
+    /// The field path the error is attributed to, if any.
+    #[must_use]
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            Self::AtPath { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+
+    /// The error without the field path it is attributed to.
+    #[must_use]
+    pub fn without_path(&self) -> &Error {
+        match self {
+            Self::AtPath { source, .. } => source.without_path(),
+            other => other,
+        }
+    }
+
+    /// The code of the violated IVMS101 constraint, e.g. `"C4"`, if the
+    /// error is due to one.
+    #[must_use]
+    pub fn constraint(&self) -> Option<&str> {
+        match self.without_path() {
+            Self::ValidationError(message) => message
+                .strip_suffix(')')?
+                .rsplit_once("(IVMS101 ")
+                .map(|(_, code)| code),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for std::io::Error {
+    fn from(error: Error) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, error)
+    }
+}
+
+impl From<lei::Error> for Error {
+    /// Reports an invalid LEI as a violation of C11.
+    fn from(error: lei::Error) -> Self {
+        Self::ValidationError(format!("Invalid LEI: {error} (IVMS101 C11)"))
+    }
+}
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self::ValidationError(value.to_owned())
+    }
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+#[allow(deprecated)]
+impl IVMS101 {
+    /// The originator person of a payload with a single natural person as
+    /// originator, such as the mock payload.
+    pub(crate) fn originator_person_mut(&mut self) -> &mut NaturalPerson {
+        let originator = self.originator.as_mut().expect("payload has an originator");
+        let OneToN::One(Person::NaturalPerson(person)) = &mut originator.originator_persons else {
+            panic!("originator is a single natural person");
+        };
+        person
+    }
+
+    /// The beneficiary person of a payload with a single legal person as
+    /// beneficiary, such as the mock payload.
+    pub(crate) fn beneficiary_person_mut(&mut self) -> &mut LegalPerson {
+        let beneficiary = self
+            .beneficiary
+            .as_mut()
+            .expect("payload has a beneficiary");
+        let OneToN::One(Person::LegalPerson(person)) = &mut beneficiary.beneficiary_persons else {
+            panic!("beneficiary is a single legal person");
+        };
+        person
+    }
+}
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod tests {
+    use super::*;
+    use serde_test::{assert_tokens, Token};
+
+    impl IVMS101 {
+        pub(crate) fn mock() -> Self {
+            let mut person = NaturalPerson::mock();
+            person.geographic_address = Some(Address::mock()).into();
+            let mut company = LegalPerson::mock();
+            company.customer_identification = Some("customer-b".try_into().unwrap());
+            let mut vasp = LegalPerson::mock();
+            vasp.name.name_identifier = LegalPersonNameID {
+                legal_person_name: "VASP B".try_into().unwrap(),
+                legal_person_name_identifier_type: LegalPersonNameTypeCode::Legal,
+            }
+            .into();
+            vasp.customer_identification = Some("vasp-id".try_into().unwrap());
+            Self {
+                originator: Some(Box::new(Originator {
+                    originator_persons: Person::NaturalPerson(person).into(),
+                    account_number: Some("account-1".try_into().unwrap()).into(),
+                })),
+                beneficiary: Some(Box::new(Beneficiary {
+                    beneficiary_persons: Person::LegalPerson(company).into(),
+                    account_number: Some("account-2".try_into().unwrap()).into(),
+                })),
+                originating_vasp: Some(Box::new(
+                    OriginatingVASP::new(
+                        "VASP A",
+                        &lei::LEI::try_from("2594007XIACKNMUAW223").unwrap(),
+                    )
+                    .unwrap(),
+                )),
+                beneficiary_vasp: Some(Box::new(BeneficiaryVASP {
+                    beneficiary_vasp: Some(Person::LegalPerson(vasp)),
+                })),
+            }
+        }
+    }
+
+    impl NaturalPerson {
+        pub(crate) fn mock() -> Self {
+            Self {
+                name: NaturalPersonName::mock().into(),
+                geographic_address: None.into(),
+                national_identification: None,
+                customer_identification: None,
+                date_and_place_of_birth: None,
+                country_of_residence: None,
+            }
+        }
+    }
+
+    impl LegalPerson {
+        pub(crate) fn mock() -> Self {
+            Self {
+                name: LegalPersonName::mock(),
+                geographic_address: None.into(),
+                customer_identification: None,
+                national_identification: None,
+                country_of_registration: None,
+            }
+        }
+    }
+
+    impl LegalPersonName {
+        pub(crate) fn mock() -> Self {
+            Self {
+                name_identifier: LegalPersonNameID::mock().into(),
+                local_name_identifier: None.into(),
+                phonetic_name_identifier: None.into(),
+            }
+        }
+    }
+
+    impl LegalPersonNameID {
+        pub(crate) fn mock() -> Self {
+            Self {
+                legal_person_name: "Company A".try_into().unwrap(),
+                legal_person_name_identifier_type: LegalPersonNameTypeCode::Legal,
+            }
+        }
+    }
+
+    impl NationalIdentification {
+        pub(crate) fn mock() -> Self {
+            Self {
+                national_identifier: "id".try_into().unwrap(),
+                national_identifier_type: NationalIdentifierTypeCode::Unspecified,
+                country_of_issue: None,
+                registration_authority: Some("RA000001".try_into().unwrap()),
+            }
+        }
+    }
+
+    impl Address {
+        pub(crate) fn mock() -> Self {
+            Self {
+                address_type: AddressTypeCode::Residential,
+                department: None,
+                sub_department: None,
+                street_name: None,
+                building_number: None,
+                building_name: None,
+                floor: None,
+                post_box: None,
+                room: None,
+                post_code: None,
+                town_name: "Zurich".try_into().unwrap(),
+                town_location_name: None,
+                district_name: None,
+                country_sub_division: None,
+                address_line: Some("Main street".try_into().unwrap()).into(),
+                country: "CH".try_into().unwrap(),
+            }
+        }
+    }
+
+    impl NaturalPersonNameID {
+        pub(crate) fn mock() -> Self {
+            Self {
+                primary_identifier: "Engels".try_into().unwrap(),
+                secondary_identifier: Some("Friedrich".try_into().unwrap()),
+                name_identifier_type: NaturalPersonNameTypeCode::LegalName,
+            }
+        }
+    }
+
+    impl NaturalPersonName {
+        pub(crate) fn mock() -> Self {
+            Self {
+                name_identifier: NaturalPersonNameID::mock().into(),
+                local_name_identifier: None.into(),
+                phonetic_name_identifier: None.into(),
+            }
+        }
+    }
+
+    impl DateAndPlaceOfBirth {
+        pub(crate) fn mock() -> Self {
+            Self {
+                date_of_birth: chrono::NaiveDate::from_ymd_opt(1946, 11, 5).unwrap(),
+                place_of_birth: "London".try_into().unwrap(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_date() {
+        assert_tokens(
+            &Date::from_ymd_opt(2018, 11, 5).unwrap(),
+            &[Token::String("2018-11-05")],
+        );
+    }
+
+    /// Asserts that every variant of a type code enum has the given code
+    /// on the wire and as [`wire_code`](AddressTypeCode::wire_code). The
+    /// `match` fails to compile if a variant is missing from the table.
+    macro_rules! assert_wire_codes {
+        ($enum:ident { $($variant:ident => $code:literal),+ $(,)? }) => {
+            let _exhaustive = |code: $enum| match code {
+                $($enum::$variant => ()),+
+            };
+            $(
+                let code = $enum::$variant;
+                assert_eq!(code.wire_code(), $code);
+                let json = serde_json::to_string(&code).unwrap();
+                assert_eq!(json, concat!("\"", $code, "\""));
+                assert_eq!(serde_json::from_str::<$enum>(&json).unwrap(), code);
+            )+
+        };
+    }
+
+    #[test]
+    fn test_wire_codes() {
+        assert_wire_codes!(NaturalPersonNameTypeCode {
+            Alias => "ALIA",
+            NameAtBirth => "BIRT",
+            MaidenName => "MAID",
+            LegalName => "LEGL",
+            Unspecified => "MISC",
+        });
+        assert_wire_codes!(LegalPersonNameTypeCode {
+            Legal => "LEGL",
+            Short => "SHRT",
+            Trading => "TRAD",
+        });
+        assert_wire_codes!(AddressTypeCode {
+            Residential => "HOME",
+            Business => "BIZZ",
+            Geographic => "GEOG",
+        });
+        assert_wire_codes!(NationalIdentifierTypeCode {
+            AlienRegistrationNumber => "ARNU",
+            PassportNumber => "CCPT",
+            RegistrationAuthorityIdentifier => "RAID",
+            DriverLicenseNumber => "DRLC",
+            ForeignInvestmentIdentityNumber => "FIIN",
+            TaxIdentificationNumber => "TXID",
+            SocialSecurityNumber => "SOCS",
+            IdentityCardNumber => "IDCD",
+            LegalEntityIdentifier => "LEIX",
+            Unspecified => "MISC",
+        });
+    }
+
+    #[test]
+    fn test_lowercase_type_codes() {
+        for code in ["\"legl\"", "\"LEGL\""] {
+            assert_eq!(
+                serde_json::from_str::<NaturalPersonNameTypeCode>(code).unwrap(),
+                NaturalPersonNameTypeCode::LegalName
+            );
+            assert_eq!(
+                serde_json::from_str::<LegalPersonNameTypeCode>(code).unwrap(),
+                LegalPersonNameTypeCode::Legal
+            );
+        }
+        let code: AddressTypeCode = serde_json::from_str("\"geog\"").unwrap();
+        assert_eq!(serde_json::to_string(&code).unwrap(), "\"GEOG\"");
+        let code: NationalIdentifierTypeCode = serde_json::from_str("\"leix\"").unwrap();
+        assert_eq!(code, NationalIdentifierTypeCode::LegalEntityIdentifier);
+        assert!(serde_json::from_str::<AddressTypeCode>("\"Geog\"").is_err());
+    }
+
+    #[test]
+    fn test_type_codes() {
+        assert_tokens(
+            &NaturalPersonNameTypeCode::Alias,
+            &[Token::UnitVariant {
+                name: "NaturalPersonNameTypeCode",
+                variant: "ALIA",
+            }],
+        );
+        assert_tokens(
+            &LegalPersonNameTypeCode::Legal,
+            &[Token::UnitVariant {
+                name: "LegalPersonNameTypeCode",
+                variant: "LEGL",
+            }],
+        );
+        assert_tokens(
+            &AddressTypeCode::Business,
+            &[Token::UnitVariant {
+                name: "AddressTypeCode",
+                variant: "BIZZ",
+            }],
+        );
+        assert_tokens(
+            &NationalIdentifierTypeCode::AlienRegistrationNumber,
+            &[Token::UnitVariant {
+                name: "NationalIdentifierTypeCode",
+                variant: "ARNU",
+            }],
+        );
+    }
+
+    fn match_validation_error(val: &impl Validatable, code: u8) {
+        let res = val.validate();
+        assert!(res
+            .unwrap_err()
+            .to_string()
+            .ends_with(format!("(IVMS101 C{code})").as_str()));
+    }
+
+    #[test]
+    fn test_person_serialization() {
+        let person = Person::NaturalPerson(NaturalPerson::mock());
+        let serialized = serde_json::to_string(&person).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"naturalPerson":{"name":{"nameIdentifier":{"primaryIdentifier":"Engels","secondaryIdentifier":"Friedrich","nameIdentifierType":"LEGL"}}}}"#
+        );
+        let deserialized: Person = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(person, deserialized);
+
+        let person = Person::LegalPerson(LegalPerson::mock());
+        let serialized = serde_json::to_string(&person).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"legalPerson":{"name":{"nameIdentifier":{"legalPersonName":"Company A","legalPersonNameIdentifierType":"LEGL"}}}}"#
+        );
+        let deserialized: Person = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(person, deserialized);
+    }
+
+    #[test]
+    fn test_ivms101_size() {
+        // Inline sections made the struct 2880 bytes on 64-bit targets.
+        assert_eq!(
+            std::mem::size_of::<IVMS101>(),
+            4 * std::mem::size_of::<usize>()
+        );
+    }
+
+    #[test]
+    fn test_ivms101_serialization() {
+        let ivms = IVMS101::mock();
+        ivms.validate().unwrap();
+        assert_eq!(ivms.originator(), ivms.originator.as_deref());
+        let serialized = serde_json::to_string(&ivms).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"originator":{"originatorPersons":{"naturalPerson":{"name":{"nameIdentifier":{"primaryIdentifier":"Engels","secondaryIdentifier":"Friedrich","nameIdentifierType":"LEGL"}},"geographicAddress":{"addressType":"HOME","townName":"Zurich","addressLine":"Main street","country":"CH"}}},"accountNumber":"account-1"},"beneficiary":{"beneficiaryPersons":{"legalPerson":{"name":{"nameIdentifier":{"legalPersonName":"Company A","legalPersonNameIdentifierType":"LEGL"}},"customerIdentification":"customer-b"}},"accountNumber":"account-2"},"originatingVASP":{"originatingVASP":{"legalPerson":{"name":{"nameIdentifier":{"legalPersonName":"VASP A","legalPersonNameIdentifierType":"LEGL"}},"nationalIdentification":{"nationalIdentifier":"2594007XIACKNMUAW223","nationalIdentifierType":"LEIX"}}}},"beneficiaryVASP":{"beneficiaryVASP":{"legalPerson":{"name":{"nameIdentifier":{"legalPersonName":"VASP B","legalPersonNameIdentifierType":"LEGL"}},"customerIdentification":"vasp-id"}}}}"#
+        );
+        let deserialized: IVMS101 = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(serde_json::to_string(&deserialized).unwrap(), serialized);
+    }
+
+    #[test]
+    fn test_name_id_constructors() {
+        let legal = NaturalPersonNameID::legal_name("Engels", Some("Friedrich")).unwrap();
+        assert_eq!(legal, NaturalPersonNameID::mock());
+        let alias = NaturalPersonNameID::alias("General", None).unwrap();
+        assert_eq!(alias.name_identifier_type, NaturalPersonNameTypeCode::Alias);
+        assert_eq!(alias.secondary_identifier, None);
+        assert!(NaturalPersonNameID::legal_name("Engels", Some(&"F".repeat(101))).is_err());
+
+        let legal = LegalPersonNameID::legal_name("Company A").unwrap();
+        assert_eq!(legal, LegalPersonNameID::mock());
+        let trading = LegalPersonNameID::trading_name("A").unwrap();
+        assert_eq!(
+            trading.legal_person_name_identifier_type,
+            LegalPersonNameTypeCode::Trading
+        );
+        assert!(LegalPersonNameID::legal_name(&"A".repeat(101)).is_err());
+    }
+
+    #[test]
+    fn test_intermediary_vasp() {
+        let leis = [
+            lei::LEI::try_from("2594007XIACKNMUAW223").unwrap(),
+            lei::LEI::try_from("549300MLUDYVRQOOXS22").unwrap(),
+        ];
+        let path: Vec<IntermediaryVASP> = ["VASP C", "VASP D"]
+            .into_iter()
+            .zip(&leis)
+            .zip(1..)
+            .map(|((name, lei), sequence)| IntermediaryVASP::new(name, lei, sequence).unwrap())
+            .collect();
+        for (vasp, (name, lei)) in path.iter().zip(["VASP C", "VASP D"].iter().zip(&leis)) {
+            vasp.validate().unwrap();
+            assert_eq!(vasp.name(), *name);
+            assert_eq!(vasp.lei().unwrap().as_ref(), Some(lei));
+        }
+        assert_eq!(path[1].sequence, 2);
+
+        let natural = IntermediaryVASP {
+            intermediary_vasp: NaturalPerson::mock().into(),
+            sequence: 1,
+        };
+        assert_eq!(
+            natural.validate(),
+            Err(Error::ValidationError(
+                "Intermediary VASP must be a legal person".into()
+            ))
+        );
+        let beneficiary_vasp = BeneficiaryVASP {
+            beneficiary_vasp: Some(NaturalPerson::mock().into()),
+        };
+        assert!(beneficiary_vasp.validate().is_err());
+    }
+
+    #[test]
+    fn test_person_count() {
+        let mut originator = Originator::new(NaturalPerson::mock().into()).unwrap();
+        assert_eq!(originator.person_count(), 1);
+        assert!(originator.is_single_person());
+        originator.originator_persons = OneToN::N(
+            vec![NaturalPerson::mock().into(), LegalPerson::mock().into()]
+                .try_into()
+                .unwrap(),
+        );
+        assert_eq!(originator.person_count(), 2);
+        assert!(!originator.is_single_person());
+
+        let mut beneficiary = Beneficiary::new(LegalPerson::mock().into(), None).unwrap();
+        assert!(beneficiary.is_single_person());
+        beneficiary.beneficiary_persons =
+            OneToN::N(vec![LegalPerson::mock().into()].try_into().unwrap());
+        assert_eq!(beneficiary.person_count(), 1);
+    }
+
+    #[test]
+    fn test_addresses_by_type() {
+        let address = |address_type, town: &str| {
+            let mut address = Address::mock();
+            address.set_address_type(address_type);
+            address.town_name = town.try_into().unwrap();
+            address
+        };
+        let mut person = LegalPerson::mock();
+        person.geographic_address = vec![
+            address(AddressTypeCode::Residential, "Basel"),
+            address(AddressTypeCode::Business, "Bern"),
+            address(AddressTypeCode::Geographic, "Zug"),
+        ]
+        .into();
+        let towns = |person: &Person, address_type| {
+            person
+                .addresses_of_type(address_type)
+                .map(|a| a.town_name.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let person = Person::from(person);
+        assert_eq!(towns(&person, AddressTypeCode::Business), ["Bern"]);
+        assert_eq!(person.address().unwrap().town_name.as_str(), "Basel");
+        assert_eq!(person.primary_address().unwrap().town_name.as_str(), "Zug");
+        assert_eq!(
+            person.primary_address().unwrap().address_type(),
+            &AddressTypeCode::Geographic
+        );
+
+        let mut person = NaturalPerson::mock();
+        person.geographic_address = vec![
+            address(AddressTypeCode::Residential, "Basel"),
+            address(AddressTypeCode::Business, "Bern"),
+        ]
+        .into();
+        assert_eq!(person.primary_address().unwrap().town_name.as_str(), "Bern");
+        person.geographic_address = Some(address(AddressTypeCode::Residential, "Basel")).into();
+        assert_eq!(
+            person.primary_address().unwrap().town_name.as_str(),
+            "Basel"
+        );
+        person.geographic_address = None.into();
+        assert_eq!(person.primary_address(), None);
+    }
+
+    #[test]
+    fn test_associated_countries() {
+        let mut person = NaturalPerson::mock();
+        person.country_of_residence = Some("CH".try_into().unwrap());
+        let mut address = Address::mock();
+        address.country = "IR".try_into().unwrap();
+        person.geographic_address = vec![Address::mock(), address].into();
+        let mut id = NationalIdentification::mock();
+        id.country_of_issue = Some("DE".try_into().unwrap());
+        person.national_identification = Some(id);
+        let person = Person::from(person);
 
-/// An error while validating an IVMS data structure.
-#[derive(thiserror::Error, Debug, PartialEq, Eq)]
-pub enum Error {
-    #[error("Validation error: {0}")]
-    ValidationError(String),
-    #[error("invalid country code: {0}")]
-    InvalidCountryCode(String),
-}
+        let countries: Vec<&str> = person
+            .associated_countries()
+            .into_iter()
+            .map(CountryCode::as_str)
+            .collect();
+        assert_eq!(countries, ["CH", "IR", "DE"]);
+        let flagged: Vec<CountryCode> = ["IR", "KP"]
+            .into_iter()
+            .map(|c| c.try_into().unwrap())
+            .collect();
+        assert!(person.touches_any(&flagged));
+        assert!(!Person::from(LegalPerson::mock()).touches_any(&flagged));
+    }
 
-impl From<&str> for Error {
-    fn from(value: &str) -> Self {
-        Self::ValidationError(value.to_owned())
+    #[test]
+    fn test_mononym() {
+        let person = NaturalPerson::new_mononym("Sukarno", None, Some(Address::mock())).unwrap();
+        person.validate().unwrap();
+        let json = serde_json::to_string(&person).unwrap();
+        assert!(json.contains(
+            r#""nameIdentifier":{"primaryIdentifier":"Sukarno","nameIdentifierType":"LEGL"}"#
+        ));
+        assert!(!json.contains("secondaryIdentifier"));
+
+        let person = Person::from(person);
+        assert_eq!(person.first_name(), None);
+        assert_eq!(person.full_name(), "Sukarno");
+        let person = Person::from(NaturalPerson::new("", "Sukarno", None, None).unwrap());
+        assert_eq!(person.full_name(), "Sukarno");
+        assert_eq!(
+            Person::from(NaturalPerson::mock()).full_name(),
+            "Friedrich Engels"
+        );
+        assert_eq!(Person::from(LegalPerson::mock()).full_name(), "Company A");
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use serde_test::{assert_tokens, Token};
+    #[test]
+    fn test_malformed_passport_number() {
+        let mut person = NaturalPerson::mock();
+        let mut id = NationalIdentification::mock();
+        id.national_identifier_type = NationalIdentifierTypeCode::PassportNumber;
+        id.registration_authority = None;
+        id.country_of_issue = Some("CH".try_into().unwrap());
+        person.national_identification = Some(id);
 
-    impl NaturalPerson {
-        fn mock() -> Self {
-            Self {
-                name: NaturalPersonName::mock().into(),
-                geographic_address: None.into(),
-                national_identification: None,
-                customer_identification: None,
-                date_and_place_of_birth: None,
-                country_of_residence: None,
-            }
+        for number in ["AB 12", "", "AB12", "AB1234567890123456", "AB-12345"] {
+            person
+                .national_identification
+                .as_mut()
+                .unwrap()
+                .national_identifier = number.try_into().unwrap();
+            assert_eq!(
+                person.warnings(),
+                [Warning::MalformedPassportNumber],
+                "{number}"
+            );
+            person.validate().unwrap();
         }
+
+        person
+            .national_identification
+            .as_mut()
+            .unwrap()
+            .national_identifier = "AB123456".try_into().unwrap();
+        assert!(person.warnings().is_empty());
     }
 
-    impl LegalPerson {
-        fn mock() -> Self {
-            Self {
-                name: LegalPersonName::mock(),
-                geographic_address: None.into(),
-                customer_identification: None,
-                national_identification: None,
-                country_of_registration: None,
-            }
-        }
+    #[test]
+    fn test_identity_document_country_of_issue() {
+        let mut person = NaturalPerson::mock();
+        let mut id = NationalIdentification::mock();
+        id.national_identifier_type = NationalIdentifierTypeCode::PassportNumber;
+        id.national_identifier = "AB123456".try_into().unwrap();
+        id.registration_authority = None;
+        person.national_identification = Some(id);
+        person.validate().unwrap();
+        assert_eq!(
+            person.warnings(),
+            [Warning::IdentityDocumentWithoutCountryOfIssue]
+        );
+        let err = person
+            .validate_with(&ValidationOptions::strict())
+            .unwrap_err();
+        assert_eq!(err.path(), Some("nationalIdentification.countryOfIssue"));
+
+        person
+            .national_identification
+            .as_mut()
+            .unwrap()
+            .country_of_issue = Some("CH".try_into().unwrap());
+        person.validate_with(&ValidationOptions::strict()).unwrap();
+        assert!(person.warnings().is_empty());
+
+        let err = serde_json::from_str::<NationalIdentification>(
+            r#"{"nationalIdentifier":"X1","nationalIdentifierType":"CCPT","countryOfIssue":"QQ"}"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("invalid country code: QQ"));
     }
 
-    impl LegalPersonName {
-        fn mock() -> Self {
-            Self {
-                name_identifier: LegalPersonNameID::mock().into(),
-                local_name_identifier: None.into(),
-                phonetic_name_identifier: None.into(),
-            }
+    #[test]
+    fn test_person_from() {
+        let person: Person = NaturalPerson::mock().into();
+        assert_eq!(person, Person::NaturalPerson(NaturalPerson::mock()));
+        let person: Person = LegalPerson::mock().into();
+        assert_eq!(person, Person::LegalPerson(LegalPerson::mock()));
+    }
+
+    #[test]
+    fn test_person_kind() {
+        let person: Person = NaturalPerson::mock().into();
+        assert_eq!(person.kind(), PersonKind::Natural);
+        assert_eq!(person.kind().to_string(), "natural");
+        let person: Person = LegalPerson::mock().into();
+        assert_eq!(person.kind(), PersonKind::Legal);
+        assert_eq!(person.kind().to_string(), "legal");
+    }
+
+    /// The serialized fields of a natural person.
+    const NATURAL_PERSON_FIELDS: &[&str] = &[
+        "name",
+        "geographicAddress",
+        "nationalIdentification",
+        "customerIdentification",
+        "dateAndPlaceOfBirth",
+        "countryOfResidence",
+    ];
+
+    /// The serialized fields of a legal person.
+    const LEGAL_PERSON_FIELDS: &[&str] = &[
+        "name",
+        "geographicAddress",
+        "customerIdentification",
+        "nationalIdentification",
+        "countryOfRegistration",
+    ];
+
+    /// Guards against a field being added to the wrong type of person.
+    #[test]
+    fn test_person_variant_fields() {
+        fn fields(person: &Person) -> Vec<String> {
+            let options = SerializeOptions {
+                always_array: false,
+                empty_as_array: true,
+            };
+            let value = options.to_value(person);
+            let (_, fields) = value.as_object().unwrap().iter().next().unwrap();
+            fields.as_object().unwrap().keys().cloned().collect()
         }
+
+        let mut natural = NaturalPerson::mock();
+        natural.geographic_address = Some(Address::mock()).into();
+        natural.national_identification = Some(NationalIdentification::mock());
+        natural.customer_identification = Some("customer-a".try_into().unwrap());
+        natural.date_and_place_of_birth = Some(DateAndPlaceOfBirth::mock());
+        natural.country_of_residence = Some("CH".try_into().unwrap());
+        let natural = Person::NaturalPerson(natural);
+        let mut natural_fields = fields(&natural);
+        natural_fields.sort();
+        let mut expected = NATURAL_PERSON_FIELDS.to_vec();
+        expected.sort_unstable();
+        assert_eq!(natural_fields, expected);
+
+        let mut legal = LegalPerson::mock();
+        legal.geographic_address = Some(Address::mock()).into();
+        legal.national_identification = Some(NationalIdentification::mock());
+        legal.customer_identification = Some("customer-b".try_into().unwrap());
+        legal.country_of_registration = Some("CH".try_into().unwrap());
+        let legal = Person::LegalPerson(legal);
+        let mut legal_fields = fields(&legal);
+        legal_fields.sort();
+        let mut expected = LEGAL_PERSON_FIELDS.to_vec();
+        expected.sort_unstable();
+        assert_eq!(legal_fields, expected);
     }
 
-    impl LegalPersonNameID {
-        fn mock() -> Self {
-            Self {
-                legal_person_name: "Company A".try_into().unwrap(),
-                legal_person_name_identifier_type: LegalPersonNameTypeCode::Legal,
+    #[test]
+    fn test_edit() {
+        fn company(ivms: &mut IVMS101) -> &mut LegalPerson {
+            match ivms
+                .beneficiary
+                .as_mut()
+                .map(|b| &mut b.beneficiary_persons)
+            {
+                Some(OneToN::One(Person::LegalPerson(company))) => company,
+                _ => unreachable!(),
             }
         }
+
+        let mut ivms = IVMS101::mock();
+        ivms.edit(|ivms| {
+            company(ivms).geographic_address = Some(Address::mock()).into();
+        })
+        .unwrap();
+        assert!(ivms
+            .beneficiary()
+            .unwrap()
+            .beneficiary_persons
+            .first()
+            .address()
+            .is_some());
+
+        company(&mut ivms).customer_identification = None;
+        ivms.validate().unwrap();
+        let before = ivms.clone();
+        let errors = ivms
+            .edit(|ivms| company(ivms).geographic_address = ZeroToN::None)
+            .unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].constraint(), Some("C4"));
+        assert_eq!(Error::from(errors.clone()), errors[0]);
+        assert_eq!(ivms, before);
     }
 
-    impl NationalIdentification {
-        fn mock() -> Self {
-            Self {
-                national_identifier: "id".try_into().unwrap(),
-                national_identifier_type: NationalIdentifierTypeCode::Unspecified,
-                country_of_issue: None,
-                registration_authority: Some("RA000001".try_into().unwrap()),
+    #[test]
+    fn test_setters() {
+        let mut person = NaturalPerson::mock();
+        person.set_addresses(vec![Address::mock()]).unwrap();
+        let err = person.set_customer_identification(Some(&"x".repeat(51)));
+        assert_eq!(
+            err,
+            Err(Error::ValidationError(
+                "Customer identification exceeds 50 bytes".into()
+            ))
+        );
+        person
+            .set_customer_identification(Some("customer-1"))
+            .unwrap();
+        person.set_customer_identification(None).unwrap();
+        person.clear_addresses().unwrap_err();
+
+        let mut name = NaturalPersonName::new(NaturalPersonNameID::mock());
+        name.add_local_name_identifier(NaturalPersonNameID::legal_name("恩格斯", None).unwrap());
+        name.add_phonetic_name_identifier(NaturalPersonNameID::mock());
+        person.set_name(name.clone());
+        assert!(person.names().eq([&name]));
+        person.add_name(name.clone());
+        assert_eq!(person.names().count(), 1);
+
+        let mut address = Address::mock();
+        address.set_town_name("Zürich").unwrap();
+        address.set_floor(Some("3")).unwrap();
+        address.set_address_line(&["Line 1", "Line 2"]).unwrap();
+        address.set_post_code(Some(&"1".repeat(17))).unwrap_err();
+        address.set_country("QQ").unwrap_err();
+        assert_eq!(address.town_name(), "Zürich");
+        assert_eq!(address.floor(), Some("3"));
+        assert!(address.address_line().eq(["Line 1", "Line 2"]));
+        assert_eq!(address.post_code(), Address::mock().post_code());
+        assert_eq!(address.country(), &"CH");
+
+        let mut ivms = IVMS101::mock();
+        let mut originator = ivms.originator().unwrap().clone();
+        assert!(originator.remove_account_number("account-1"));
+        assert!(!originator.remove_account_number("account-1"));
+        assert_eq!(originator.account_numbers().count(), 0);
+        ivms.set_originator(originator);
+        ivms.clear_beneficiary_vasp();
+        assert_eq!(ivms.beneficiary_vasp_status(), VaspStatus::Absent);
+        ivms.clear_originator();
+        assert!(ivms.originator().is_none());
+    }
+
+    #[test]
+    fn test_display_summary() {
+        let ivms = IVMS101::mock();
+        let summary = ivms.to_string();
+        assert!(summary.contains("Beneficiary: Company A"), "{summary}");
+        assert!(summary.contains("2594007XIACKNMUAW223"), "{summary}");
+        assert!(!summary.contains('\n'));
+
+        let ivms: IVMS101 = serde_json::from_str("{}").unwrap();
+        assert_eq!(ivms.to_string(), "Originator: - -> Beneficiary: -");
+    }
+
+    #[test]
+    fn test_snake_case_aliases() {
+        fn snake_case(value: serde_json::Value) -> serde_json::Value {
+            match value {
+                serde_json::Value::Object(map) => map
+                    .into_iter()
+                    .map(|(key, value)| {
+                        let mut snake = String::new();
+                        for (i, c) in key.replace("VASP", "Vasp").chars().enumerate() {
+                            if c.is_uppercase() && i > 0 {
+                                snake.push('_');
+                            }
+                            snake.push(c.to_ascii_lowercase());
+                        }
+                        (snake, snake_case(value))
+                    })
+                    .collect(),
+                serde_json::Value::Array(values) => values.into_iter().map(snake_case).collect(),
+                value => value,
             }
         }
+
+        let mut ivms = IVMS101::mock();
+        let mut person = NaturalPerson::mock();
+        person.geographic_address = Some(Address::mock()).into();
+        person.date_and_place_of_birth = Some(DateAndPlaceOfBirth::mock());
+        person.national_identification = Some(NationalIdentification::mock());
+        ivms.originator.as_mut().unwrap().originator_persons = Person::NaturalPerson(person).into();
+        let camel = serde_json::to_string(&ivms).unwrap();
+
+        let snake = snake_case(serde_json::from_str(&camel).unwrap()).to_string();
+        assert!(snake.contains("\"originating_vasp\":{\"originating_vasp\":{\"legal_person\""));
+        assert!(snake.contains("\"date_and_place_of_birth\""));
+        let parsed = IVMS101::from_json(&snake).unwrap();
+        assert_eq!(parsed, ivms);
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), camel);
     }
 
-    impl Address {
-        fn mock() -> Self {
-            Self {
-                address_type: AddressTypeCode::Residential,
-                department: None,
-                sub_department: None,
-                street_name: None,
-                building_number: None,
-                building_name: None,
-                floor: None,
-                post_box: None,
-                room: None,
-                post_code: None,
-                town_name: "Zurich".try_into().unwrap(),
-                town_location_name: None,
-                district_name: None,
-                country_sub_division: None,
-                address_line: Some("Main street".try_into().unwrap()).into(),
-                country: "CH".try_into().unwrap(),
-            }
+    #[test]
+    fn test_name_identifier_shapes() {
+        let object = r#"{"nameIdentifier":{"primaryIdentifier":"Engels","secondaryIdentifier":"Friedrich","nameIdentifierType":"LEGL"}}"#;
+        let array = r#"{"nameIdentifier":[{"primaryIdentifier":"Engels","secondaryIdentifier":"Friedrich","nameIdentifierType":"LEGL"}],"localNameIdentifier":[]}"#;
+        let from_object: NaturalPersonName = serde_json::from_str(object).unwrap();
+        let from_array: NaturalPersonName = serde_json::from_str(array).unwrap();
+        assert_eq!(from_object, NaturalPersonName::mock());
+        assert_eq!(from_array, NaturalPersonName::mock());
+        assert_eq!(serde_json::to_string(&from_array).unwrap(), object);
+
+        let object = r#"{"nameIdentifier":{"legalPersonName":"Company A","legalPersonNameIdentifierType":"LEGL"}}"#;
+        let array = r#"{"nameIdentifier":[{"legalPersonName":"Company A","legalPersonNameIdentifierType":"LEGL"}],"phoneticNameIdentifier":[{"legalPersonName":"Company A","legalPersonNameIdentifierType":"LEGL"}]}"#;
+        let from_object: LegalPersonName = serde_json::from_str(object).unwrap();
+        let from_array: LegalPersonName = serde_json::from_str(array).unwrap();
+        assert_eq!(from_object.name_identifier, from_array.name_identifier);
+        assert!(matches!(
+            from_array.phonetic_name_identifier,
+            ZeroToN::One(_)
+        ));
+    }
+
+    #[test]
+    fn test_unknown_fields() {
+        let json = r#"{"originator":{"originatorPersons":{"naturalPerson":{"name":{"nameIdentifier":{"primaryIdentifier":"Engels","nameIdentifierType":"LEGL"}},"customerIdentification":"customer-a","futureField":1}}},"futureSection":{}}"#;
+        let known = r#"{"originator":{"originatorPersons":{"naturalPerson":{"name":{"nameIdentifier":{"primaryIdentifier":"Engels","nameIdentifierType":"LEGL"}},"customerIdentification":"customer-a"}}}}"#;
+        let value: serde_json::Value = serde_json::from_str(json).unwrap();
+        if cfg!(feature = "strict-fields") {
+            let err = IVMS101::from_json(json).unwrap_err();
+            assert!(err.to_string().contains("unknown field `futureField`"));
+            assert!(IVMS101::from_value(value).is_err());
+        } else {
+            let ivms = IVMS101::from_json(json).unwrap();
+            assert_eq!(ivms.to_json(), known);
+            assert_eq!(IVMS101::from_value(value).unwrap(), ivms);
         }
     }
 
-    impl NaturalPersonNameID {
-        fn mock() -> Self {
-            Self {
-                primary_identifier: "Engels".try_into().unwrap(),
-                secondary_identifier: Some("Friedrich".try_into().unwrap()),
-                name_identifier_type: NaturalPersonNameTypeCode::LegalName,
-            }
+    #[test]
+    fn test_empty_required_field() {
+        let err = IVMS101::from_json(r#"{"originator":{"originatorPersons":[]}}"#).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Parse error: originatorPersons must contain at least one element"));
+
+        let err = serde_json::from_str::<LegalPersonName>(r#"{"nameIdentifier":[]}"#).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("nameIdentifier must contain at least one element"));
+    }
+
+    #[test]
+    fn test_empty_required_field_locations() {
+        let cases = [
+            (
+                r#"{"beneficiary":{"beneficiaryPersons":[]}}"#,
+                "beneficiaryPersons",
+            ),
+            (
+                r#"{"originator":{"originatorPersons":{"naturalPerson":{"name":[]}}}}"#,
+                "name",
+            ),
+            (
+                r#"{"originator":{"originatorPersons":{"naturalPerson":{"name":{"nameIdentifier":[]}}}}}"#,
+                "nameIdentifier",
+            ),
+            (
+                r#"{"beneficiary":{"beneficiaryPersons":{"legalPerson":{"name":{"nameIdentifier":[]}}}}}"#,
+                "nameIdentifier",
+            ),
+        ];
+        for (json, field) in cases {
+            let err = IVMS101::from_json(json).unwrap_err().to_string();
+            assert!(
+                err.starts_with(&format!(
+                    "Parse error: {field} must contain at least one element"
+                )),
+                "{err}"
+            );
         }
     }
 
-    impl NaturalPersonName {
-        fn mock() -> Self {
-            Self {
-                name_identifier: NaturalPersonNameID::mock().into(),
-                local_name_identifier: None.into(),
-                phonetic_name_identifier: None.into(),
+    #[test]
+    fn test_validatable_validate_with_only() {
+        struct Positive(i32);
+
+        impl Validatable for Positive {
+            fn validate_with(&self, _options: &ValidationOptions) -> Result<(), Error> {
+                if self.0 <= 0 {
+                    return Err("Value must be positive".into());
+                }
+                Ok(())
             }
         }
+
+        assert!(Positive(1).validate().is_ok());
+        assert!(Positive(0).validate().is_err());
+        assert_eq!(Positive(-1).validate_all().unwrap_err().len(), 1);
     }
 
-    impl DateAndPlaceOfBirth {
-        fn mock() -> Self {
-            Self {
-                date_of_birth: chrono::NaiveDate::from_ymd_opt(1946, 11, 5).unwrap(),
-                place_of_birth: "London".try_into().unwrap(),
-            }
+    #[test]
+    fn test_validatable_trait_objects() {
+        fn check(items: &[&dyn Validatable]) -> Vec<Error> {
+            items
+                .iter()
+                .filter_map(|item| item.validate().err())
+                .collect()
+        }
+
+        let mut name = NaturalPersonNameID::mock();
+        name.name_identifier_type = NaturalPersonNameTypeCode::Alias;
+        let mut invalid = NaturalPerson::mock();
+        invalid.name = NaturalPersonName {
+            name_identifier: name.into(),
+            local_name_identifier: None.into(),
+            phonetic_name_identifier: None.into(),
         }
+        .into();
+        let none: Option<Address> = None;
+        assert_eq!(
+            check(&[&IVMS101::mock(), &invalid, &none, &Some(Address::mock())]).len(),
+            1
+        );
+
+        let items: Vec<Box<dyn Validatable>> = vec![
+            Box::new(IVMS101::mock()),
+            Box::new(Some(invalid)),
+            Box::new(Box::new(LegalPersonName::mock())),
+        ];
+        let errors: Vec<Error> = items
+            .iter()
+            .filter_map(|item| item.validate().err())
+            .collect();
+        assert_eq!(errors.len(), 1);
     }
 
     #[test]
-    fn test_date() {
-        assert_tokens(
-            &Date::from_ymd_opt(2018, 11, 5).unwrap(),
-            &[Token::String("2018-11-05")],
+    fn test_unhosted_withdrawal() {
+        let mut originator = NaturalPerson::mock();
+        originator.geographic_address = Some(Address::mock()).into();
+        let ivms = IVMS101::for_unhosted_withdrawal(
+            Person::NaturalPerson(originator),
+            "account-1",
+            "Karl",
+            "Marx",
+            "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq",
+        )
+        .unwrap();
+        assert_eq!(
+            serde_json::to_string(&ivms).unwrap(),
+            r#"{"originator":{"originatorPersons":{"naturalPerson":{"name":{"nameIdentifier":{"primaryIdentifier":"Engels","secondaryIdentifier":"Friedrich","nameIdentifierType":"LEGL"}},"geographicAddress":{"addressType":"HOME","townName":"Zurich","addressLine":"Main street","country":"CH"}}},"accountNumber":"account-1"},"beneficiary":{"beneficiaryPersons":{"naturalPerson":{"name":{"nameIdentifier":{"primaryIdentifier":"Marx","secondaryIdentifier":"Karl","nameIdentifierType":"LEGL"}}}},"accountNumber":"bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq"}}"#
         );
+
+        let mut ivms = ivms;
+        ivms.originating_vasp = IVMS101::mock().originating_vasp;
+        ivms.validate().unwrap();
+        assert!(ivms.beneficiary_vasp().is_none());
+
+        // The originator still needs to be identifiable (C1).
+        assert!(IVMS101::for_unhosted_withdrawal(
+            Person::NaturalPerson(NaturalPerson::mock()),
+            "account-1",
+            "Karl",
+            "Marx",
+            "wallet",
+        )
+        .is_err());
+        assert!(Beneficiary::unhosted("Karl", &"x".repeat(101), "wallet").is_err());
     }
 
     #[test]
-    fn test_type_codes() {
-        assert_tokens(
-            &NaturalPersonNameTypeCode::Alias,
-            &[Token::UnitVariant {
-                name: "NaturalPersonNameTypeCode",
-                variant: "ALIA",
-            }],
+    fn test_validate_all() {
+        let mut ivms = IVMS101::mock();
+        ivms.validate_all().unwrap();
+        ivms.originator = Some(Box::new(Originator {
+            originator_persons: OneToN::N(
+                vec![
+                    Person::NaturalPerson(NaturalPerson::mock()),
+                    Person::LegalPerson(LegalPerson::mock()),
+                ]
+                .try_into()
+                .unwrap(),
+            ),
+            account_number: None.into(),
+        }));
+        ivms.beneficiary.as_mut().unwrap().beneficiary_persons =
+            Person::LegalPerson(LegalPerson::mock()).into();
+
+        let errors = ivms.validate_all().unwrap_err();
+        let paths: Vec<_> = errors.iter().map(|e| e.path().unwrap()).collect();
+        assert_eq!(
+            paths,
+            [
+                "originator.originatorPersons[0]",
+                "originator.originatorPersons[1]",
+                "beneficiary.beneficiaryPersons[0]"
+            ]
         );
-        assert_tokens(
-            &LegalPersonNameTypeCode::Legal,
-            &[Token::UnitVariant {
-                name: "LegalPersonNameTypeCode",
-                variant: "LEGL",
-            }],
+        let constraints: Vec<_> = errors.iter().map(|e| e.constraint().unwrap()).collect();
+        assert_eq!(constraints, ["C1", "C4", "C4"]);
+        assert_eq!(ivms.validate().unwrap_err(), errors[0]);
+
+        let io = std::io::Error::from(errors[0].clone());
+        assert_eq!(io.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_is_valid() {
+        let mut persons = vec![
+            Person::NaturalPerson(NaturalPerson::mock()),
+            Person::LegalPerson(LegalPerson::mock()),
+        ];
+        let mut legal = LegalPerson::mock();
+        legal.customer_identification = Some("customer-b".try_into().unwrap());
+        persons.push(Person::LegalPerson(legal));
+
+        persons.retain(Validatable::is_valid);
+        assert_eq!(persons.len(), 2);
+        assert!(persons.iter().all(|p| p.is_valid()));
+    }
+
+    #[test]
+    fn test_person_visitor() {
+        #[derive(Default)]
+        struct Counter {
+            natural: usize,
+            legal: usize,
+            addresses: usize,
+            identifications: usize,
+        }
+
+        impl PersonVisitor for Counter {
+            fn visit_natural(&mut self, _person: &NaturalPerson) {
+                self.natural += 1;
+            }
+            fn visit_legal(&mut self, _person: &LegalPerson) {
+                self.legal += 1;
+            }
+            fn visit_address(&mut self, _address: &Address) {
+                self.addresses += 1;
+            }
+            fn visit_national_identification(&mut self, _ni: &NationalIdentification) {
+                self.identifications += 1;
+            }
+        }
+
+        let mut ivms = IVMS101::mock();
+        ivms.beneficiary.as_mut().unwrap().beneficiary_persons = vec![
+            Person::LegalPerson(LegalPerson::mock()),
+            Person::NaturalPerson(NaturalPerson::mock()),
+        ]
+        .try_into()
+        .map(OneToN::N)
+        .unwrap();
+        let mut counter = Counter::default();
+        ivms.accept(&mut counter);
+        assert_eq!(counter.natural, 2);
+        assert_eq!(counter.legal, 3);
+        assert_eq!(counter.addresses, 1);
+        assert_eq!(counter.identifications, 1);
+    }
+
+    #[test]
+    fn test_accessors() {
+        let ivms = IVMS101::mock();
+        let originator = ivms.originator().unwrap();
+        assert_eq!(originator.persons(), &originator.originator_persons);
+        assert!(originator.account_numbers().eq(["account-1"]));
+        let beneficiary = ivms.beneficiary().unwrap();
+        assert_eq!(beneficiary.persons().first().last_name(), "Company A");
+        assert!(beneficiary.account_numbers().eq(["account-2"]));
+        assert_eq!(
+            ivms.originating_vasp().unwrap().person().last_name(),
+            "VASP A"
         );
-        assert_tokens(
-            &AddressTypeCode::Business,
-            &[Token::UnitVariant {
-                name: "AddressTypeCode",
-                variant: "BIZZ",
-            }],
+        let vasp = ivms.beneficiary_vasp().unwrap().person().unwrap();
+        assert_eq!(vasp.last_name(), "VASP B");
+
+        let address = Address::mock();
+        assert_eq!(address.town_name(), "Zurich");
+        assert_eq!(address.country(), &"CH");
+
+        let mut natural = NaturalPerson::mock();
+        natural.national_identification = Some(NationalIdentification::mock());
+        natural.date_and_place_of_birth = Some(DateAndPlaceOfBirth::mock());
+        natural.country_of_residence = Some("CH".try_into().unwrap());
+        assert_eq!(
+            natural.national_identification(),
+            Some(&NationalIdentification::mock())
         );
-        assert_tokens(
-            &NationalIdentifierTypeCode::AlienRegistrationNumber,
-            &[Token::UnitVariant {
-                name: "NationalIdentifierTypeCode",
-                variant: "ARNU",
-            }],
+        assert_eq!(
+            natural.date_and_place_of_birth(),
+            Some(&DateAndPlaceOfBirth::mock())
         );
+        assert_eq!(natural.country_of_residence().unwrap(), &"CH");
+        assert_eq!(LegalPerson::mock().national_identification(), None);
     }
 
-    fn match_validation_error(val: &impl Validatable, code: u8) {
-        let res = val.validate();
-        assert!(res
-            .unwrap_err()
-            .to_string()
-            .ends_with(format!("(IVMS101 C{code})").as_str()));
+    #[test]
+    fn test_person_and_address_count() {
+        let mut ivms = IVMS101::mock();
+        assert_eq!(ivms.person_count(), 4);
+        assert_eq!(ivms.address_count(), 1);
+
+        ivms.originator.as_mut().unwrap().originator_persons = vec![
+            Person::NaturalPerson(NaturalPerson::mock()),
+            Person::LegalPerson(LegalPerson::mock()),
+        ]
+        .try_into()
+        .map(OneToN::N)
+        .unwrap();
+        let mut vasp = LegalPerson::mock();
+        vasp.geographic_address = Some(Address::mock()).into();
+        ivms.set_beneficiary_vasp(vasp.into());
+        assert_eq!(ivms.person_count(), 5);
+        assert_eq!(ivms.address_count(), 1);
+
+        assert_eq!(IVMS101::from_json("{}").unwrap().person_count(), 0);
     }
 
     #[test]
-    fn test_person_serialization() {
-        let person = Person::NaturalPerson(NaturalPerson::mock());
-        let serialized = serde_json::to_string(&person).unwrap();
+    fn test_persons_by_role() {
+        let mut ivms = IVMS101::mock();
+        let roles: Vec<_> = ivms.persons_with_role().map(|(role, _)| role).collect();
         assert_eq!(
-            serialized,
-            r#"{"naturalPerson":{"name":{"nameIdentifier":{"primaryIdentifier":"Engels","secondaryIdentifier":"Friedrich","nameIdentifierType":"LEGL"}}}}"#
+            roles,
+            [
+                PartyRole::Originator,
+                PartyRole::Beneficiary,
+                PartyRole::OriginatingVASP,
+                PartyRole::BeneficiaryVASP,
+            ]
         );
-        let deserialized: Person = serde_json::from_str(&serialized).unwrap();
-        assert_eq!(person, deserialized);
+        assert!(ivms.has_natural_person(false));
+        assert!(ivms.has_legal_person(false));
+        assert!(!ivms.originator_is_corporate());
+        assert!(ivms.beneficiary_is_corporate());
 
-        let person = Person::LegalPerson(LegalPerson::mock());
-        let serialized = serde_json::to_string(&person).unwrap();
+        // Without the legal person beneficiary, only the VASPs are legal
+        // persons.
+        ivms.beneficiary.as_mut().unwrap().beneficiary_persons =
+            Person::NaturalPerson(NaturalPerson::mock()).into();
+        assert!(!ivms.has_legal_person(false));
+        assert!(ivms.has_legal_person(true));
+        assert!(!ivms.beneficiary_is_corporate());
+
+        ivms.originator.as_mut().unwrap().originator_persons = vec![
+            Person::NaturalPerson(NaturalPerson::mock()),
+            Person::LegalPerson(LegalPerson::mock()),
+        ]
+        .try_into()
+        .map(OneToN::N)
+        .unwrap();
+        assert!(ivms.originator_is_corporate());
         assert_eq!(
-            serialized,
-            r#"{"legalPerson":{"name":{"nameIdentifier":{"legalPersonName":"Company A","legalPersonNameIdentifierType":"LEGL"}}}}"#
+            ivms.person_count_by_role().into_iter().collect::<Vec<_>>(),
+            [
+                ((PartyRole::Originator, PersonKind::Natural), 1),
+                ((PartyRole::Originator, PersonKind::Legal), 1),
+                ((PartyRole::Beneficiary, PersonKind::Natural), 1),
+                ((PartyRole::OriginatingVASP, PersonKind::Legal), 1),
+                ((PartyRole::BeneficiaryVASP, PersonKind::Legal), 1),
+            ]
         );
-        let deserialized: Person = serde_json::from_str(&serialized).unwrap();
-        assert_eq!(person, deserialized);
+
+        let empty = IVMS101::from_json("{}").unwrap();
+        assert!(!empty.has_natural_person(true));
+        assert!(!empty.has_legal_person(true));
+        assert!(empty.person_count_by_role().is_empty());
     }
 
     #[test]
@@ -1158,6 +4875,19 @@ mod tests {
         date.validate().unwrap();
     }
 
+    #[test]
+    fn test_c2_natural_person_validation_error() {
+        let mut person = NaturalPerson::mock();
+        person.date_and_place_of_birth = Some(DateAndPlaceOfBirth {
+            date_of_birth: chrono::NaiveDate::MAX,
+            place_of_birth: "Bern".try_into().unwrap(),
+        });
+        match_validation_error(&person, 2);
+
+        person.date_and_place_of_birth = Some(DateAndPlaceOfBirth::mock());
+        person.validate().unwrap();
+    }
+
     // C3 is tested in test_invalid_country_code
 
     #[test]
@@ -1182,6 +4912,360 @@ mod tests {
         legal.validate().unwrap();
     }
 
+    #[test]
+    fn test_beneficiary_vasp_status() {
+        let mut ivms = IVMS101::mock();
+        let vasp = Person::from(LegalPerson::mock());
+        ivms.set_beneficiary_vasp(vasp.clone());
+        assert_eq!(ivms.beneficiary_vasp_status(), VaspStatus::Known(&vasp));
+        let json = serde_json::to_value(&ivms).unwrap();
+        assert!(json["beneficiaryVASP"]["beneficiaryVASP"].is_object());
+
+        ivms.set_beneficiary_vasp_unknown();
+        assert_eq!(ivms.beneficiary_vasp_status(), VaspStatus::Unknown);
+        let json = serde_json::to_value(&ivms).unwrap();
+        assert_eq!(json["beneficiaryVASP"], serde_json::json!({}));
+        let parsed: IVMS101 = serde_json::from_str(&json.to_string()).unwrap();
+        assert_eq!(parsed.beneficiary_vasp_status(), VaspStatus::Unknown);
+
+        ivms.beneficiary_vasp = None;
+        assert_eq!(ivms.beneficiary_vasp_status(), VaspStatus::Absent);
+        let json = serde_json::to_value(&ivms).unwrap();
+        assert!(json.get("beneficiaryVASP").is_none());
+    }
+
+    #[test]
+    fn test_check_registration_consistency() {
+        let resolver = |lei: &lei::LEI| {
+            (lei.to_string() == "2594007XIACKNMUAW223").then(|| "DE".try_into().unwrap())
+        };
+        let mut legal = LegalPerson::mock();
+        legal.check_registration_consistency(&resolver).unwrap();
+
+        let lei = lei::LEI::try_from("2594007XIACKNMUAW223").unwrap();
+        legal.national_identification = Some((&lei).into());
+        let err = legal.check_registration_consistency(&resolver).unwrap_err();
+        assert_eq!(err.path(), Some("countryOfRegistration"));
+
+        legal.set_country_of_registration("de").unwrap();
+        assert_eq!(legal.country_of_registration().unwrap(), &"DE");
+        legal.check_registration_consistency(&resolver).unwrap();
+
+        assert!(legal.set_country_of_registration("ZZ").is_err());
+        legal.set_country_of_registration("CH").unwrap();
+        let err = legal.check_registration_consistency(&resolver).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "countryOfRegistration: Validation error: Country of registration CH does not match the jurisdiction DE of the LEI 2594007XIACKNMUAW223"
+        );
+        legal.check_registration_consistency(&|_| None).unwrap();
+    }
+
+    #[test]
+    fn test_registration_authority_case() {
+        let mut id = NationalIdentification::mock();
+        id.set_registration_authority("ra000002").unwrap();
+        assert_eq!(
+            id.registration_authority.as_ref().unwrap().to_string(),
+            "RA000002"
+        );
+        assert!(id.set_registration_authority("RA00000A").is_err());
+
+        let json = r#"{"nationalIdentifier":"id","nationalIdentifierType":"MISC","registrationAuthority":"ra000001"}"#;
+        let parsed: NationalIdentification = serde_json::from_str(json).unwrap();
+        assert_eq!(parsed, NationalIdentification::mock());
+        let canonical = serde_json::to_string(&parsed).unwrap();
+        assert_eq!(canonical, json.replace("ra000001", "RA000001"));
+        let reparsed: NationalIdentification = serde_json::from_str(&canonical).unwrap();
+        assert_eq!(serde_json::to_string(&reparsed).unwrap(), canonical);
+
+        let err =
+            serde_json::from_str::<NationalIdentification>(&json.replace("ra000001", "RA00001"))
+                .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("must consist of RA and six digits"));
+    }
+
+    #[test]
+    fn test_ra_jurisdiction_matches() {
+        let mut legal = LegalPerson::mock();
+        assert_eq!(legal.ra_jurisdiction_matches(), None);
+
+        let mut id = NationalIdentification::mock();
+        id.registration_authority = Some("RA000585".try_into().unwrap());
+        legal.national_identification = Some(id);
+        assert_eq!(legal.ra_jurisdiction_matches(), None);
+
+        legal.country_of_registration = Some("GB".try_into().unwrap());
+        assert_eq!(legal.ra_jurisdiction_matches(), Some(true));
+        assert!(legal.warnings().is_empty());
+
+        legal.country_of_registration = Some("DE".try_into().unwrap());
+        assert_eq!(legal.ra_jurisdiction_matches(), Some(false));
+        legal.validate().unwrap();
+        assert_eq!(
+            legal.warnings(),
+            vec![Warning::RegistrationAuthorityJurisdictionMismatch]
+        );
+
+        // The registration authority of the mock maintains the register of
+        // Afghanistan.
+        legal.national_identification = Some(NationalIdentification::mock());
+        assert_eq!(legal.ra_jurisdiction_matches(), Some(false));
+        legal.country_of_registration = Some("AF".try_into().unwrap());
+        assert_eq!(legal.ra_jurisdiction_matches(), Some(true));
+
+        let mut id = NationalIdentification::mock();
+        id.registration_authority = None;
+        legal.national_identification = Some(id);
+        assert_eq!(legal.ra_jurisdiction_matches(), None);
+    }
+
+    #[test]
+    fn test_legal_person_address_type_warning() {
+        let mut legal = LegalPerson::mock();
+        assert!(legal.warnings().is_empty());
+
+        legal.geographic_address = Some(Address::mock()).into();
+        legal.validate().unwrap();
+        assert_eq!(
+            legal.warnings(),
+            vec![Warning::LegalPersonResidentialAddressOnly]
+        );
+
+        let mut business = Address::mock();
+        business.address_type = AddressTypeCode::Business;
+        legal.geographic_address = vec![Address::mock(), business.clone()].into();
+        assert!(legal.warnings().is_empty());
+
+        business.address_type = AddressTypeCode::Geographic;
+        legal.geographic_address = Some(business).into();
+        legal.validate().unwrap();
+        assert!(legal.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_ensure_c1() {
+        let mut person = NaturalPerson::mock();
+        let originator = Originator::new(Person::NaturalPerson(person.clone())).unwrap();
+        match_validation_error(&originator, 1);
+
+        person.ensure_c1("CH").unwrap();
+        let originator = Originator::new(Person::NaturalPerson(person.clone())).unwrap();
+        originator.validate().unwrap();
+        assert!(originator
+            .validate_with(&ValidationOptions::strict())
+            .is_err());
+        assert_eq!(person.geographic_address.first().unwrap().country, "CH");
+
+        let mut person = NaturalPerson::mock();
+        person.customer_identification = Some("customer-a".try_into().unwrap());
+        let unchanged = person.clone();
+        person.ensure_c1("CH").unwrap();
+        assert_eq!(person, unchanged);
+
+        assert!(NaturalPerson::mock().ensure_c1("ZZ").is_err());
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut person = NaturalPerson::mock();
+        person.customer_identification = Some("customer-a".try_into().unwrap());
+        let mut other = NaturalPerson::mock();
+        other.name = NaturalPersonName {
+            name_identifier: NaturalPersonNameID::alias("Fred", None).unwrap().into(),
+            local_name_identifier: ZeroToN::None,
+            phonetic_name_identifier: Some(NaturalPersonNameID::mock()).into(),
+        }
+        .into();
+        other.geographic_address = Some(Address::mock()).into();
+        other.customer_identification = Some("customer-b".try_into().unwrap());
+        other.country_of_residence = Some("DE".try_into().unwrap());
+
+        person.merge(&other);
+        let name = person.name.as_single().unwrap();
+        assert_eq!(
+            name.name_identifier.to_vec(),
+            [
+                NaturalPersonNameID::mock(),
+                NaturalPersonNameID::alias("Fred", None).unwrap()
+            ]
+        );
+        assert_eq!(
+            name.phonetic_name_identifier,
+            ZeroToN::One(NaturalPersonNameID::mock())
+        );
+        assert_eq!(person.geographic_address, ZeroToN::One(Address::mock()));
+        assert_eq!(
+            person.customer_identification.unwrap().as_str(),
+            "customer-a"
+        );
+        assert_eq!(person.country_of_residence.unwrap(), "DE");
+
+        // Merging the same record again changes nothing.
+        let mut merged = NaturalPerson::mock();
+        merged.merge(&NaturalPerson::mock());
+        assert_eq!(merged, NaturalPerson::mock());
+    }
+
+    #[test]
+    fn test_minimize() {
+        let c1_data = |person: &NaturalPerson| {
+            [
+                person.national_identification.is_some(),
+                person.date_and_place_of_birth.is_some(),
+                !person.geographic_address.is_empty(),
+                person.customer_identification.is_some(),
+            ]
+        };
+
+        let mut person = NaturalPerson::mock();
+        person.national_identification = Some(NationalIdentification::mock());
+        person.date_and_place_of_birth = Some(DateAndPlaceOfBirth::mock());
+        person.geographic_address = Some(Address::mock()).into();
+        person.customer_identification = Some("customer-a".try_into().unwrap());
+        person.country_of_residence = Some("CH".try_into().unwrap());
+
+        let mut minimized = person.clone();
+        minimized.minimize();
+        assert_eq!(c1_data(&minimized), [true, false, false, false]);
+        assert_eq!(minimized.country_of_residence, person.country_of_residence);
+        Originator::new(Person::NaturalPerson(minimized))
+            .unwrap()
+            .validate()
+            .unwrap();
+
+        person.national_identification = None;
+        let mut minimized = person.clone();
+        minimized.minimize();
+        assert_eq!(c1_data(&minimized), [false, true, false, false]);
+
+        person.date_and_place_of_birth = None;
+        let mut minimized = person.clone();
+        minimized.minimize();
+        assert_eq!(c1_data(&minimized), [false, false, true, false]);
+        Originator::new(Person::NaturalPerson(minimized))
+            .unwrap()
+            .validate()
+            .unwrap();
+
+        person.geographic_address = ZeroToN::None;
+        let mut minimized = person.clone();
+        minimized.minimize();
+        assert_eq!(minimized, person);
+
+        let mut person = NaturalPerson::mock();
+        person.minimize();
+        assert_eq!(person, NaturalPerson::mock());
+    }
+
+    #[test]
+    fn test_combine() {
+        let complete = IVMS101::mock();
+        let mut originator = IVMS101::from_json("{}").unwrap();
+        originator.originator = complete.originator.clone();
+        originator.beneficiary = complete.beneficiary.clone();
+        let mut vasps = IVMS101::from_json("{}").unwrap();
+        vasps.originating_vasp = complete.originating_vasp.clone();
+        vasps.beneficiary_vasp = complete.beneficiary_vasp.clone();
+
+        let combined = IVMS101::combine([originator.clone(), vasps.clone()]);
+        assert_eq!(combined, complete);
+        combined.validate().unwrap();
+        let collected: IVMS101 = [vasps, originator].into_iter().collect();
+        assert_eq!(collected, complete);
+
+        // The last writer wins.
+        let mut other = IVMS101::from_json("{}").unwrap();
+        other.beneficiary_vasp = Some(Box::new(BeneficiaryVASP {
+            beneficiary_vasp: None,
+        }));
+        let combined = IVMS101::combine([complete.clone(), other.clone()]);
+        assert_eq!(combined.beneficiary_vasp, other.beneficiary_vasp);
+        assert_eq!(combined.originator, complete.originator);
+        assert_eq!(IVMS101::combine([]), IVMS101::from_json("{}").unwrap());
+    }
+
+    #[test]
+    fn test_set_addresses() {
+        let mut person = NaturalPerson::mock();
+        person.set_addresses(vec![Address::mock()]).unwrap();
+        assert_eq!(person.geographic_address, ZeroToN::One(Address::mock()));
+        let err = person.set_addresses(Vec::new()).unwrap_err();
+        assert_eq!(err.constraint(), Some("C1"));
+        assert_eq!(person.clear_addresses().unwrap_err(), err);
+        assert_eq!(person.geographic_address, ZeroToN::One(Address::mock()));
+        person.customer_identification = Some("customer-a".try_into().unwrap());
+        person.clear_addresses().unwrap();
+        assert!(person.geographic_address.is_empty());
+
+        let mut person = LegalPerson::mock();
+        let mut home = Address::mock();
+        home.address_type = AddressTypeCode::Residential;
+        person.set_addresses(vec![home.clone(), home]).unwrap();
+        assert_eq!(person.geographic_address.iter().len(), 2);
+        assert_eq!(
+            person.warnings(),
+            [Warning::LegalPersonResidentialAddressOnly]
+        );
+        let err = person.clear_addresses().unwrap_err();
+        assert_eq!(err.constraint(), Some("C4"));
+        assert_eq!(person.geographic_address.iter().len(), 2);
+        person.national_identification = Some(NationalIdentification::mock());
+        person.set_addresses(Vec::new()).unwrap();
+        assert_eq!(person.geographic_address, ZeroToN::None);
+    }
+
+    #[test]
+    fn test_check() {
+        let report = IVMS101::mock().check();
+        assert!(report.is_valid());
+        assert_eq!(report.warnings().count(), 0);
+
+        let mut ivms = IVMS101::mock();
+        let mut natural = NaturalPerson::mock();
+        natural.geographic_address = Some(Address::mock()).into();
+        natural.country_of_residence = Some("DE".try_into().unwrap());
+        ivms.originator.as_mut().unwrap().originator_persons =
+            Person::NaturalPerson(natural).into();
+        let mut legal = LegalPerson::mock();
+        legal.name.name_identifier = LegalPersonNameID {
+            legal_person_name: "株式会社".try_into().unwrap(),
+            legal_person_name_identifier_type: LegalPersonNameTypeCode::Legal,
+        }
+        .into();
+        legal.geographic_address = Some(Address::mock()).into();
+        ivms.beneficiary.as_mut().unwrap().beneficiary_persons = Person::LegalPerson(legal).into();
+
+        let report = ivms.check();
+        assert!(report.is_valid());
+        assert_eq!(
+            report.warnings().collect::<Vec<_>>(),
+            [
+                (
+                    "originator.originatorPersons[0]",
+                    &Warning::AddressCountryMismatch
+                ),
+                (
+                    "beneficiary.beneficiaryPersons[0]",
+                    &Warning::LegalPersonResidentialAddressOnly
+                ),
+                (
+                    "beneficiary.beneficiaryPersons[0]",
+                    &Warning::NonLatinNameWithoutPhoneticName
+                ),
+            ]
+        );
+
+        ivms.originator = None;
+        ivms.beneficiary.as_mut().unwrap().beneficiary_persons =
+            Person::LegalPerson(LegalPerson::mock()).into();
+        let report = ivms.check();
+        assert!(!report.is_valid());
+        assert_eq!(report.errors()[0].constraint(), Some("C4"));
+    }
+
     #[test]
     fn test_c5_validation_error() {
         let mut legal = LegalPersonName::mock();
@@ -1290,6 +5374,203 @@ mod tests {
         addr.validate().unwrap();
     }
 
+    #[test]
+    fn test_c8_post_box_only() {
+        let mut addr = Address::mock();
+        addr.address_line = None.into();
+        addr.post_box = Some("PO Box 1234".try_into().unwrap());
+        match_validation_error(&addr, 8);
+
+        let lenient = ValidationOptions {
+            allow_post_box_only: true,
+            ..Default::default()
+        };
+        addr.validate_with(&lenient).unwrap();
+
+        addr.post_box = None;
+        assert!(addr.validate_with(&lenient).is_err());
+    }
+
+    #[test]
+    fn test_post_box_only_legal_person() {
+        let json = r#"{"legalPerson":{"name":{"nameIdentifier":{"legalPersonName":"Company A","legalPersonNameIdentifierType":"LEGL"}},"geographicAddress":{"addressType":"BIZZ","postBox":"PO Box 1234","townName":"Zurich","country":"CH"}}}"#;
+        let person: Person = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&person).unwrap(), json);
+
+        let err = person.validate().unwrap_err();
+        assert_eq!(err.constraint(), Some("C8"));
+        let lenient = ValidationOptions {
+            allow_post_box_only: true,
+            ..Default::default()
+        };
+        person.validate_with(&lenient).unwrap();
+    }
+
+    #[test]
+    fn test_address_field_too_long() {
+        let number = "1".repeat(17);
+        let err = Address::new(
+            Some("Main street"),
+            Some(&number),
+            None,
+            "8000",
+            "Zurich",
+            "CH",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            Error::ValidationError("Building number exceeds 16 bytes".into())
+        );
+        assert!(Address::new(
+            Some("Main street"),
+            Some(&number[1..]),
+            None,
+            "8000",
+            "Zurich",
+            "CH"
+        )
+        .is_ok());
+
+        let err =
+            Address::new(None, None, Some("Main street"), &number, "Zurich", "CH").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Validation error: Post code exceeds 16 bytes"
+        );
+        let line = format!("Main street, {number} Zurich");
+        let err = Address::from_unstructured(&line, "CH".try_into().unwrap()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Validation error: Post code exceeds 16 bytes"
+        );
+    }
+
+    #[test]
+    fn test_reject_mixed_address() {
+        let mut addr = Address::mock();
+        addr.street_name = Some("Main street".try_into().unwrap());
+        addr.building_number = Some("12".try_into().unwrap());
+        addr.validate().unwrap();
+
+        let no_mixing = ValidationOptions {
+            reject_mixed_address: true,
+            ..Default::default()
+        };
+        let err = addr.validate_with(&no_mixing).unwrap_err();
+        assert_eq!(err, addr.validate_no_mixing().unwrap_err());
+        assert_eq!(err.path(), Some("addressLine"));
+
+        addr.address_line = None.into();
+        addr.validate_with(&no_mixing).unwrap();
+        Address::mock().validate_with(&no_mixing).unwrap();
+    }
+
+    #[test]
+    fn test_address_unstructured() {
+        let mut address = Address::mock();
+        address.street_name = Some("Main street".try_into().unwrap());
+        address.building_number = Some("12".try_into().unwrap());
+        address.address_line = Some("Building B".try_into().unwrap()).into();
+        address.post_code = Some("8000".try_into().unwrap());
+        let (line, country) = address.to_unstructured(140);
+        assert_eq!(line, "Main street 12, Building B, 8000 Zurich");
+        assert_eq!(country, "CH");
+
+        let parsed = Address::from_unstructured(&line, country).unwrap();
+        parsed.validate().unwrap();
+        assert_eq!(
+            parsed.address_line,
+            ZeroToN::N(vec![
+                "Main street 12".try_into().unwrap(),
+                "Building B".try_into().unwrap()
+            ])
+        );
+        assert_eq!(parsed.post_code, address.post_code);
+        assert_eq!(parsed.town_name, address.town_name);
+        assert_eq!(parsed.to_unstructured(140).0, line);
+
+        let parsed = Address::from_unstructured("Zurich", "CH".try_into().unwrap()).unwrap();
+        assert_eq!(parsed.post_code, None);
+        assert!(parsed.address_line.is_empty());
+    }
+
+    #[test]
+    fn test_address_unstructured_truncation() {
+        let mut address = Address::mock();
+        address.address_line = vec![
+            "Überlandstrasse 1234567890 1234567890 1234567890 1234567890"
+                .try_into()
+                .unwrap(),
+            "Gebäude Süd 1234567890 1234567890 1234567890 1234567890 12345"
+                .try_into()
+                .unwrap(),
+            "Stockwerk Ö 1234567890 1234567890 1234567890 1234567890 12345"
+                .try_into()
+                .unwrap(),
+        ]
+        .into();
+        let (line, _) = address.to_unstructured(140);
+        assert_eq!(line.chars().count(), 140);
+        assert!(line.starts_with("Überlandstrasse"));
+        assert_eq!(address.to_unstructured(1).0, "Ü");
+        assert_eq!(address.to_unstructured(0).0, "");
+    }
+
+    #[test]
+    fn test_placeholder_values() {
+        let mut person = NaturalPerson::mock();
+        let mut name = NaturalPersonNameID::mock();
+        name.primary_identifier = "unknown".try_into().unwrap();
+        person.name = NaturalPersonName {
+            name_identifier: name.into(),
+            local_name_identifier: None.into(),
+            phonetic_name_identifier: None.into(),
+        }
+        .into();
+        person.validate().unwrap();
+        assert_eq!(
+            person.validate_with(&ValidationOptions::strict()),
+            Err(Error::ValidationError("Placeholder value 'unknown'".into())
+                .at("name[0].nameIdentifier[0].primaryIdentifier"))
+        );
+
+        let mut ivms = IVMS101::mock();
+        ivms.originator_person_mut().name = person.name;
+        let path = "originator.originatorPersons[0].name[0].nameIdentifier[0].primaryIdentifier";
+        let options = ValidationOptions::strict();
+        assert_eq!(ivms.validate_with(&options).unwrap_err().path(), Some(path));
+        assert_eq!(
+            ivms.validate_all_with(&options).unwrap_err()[0].path(),
+            Some(path)
+        );
+
+        let mut address = Address::mock();
+        address.town_name = "Å".try_into().unwrap();
+        address.country = "NO".try_into().unwrap();
+        address.validate_with(&ValidationOptions::strict()).unwrap();
+
+        address.address_line =
+            vec!["Main street".try_into().unwrap(), "N/A".try_into().unwrap()].into();
+        assert_eq!(
+            address
+                .validate_with(&ValidationOptions::strict())
+                .unwrap_err()
+                .to_string(),
+            "addressLine[1]: Validation error: Placeholder value 'N/A'"
+        );
+
+        let mut legal = LegalPerson::mock();
+        legal.customer_identification = Some("id".try_into().unwrap());
+        let mut options = ValidationOptions::strict();
+        legal.validate_with(&options).unwrap();
+        options.placeholders.push("Company A".into());
+        assert_eq!(
+            legal.validate_with(&options).unwrap_err().path(),
+            Some("name.nameIdentifier[0].legalPersonName")
+        );
+    }
+
     #[test]
     fn test_c9_validation_error() {
         let mut ni = NationalIdentification::mock();
@@ -1352,6 +5633,63 @@ mod tests {
         person.validate().unwrap();
     }
 
+    #[test]
+    fn test_national_identification_lei() {
+        let lei = lei::LEI::try_from("2594007XIACKNMUAW223").unwrap();
+        let mut ni = NationalIdentification::mock();
+        assert!(ni.as_lei().is_none());
+
+        ni.set_lei(&lei);
+        assert_eq!(ni.as_lei().unwrap().unwrap(), lei);
+        assert_eq!(ni.registration_authority, None);
+        let mut person = LegalPerson::mock();
+        person.national_identification = Some(ni.clone());
+        assert_eq!(person.lei().unwrap(), Some(lei.clone()));
+        person.validate().unwrap();
+
+        // Bad checksum
+        ni.national_identifier = "2594007XIACKNMUAW224".try_into().unwrap();
+        assert!(matches!(ni.as_lei(), Some(Err(_))));
+        person.national_identification = Some(ni);
+        assert!(person.lei().is_err());
+        match_validation_error(&person, 11);
+
+        let vasp = OriginatingVASP::new("VASP A", &lei).unwrap();
+        assert_eq!(vasp.lei().unwrap(), Some(lei));
+    }
+
+    #[test]
+    fn test_originating_vasp_lei() {
+        let lei = lei::LEI::try_from("2594007XIACKNMUAW223").unwrap();
+        let mut vasp = OriginatingVASP::new("VASP A", &lei).unwrap();
+        vasp.validate().unwrap();
+
+        let mut invalid = vasp.clone();
+        let Person::LegalPerson(legal) = &mut invalid.originating_vasp else {
+            unreachable!()
+        };
+        let ni = legal.national_identification.as_mut().unwrap();
+        ni.national_identifier = "2594007XIACKNMUAW224".try_into().unwrap();
+        match_validation_error(&invalid, 11);
+
+        let Person::LegalPerson(legal) = &mut vasp.originating_vasp else {
+            unreachable!()
+        };
+        legal.national_identification = Some(NationalIdentification::mock());
+        assert_eq!(
+            vasp.validate(),
+            Err("Originating VASP must be identified by an LEI".into())
+        );
+
+        let mut legal = LegalPerson::mock();
+        legal.customer_identification = Some("id".try_into().unwrap());
+        vasp.originating_vasp = Person::LegalPerson(legal);
+        assert_eq!(
+            vasp.validate(),
+            Err("Originating VASP must be identified by an LEI".into())
+        );
+    }
+
     #[test]
     fn test_natural_person_name() {
         let mut person = NaturalPerson::mock();
@@ -1371,7 +5709,7 @@ mod tests {
 
     #[test]
     fn test_legal_person_name() {
-        assert_eq!(LegalPerson::mock().name(), "Company A");
+        assert_eq!(LegalPerson::mock().legal_name(), "Company A");
     }
 
     #[test]
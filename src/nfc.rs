@@ -0,0 +1,211 @@
+use serde::Deserialize;
+use serde_json::Value;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{Error, SerializeOptions, IVMS101};
+
+impl IVMS101 {
+    /// Normalizes all strings of the payload to Unicode Normalization
+    /// Form C, such that length limits apply to composed characters
+    /// regardless of how the producer encoded them.
+    ///
+    /// Returns the [`IVMS101::flatten`] paths of the values that changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if a normalized value exceeds its length
+    /// limit, which is possible for the few characters NFC expands, at
+    /// the path of that value. The payload is left unchanged in that case.
+    pub fn nfc_normalize(&mut self) -> Result<Vec<String>, Error> {
+        let before = self.flatten();
+        let mut value = serde_json::to_value(&*self).expect("IVMS101 serializes to JSON");
+        normalize(&mut value, String::new(), String::new(), &mut Vec::new());
+        let normalized = match IVMS101::deserialize(&value) {
+            Ok(normalized) => normalized,
+            Err(e) => {
+                // Attribute the error at the indexed paths of `flatten`.
+                let options = SerializeOptions {
+                    always_array: true,
+                    empty_as_array: false,
+                };
+                let mut value = options.to_value(&*self);
+                let mut changed = Vec::new();
+                normalize(&mut value, String::new(), String::new(), &mut changed);
+                return Err(attribute(
+                    Error::ParseError(e.to_string()),
+                    &value,
+                    &changed,
+                ));
+            }
+        };
+        *self = normalized;
+        Ok(self
+            .flatten()
+            .into_iter()
+            .filter(|(path, value)| before.get(path) != Some(value))
+            .map(|(path, _)| path)
+            .collect())
+    }
+
+    /// Parses a JSON payload like [`IVMS101::from_json`], normalizing all
+    /// strings to Unicode Normalization Form C beforehand. Thus, values
+    /// only fitting their length limit when composed are accepted.
+    ///
+    /// Returns the payload along with the paths of the values that
+    /// changed, as they appear in the JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::ParseError`] if the payload cannot be parsed,
+    /// at the path of the normalized value causing it, if any.
+    pub fn from_json_nfc(json: &str) -> Result<(Self, Vec<String>), Error> {
+        let mut value: Value =
+            serde_json::from_str(json).map_err(|e| Error::ParseError(e.to_string()))?;
+        let mut changed = Vec::new();
+        normalize(&mut value, String::new(), String::new(), &mut changed);
+        let ivms = IVMS101::deserialize(&value)
+            .map_err(|e| attribute(Error::ParseError(e.to_string()), &value, &changed))?;
+        Ok((ivms, changed.into_iter().map(|(path, _)| path).collect()))
+    }
+}
+
+/// Normalizes all strings of `value` to NFC, collecting the paths and JSON
+/// pointers of those that changed.
+fn normalize(
+    value: &mut Value,
+    path: String,
+    pointer: String,
+    changed: &mut Vec<(String, String)>,
+) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                let pointer = format!("{pointer}/{}", key.replace('~', "~0").replace('/', "~1"));
+                normalize(value, path, pointer, changed);
+            }
+        }
+        Value::Array(values) => {
+            for (i, value) in values.iter_mut().enumerate() {
+                normalize(
+                    value,
+                    format!("{path}[{i}]"),
+                    format!("{pointer}/{i}"),
+                    changed,
+                );
+            }
+        }
+        Value::String(s) => {
+            let nfc: String = s.nfc().collect();
+            if nfc != *s {
+                *s = nfc;
+                changed.push((path, pointer));
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Attributes `error` deserializing the normalized `value` to the changed
+/// value causing it, if any, i.e. the first one without which `value`
+/// deserializes.
+fn attribute(error: Error, value: &Value, changed: &[(String, String)]) -> Error {
+    let path = changed.iter().find_map(|(path, pointer)| {
+        let mut candidate = value.clone();
+        *candidate.pointer_mut(pointer)? = Value::String(String::new());
+        IVMS101::deserialize(&candidate).ok().map(|_| path)
+    });
+    match path {
+        Some(path) => error.at(path),
+        None => error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payload(last_name: &str) -> String {
+        format!(
+            r#"{{
+                "originator": {{
+                    "originatorPersons": {{
+                        "naturalPerson": {{
+                            "name": {{
+                                "nameIdentifier": {{
+                                    "primaryIdentifier": "{last_name}",
+                                    "nameIdentifierType": "LEGL"
+                                }}
+                            }},
+                            "customerIdentification": "customer-a"
+                        }}
+                    }}
+                }}
+            }}"#
+        )
+    }
+
+    #[test]
+    fn test_from_json_nfc() {
+        // Lengths are counted in bytes: 80 composed, 120 decomposed.
+        let decomposed = "e\u{301}".repeat(40);
+        let json = payload(&decomposed);
+        assert!(IVMS101::from_json(&json).is_err());
+
+        let (ivms, changed) = IVMS101::from_json_nfc(&json).unwrap();
+        assert_eq!(
+            changed,
+            ["originator.originatorPersons.naturalPerson.name.nameIdentifier.primaryIdentifier"]
+        );
+        let name = ivms
+            .originator()
+            .unwrap()
+            .originator_persons
+            .first()
+            .last_name();
+        assert_eq!(name, "\u{e9}".repeat(40));
+    }
+
+    #[test]
+    fn test_nfc_normalize() {
+        let mut ivms = IVMS101::from_json(&payload("Mu\u{308}ller")).unwrap();
+        let changed = ivms.nfc_normalize().unwrap();
+        assert_eq!(
+            changed,
+            ["originator.originatorPersons[0].naturalPerson.name[0].nameIdentifier[0].primaryIdentifier"]
+        );
+        assert_eq!(ivms, IVMS101::from_json(&payload("M\u{fc}ller")).unwrap());
+        assert!(ivms.nfc_normalize().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_nfc_expanding() {
+        // NFC decomposes U+0958, doubling the 90 bytes to 180.
+        let json = payload(&"\u{958}".repeat(30));
+        let mut ivms = IVMS101::from_json(&json).unwrap();
+        let error = ivms.nfc_normalize().unwrap_err();
+        assert_eq!(
+            error.path(),
+            Some("originator.originatorPersons[0].naturalPerson.name[0].nameIdentifier[0].primaryIdentifier")
+        );
+        assert_eq!(ivms, IVMS101::from_json(&json).unwrap());
+
+        let error = IVMS101::from_json_nfc(&json).unwrap_err();
+        assert_eq!(
+            error.path(),
+            Some(
+                "originator.originatorPersons.naturalPerson.name.nameIdentifier.primaryIdentifier"
+            )
+        );
+        assert_eq!(
+            IVMS101::from_json_nfc(r#"{"originator": 1}"#)
+                .unwrap_err()
+                .path(),
+            None
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use crate::CountryCode;
+
+/// Postcode patterns of major jurisdictions, keyed by ISO 3166-1 alpha-2
+/// country code. The patterns are anchored and expect uppercase letters.
+const POSTCODE_PATTERNS: [(&str, &str); 30] = [
+    ("AT", r"^\d{4}$"),
+    ("AU", r"^\d{4}$"),
+    ("BE", r"^\d{4}$"),
+    ("BR", r"^\d{5}-?\d{3}$"),
+    ("CA", r"^[A-Z]\d[A-Z] ?\d[A-Z]\d$"),
+    ("CH", r"^\d{4}$"),
+    ("CN", r"^\d{6}$"),
+    ("CZ", r"^\d{3} ?\d{2}$"),
+    ("DE", r"^\d{5}$"),
+    ("DK", r"^\d{4}$"),
+    ("ES", r"^\d{5}$"),
+    ("FI", r"^\d{5}$"),
+    ("FR", r"^\d{5}$"),
+    ("GB", r"^[A-Z]{1,2}\d[A-Z\d]? ?\d[A-Z]{2}$"),
+    ("IE", r"^[A-Z]\d[\dW] ?[A-Z\d]{4}$"),
+    ("IN", r"^\d{6}$"),
+    ("IT", r"^\d{5}$"),
+    ("JP", r"^\d{3}-?\d{4}$"),
+    ("KR", r"^\d{5}$"),
+    ("LI", r"^\d{4}$"),
+    ("LU", r"^(L-)?\d{4}$"),
+    ("NL", r"^\d{4} ?[A-Z]{2}$"),
+    ("NO", r"^\d{4}$"),
+    ("NZ", r"^\d{4}$"),
+    ("PL", r"^\d{2}-\d{3}$"),
+    ("PT", r"^\d{4}-\d{3}$"),
+    ("SE", r"^\d{3} ?\d{2}$"),
+    ("SG", r"^\d{6}$"),
+    ("US", r"^\d{5}(-\d{4})?$"),
+    ("ZA", r"^\d{4}$"),
+];
+
+impl CountryCode {
+    /// Returns the postcode pattern of the country as a regular
+    /// expression, e.g. for client-side hinting.
+    ///
+    /// Only major jurisdictions are covered. `None` means that no pattern
+    /// is known, not that the country has no postcodes, so postcodes
+    /// should not be rejected in that case.
+    ///
+    /// ```
+    /// use ivms101::CountryCode;
+    ///
+    /// let ch = CountryCode::try_from("CH").unwrap();
+    /// assert_eq!(ch.postcode_regex(), Some(r"^\d{4}$"));
+    /// ```
+    #[must_use]
+    pub fn postcode_regex(&self) -> Option<&'static str> {
+        POSTCODE_PATTERNS
+            .iter()
+            .find(|(code, _)| *code == self.as_str())
+            .map(|(_, pattern)| *pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn postcode_regex(code: &str) -> Option<regex::Regex> {
+        let code = CountryCode::try_from(code).unwrap();
+        code.postcode_regex()
+            .map(|pattern| regex::Regex::new(pattern).unwrap())
+    }
+
+    #[test]
+    fn test_postcode_regex() {
+        let ch = postcode_regex("CH").unwrap();
+        assert!(ch.is_match("8001"));
+        assert!(!ch.is_match("80010"));
+
+        let gb = postcode_regex("GB").unwrap();
+        for postcode in ["SW1A 1AA", "M1 1AE", "EC1A1BB", "B33 8TH"] {
+            assert!(gb.is_match(postcode), "{postcode}");
+        }
+        assert!(!gb.is_match("8001"));
+
+        assert!(postcode_regex("AQ").is_none());
+    }
+
+    #[test]
+    fn test_postcode_patterns_compile() {
+        for (code, pattern) in POSTCODE_PATTERNS {
+            assert!(crate::country(code).is_some(), "{code}");
+            regex::Regex::new(pattern).unwrap();
+        }
+    }
+}
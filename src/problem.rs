@@ -0,0 +1,275 @@
+//! Rendering of errors as problem details according to RFC 9457, e.g.
+//! for HTTP handlers returning validation failures to their callers.
+//!
+//! The body has the following shape:
+//!
+//! ```json
+//! {
+//!   "type": "about:blank",
+//!   "title": "Invalid IVMS101 payload",
+//!   "status": 422,
+//!   "violations": [
+//!     {
+//!       "constraint": "C1",
+//!       "message": "Validation error: Natural person: one of ...",
+//!       "path": "originator.originatorPersons[0]"
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! `constraint` and `path` are omitted where unknown. Malformed payloads
+//! are reported with status 400 and the title "Malformed IVMS101 payload",
+//! oversized payloads with status 413 and failures to read the payload
+//! with status 500.
+//!
+//! With the `axum` or `actix` feature, [`Error`] and [`ValidationFailures`]
+//! can be returned from handlers directly.
+
+use crate::{Error, ValidationFailures};
+
+/// A problem details body describing one or more errors.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct ProblemDetails {
+    /// The problem type, which is always `about:blank`.
+    #[serde(rename = "type")]
+    pub problem_type: &'static str,
+    /// A summary of the problem.
+    pub title: &'static str,
+    /// The HTTP status code.
+    pub status: u16,
+    /// The individual errors.
+    pub violations: Vec<Violation>,
+}
+
+/// An individual error of [`ProblemDetails`].
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct Violation {
+    /// The code of the violated IVMS101 constraint, e.g. `"C4"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constraint: Option<String>,
+    /// The error message.
+    pub message: String,
+    /// The field path the error is attributed to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+impl ProblemDetails {
+    fn new<'a>(errors: impl IntoIterator<Item = &'a Error>) -> Self {
+        let errors: Vec<&Error> = errors.into_iter().collect();
+        // The most severe error determines the status.
+        let status = errors
+            .iter()
+            .map(|e| match e.without_path() {
+                Error::Io(_) => 500,
+                Error::PayloadTooLarge { .. } => 413,
+                Error::ParseError(_) | Error::TooDeep(_) => 400,
+                _ => 422,
+            })
+            .max()
+            .unwrap_or(422);
+        let title = match status {
+            500 => "Failed to read IVMS101 payload",
+            413 => "IVMS101 payload too large",
+            400 => "Malformed IVMS101 payload",
+            _ => "Invalid IVMS101 payload",
+        };
+        let violations = errors.into_iter().map(Violation::from).collect();
+        Self {
+            problem_type: "about:blank",
+            title,
+            status,
+            violations,
+        }
+    }
+}
+
+impl From<&Error> for Violation {
+    fn from(error: &Error) -> Self {
+        Self {
+            constraint: error.constraint().map(str::to_owned),
+            message: error.without_path().to_string(),
+            path: error.path().map(str::to_owned),
+        }
+    }
+}
+
+impl From<&Error> for ProblemDetails {
+    fn from(error: &Error) -> Self {
+        Self::new([error])
+    }
+}
+
+impl From<&ValidationFailures> for ProblemDetails {
+    fn from(failures: &ValidationFailures) -> Self {
+        Self::new(failures.errors())
+    }
+}
+
+/// The media type of problem details.
+#[cfg(any(feature = "axum", feature = "actix"))]
+const CONTENT_TYPE: &str = "application/problem+json";
+
+#[cfg(feature = "axum")]
+mod axum_impls {
+    use axum::http::{header, StatusCode};
+    use axum::response::{IntoResponse, Response};
+
+    use super::{ProblemDetails, CONTENT_TYPE};
+    use crate::{Error, ValidationFailures};
+
+    impl IntoResponse for ProblemDetails {
+        fn into_response(self) -> Response {
+            let status =
+                StatusCode::from_u16(self.status).unwrap_or(StatusCode::UNPROCESSABLE_ENTITY);
+            let body = serde_json::to_string(&self).expect("problem details serialize to JSON");
+            (status, [(header::CONTENT_TYPE, CONTENT_TYPE)], body).into_response()
+        }
+    }
+
+    impl IntoResponse for Error {
+        fn into_response(self) -> Response {
+            ProblemDetails::from(&self).into_response()
+        }
+    }
+
+    impl IntoResponse for ValidationFailures {
+        fn into_response(self) -> Response {
+            ProblemDetails::from(&self).into_response()
+        }
+    }
+}
+
+#[cfg(feature = "actix")]
+mod actix_impls {
+    use actix_web::http::StatusCode;
+    use actix_web::{HttpResponse, ResponseError};
+
+    use super::{ProblemDetails, CONTENT_TYPE};
+    use crate::{Error, ValidationFailures};
+
+    fn respond(details: &ProblemDetails) -> HttpResponse {
+        HttpResponse::build(status(details))
+            .content_type(CONTENT_TYPE)
+            .body(serde_json::to_string(details).expect("problem details serialize to JSON"))
+    }
+
+    fn status(details: &ProblemDetails) -> StatusCode {
+        StatusCode::from_u16(details.status).unwrap_or(StatusCode::UNPROCESSABLE_ENTITY)
+    }
+
+    impl ResponseError for Error {
+        fn status_code(&self) -> StatusCode {
+            status(&ProblemDetails::from(self))
+        }
+
+        fn error_response(&self) -> HttpResponse {
+            respond(&ProblemDetails::from(self))
+        }
+    }
+
+    impl ResponseError for ValidationFailures {
+        fn status_code(&self) -> StatusCode {
+            status(&ProblemDetails::from(self))
+        }
+
+        fn error_response(&self) -> HttpResponse {
+            respond(&ProblemDetails::from(self))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Person, Validatable, IVMS101};
+
+    pub(super) fn failures() -> ValidationFailures {
+        let mut ivms = IVMS101::mock();
+        let originator = ivms.originator.as_mut().unwrap();
+        if let Some(Person::NaturalPerson(np)) = originator.originator_persons.iter().next() {
+            let mut np = np.clone();
+            np.geographic_address = None.into();
+            originator.originator_persons = Person::NaturalPerson(np).into();
+        }
+        ivms.validate_all().unwrap_err()
+    }
+
+    pub(super) const BODY: &str = r#"{"type":"about:blank","title":"Invalid IVMS101 payload","status":422,"violations":[{"constraint":"C1","message":"Validation error: Natural person: one of 1) geographic address 2) customer id 3) national id 4) date and place of birth is required (IVMS101 C1)","path":"originator.originatorPersons[0]"}]}"#;
+
+    #[test]
+    fn test_problem_details() {
+        let details = ProblemDetails::from(&failures());
+        assert_eq!(serde_json::to_string(&details).unwrap(), BODY);
+
+        let details = ProblemDetails::from(&Error::ParseError("expected value".into()));
+        assert_eq!(
+            serde_json::to_string(&details).unwrap(),
+            r#"{"type":"about:blank","title":"Malformed IVMS101 payload","status":400,"violations":[{"message":"Parse error: expected value"}]}"#
+        );
+
+        let details = ProblemDetails::from(&Error::PayloadTooLarge { size: 3, max: 2 });
+        assert_eq!(
+            serde_json::to_string(&details).unwrap(),
+            r#"{"type":"about:blank","title":"IVMS101 payload too large","status":413,"violations":[{"message":"Payload of 3 bytes exceeds the limit of 2 bytes"}]}"#
+        );
+
+        let details = ProblemDetails::from(&Error::Io("connection reset".into()));
+        assert_eq!(
+            serde_json::to_string(&details).unwrap(),
+            r#"{"type":"about:blank","title":"Failed to read IVMS101 payload","status":500,"violations":[{"message":"I/O error: connection reset"}]}"#
+        );
+    }
+
+    /// Polls a future that is ready without waiting, such as reading a
+    /// response body held in memory.
+    #[cfg(any(feature = "axum", feature = "actix"))]
+    fn now_or_never<F: std::future::Future>(future: F) -> F::Output {
+        use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+        fn raw_waker() -> RawWaker {
+            fn clone(_: *const ()) -> RawWaker {
+                raw_waker()
+            }
+            fn noop(_: *const ()) {}
+            static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+            RawWaker::new(std::ptr::null(), &VTABLE)
+        }
+
+        // SAFETY: The vtable functions do nothing and thus uphold the
+        // `RawWaker` contract.
+        let waker = unsafe { Waker::from_raw(raw_waker()) };
+        let mut future = std::pin::pin!(future);
+        match future.as_mut().poll(&mut Context::from_waker(&waker)) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("future is not ready"),
+        }
+    }
+
+    #[cfg(feature = "axum")]
+    #[test]
+    fn test_axum_response() {
+        use axum::response::IntoResponse;
+
+        let response = failures().into_response();
+        assert_eq!(response.status(), 422);
+        assert_eq!(
+            response.headers()[axum::http::header::CONTENT_TYPE],
+            CONTENT_TYPE
+        );
+        let body = now_or_never(axum::body::to_bytes(response.into_body(), usize::MAX)).unwrap();
+        assert_eq!(body, BODY.as_bytes());
+    }
+
+    #[cfg(feature = "actix")]
+    #[test]
+    fn test_actix_response() {
+        use actix_web::ResponseError;
+
+        let response = failures().error_response();
+        assert_eq!(response.status(), 422);
+        let body = now_or_never(actix_web::body::to_bytes(response.into_body())).unwrap();
+        assert_eq!(body, BODY.as_bytes());
+    }
+}
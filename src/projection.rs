@@ -0,0 +1,389 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{Error, Person, Validatable, ZeroToN, IVMS101};
+
+/// The persons of a payload, as they appear in field paths.
+const PERSONS: [&str; 2] = ["naturalPerson", "legalPerson"];
+
+/// The fields of persons besides their names and addresses.
+const PERSON_FIELDS: [&str; 5] = [
+    "nationalIdentification",
+    "customerIdentification",
+    "dateAndPlaceOfBirth",
+    "countryOfResidence",
+    "countryOfRegistration",
+];
+
+/// The name identifier fields of a person name.
+const NAME_FIELDS: [&str; 3] = [
+    "nameIdentifier",
+    "localNameIdentifier",
+    "phoneticNameIdentifier",
+];
+
+/// The fields of an address.
+const ADDRESS_FIELDS: [&str; 16] = [
+    "addressType",
+    "department",
+    "subDepartment",
+    "streetName",
+    "buildingNumber",
+    "buildingName",
+    "floor",
+    "postBox",
+    "room",
+    "postCode",
+    "townName",
+    "townLocationName",
+    "districtName",
+    "countrySubDivision",
+    "addressLine",
+    "country",
+];
+
+/// The address fields beyond those needed to locate an address.
+const ADDRESS_DETAILS: [&str; 8] = [
+    "department",
+    "subDepartment",
+    "buildingName",
+    "floor",
+    "room",
+    "townLocationName",
+    "districtName",
+    "countrySubDivision",
+];
+
+/// The fields a counterparty accepts, for use with [`IVMS101::project`].
+///
+/// Fields are given as paths without array indices, such as
+/// `originator.originatorPersons.naturalPerson.name.nameIdentifier`.
+/// A path allows the field and everything below it, and a `*` segment
+/// matches any single field.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FieldProfile {
+    paths: Vec<Vec<String>>,
+}
+
+impl FieldProfile {
+    /// Creates a profile allowing the given paths.
+    pub fn new<P: AsRef<str>>(paths: impl IntoIterator<Item = P>) -> Self {
+        paths.into_iter().fold(Self::default(), |profile, path| {
+            profile.allow(path.as_ref())
+        })
+    }
+
+    /// Allows an additional path.
+    #[must_use]
+    pub fn allow(mut self, path: &str) -> Self {
+        self.paths
+            .push(path.split('.').map(str::to_owned).collect());
+        self
+    }
+
+    /// A profile allowing all fields.
+    pub fn full() -> Self {
+        Self::new(["*"])
+    }
+
+    /// A profile allowing only the fields needed to identify the persons
+    /// of a payload: primary names, account numbers, identifications,
+    /// dates of birth, countries and the basic address fields. Notably,
+    /// local and phonetic names as well as address details such as floor,
+    /// room or district name are dropped.
+    pub fn minimal() -> Self {
+        Self::excluding(
+            &["localNameIdentifier", "phoneticNameIdentifier"],
+            &ADDRESS_DETAILS,
+        )
+    }
+
+    /// A profile allowing all fields but the local name identifiers, for
+    /// counterparties limited to names in Latin script.
+    pub fn without_local_names() -> Self {
+        Self::excluding(&["localNameIdentifier"], &[])
+    }
+
+    /// A profile allowing all fields but the address details, i.e. all
+    /// address fields but the address type, street name, building number,
+    /// post box, post code, town name, address lines and country.
+    pub fn without_address_details() -> Self {
+        Self::excluding(&[], &ADDRESS_DETAILS)
+    }
+
+    /// A profile allowing all fields but the local name identifiers and
+    /// the floor, room and district name of addresses, which are rejected
+    /// by some implementations.
+    pub fn restricted() -> Self {
+        Self::excluding(&["localNameIdentifier"], &["floor", "room", "districtName"])
+    }
+
+    /// A profile allowing all fields of persons but the given name
+    /// identifier and address fields.
+    fn excluding(names: &[&str], address: &[&str]) -> Self {
+        let mut profile = Self::new(["*.accountNumber"]);
+        for person in PERSONS {
+            for field in PERSON_FIELDS {
+                profile = profile.allow(&format!("*.*.{person}.{field}"));
+            }
+            for field in NAME_FIELDS.iter().filter(|field| !names.contains(field)) {
+                profile = profile.allow(&format!("*.*.{person}.name.{field}"));
+            }
+            for field in ADDRESS_FIELDS
+                .iter()
+                .filter(|field| !address.contains(field))
+            {
+                profile = profile.allow(&format!("*.*.{person}.geographicAddress.{field}"));
+            }
+        }
+        profile
+    }
+
+    /// Indicates whether the field at `path` is allowed.
+    fn allows(&self, path: &[String]) -> bool {
+        self.paths.iter().any(|allowed| {
+            allowed.len() <= path.len()
+                && allowed
+                    .iter()
+                    .zip(path)
+                    .all(|(allowed, field)| allowed == "*" || allowed == field)
+        })
+    }
+}
+
+impl IVMS101 {
+    /// Returns a copy of the payload reduced to the fields allowed by
+    /// `profile`, e.g. for counterparties rejecting fields they do not
+    /// support.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the violated constraint if the reduced
+    /// payload is not valid, e.g. because the profile drops a required
+    /// field.
+    pub fn project(&self, profile: &FieldProfile) -> Result<IVMS101, Error> {
+        let Value::Object(sections) =
+            serde_json::to_value(self).expect("IVMS101 serializes to JSON")
+        else {
+            unreachable!("IVMS101 serializes to a JSON object");
+        };
+        // Sections are kept even if nothing is left of them, such that an
+        // empty `beneficiaryVASP` still marks the beneficiary VASP unknown.
+        let value: Value = sections
+            .into_iter()
+            .map(|(section, value)| {
+                let value = project_value(profile, &mut vec![section.clone()], value)
+                    .unwrap_or_else(|| Value::Object(serde_json::Map::new()));
+                (section, value)
+            })
+            .collect::<serde_json::Map<_, _>>()
+            .into();
+        let projected = IVMS101::deserialize(&value)
+            .map_err(|e| Error::ValidationError(format!("Projection is incomplete: {e}")))?;
+        projected.validate()?;
+        Ok(projected)
+    }
+
+    /// Returns a copy of the payload without the local and phonetic name
+    /// identifiers of any person, e.g. for counterparties that only need
+    /// the primary names.
+    ///
+    /// As C5 and C6 only concern the primary name identifiers, a valid
+    /// payload remains valid.
+    #[must_use]
+    pub fn strip_local_phonetic(&self) -> IVMS101 {
+        let mut stripped = self.clone();
+        let originators = stripped
+            .originator
+            .iter_mut()
+            .flat_map(|o| o.originator_persons.iter_mut());
+        let beneficiaries = stripped
+            .beneficiary
+            .iter_mut()
+            .flat_map(|b| b.beneficiary_persons.iter_mut());
+        let originating_vasp = stripped
+            .originating_vasp
+            .iter_mut()
+            .map(|v| &mut v.originating_vasp);
+        let beneficiary_vasp = stripped
+            .beneficiary_vasp
+            .iter_mut()
+            .filter_map(|v| v.beneficiary_vasp.as_mut());
+        for person in originators
+            .chain(beneficiaries)
+            .chain(originating_vasp)
+            .chain(beneficiary_vasp)
+        {
+            match person {
+                Person::NaturalPerson(person) => {
+                    for name in person.name.iter_mut() {
+                        name.local_name_identifier = ZeroToN::None;
+                        name.phonetic_name_identifier = ZeroToN::None;
+                    }
+                }
+                Person::LegalPerson(person) => {
+                    person.name.local_name_identifier = ZeroToN::None;
+                    person.name.phonetic_name_identifier = ZeroToN::None;
+                }
+            }
+        }
+        stripped
+    }
+}
+
+/// Keeps the allowed parts of `value`, returning `None` if nothing is left.
+fn project_value(profile: &FieldProfile, path: &mut Vec<String>, value: Value) -> Option<Value> {
+    if profile.allows(path) {
+        return Some(value);
+    }
+    match value {
+        Value::Object(map) => {
+            let map: serde_json::Map<String, Value> = map
+                .into_iter()
+                .filter_map(|(key, value)| {
+                    path.push(key);
+                    let value = project_value(profile, path, value);
+                    let key = path.pop().expect("pushed above");
+                    value.map(|value| (key, value))
+                })
+                .collect();
+            (!map.is_empty()).then_some(Value::Object(map))
+        }
+        Value::Array(values) => {
+            let values: Vec<Value> = values
+                .into_iter()
+                .filter_map(|value| project_value(profile, path, value))
+                .collect();
+            (!values.is_empty()).then_some(Value::Array(values))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{OneToN, VaspStatus};
+
+    #[test]
+    fn test_project_minimal() {
+        let mut ivms = IVMS101::mock();
+        let person = ivms.originator_person_mut();
+        let OneToN::One(name) = &mut person.name else {
+            panic!("mock originator has a single name");
+        };
+        name.local_name_identifier = ZeroToN::One(name.name_identifier.first().clone());
+        let ZeroToN::One(address) = &mut person.geographic_address else {
+            panic!("mock originator has a single address");
+        };
+        address.floor = Some("3".try_into().unwrap());
+        address.room = Some("301".try_into().unwrap());
+        address.district_name = Some("Kreis 1".try_into().unwrap());
+
+        let projected = ivms.project(&FieldProfile::minimal()).unwrap();
+        let paths = projected.flatten();
+        for field in ["localNameIdentifier", "floor", "room", "districtName"] {
+            assert!(!paths.keys().any(|path| path.contains(field)), "{field}");
+        }
+        assert_eq!(projected, IVMS101::mock());
+        assert_eq!(ivms.project(&FieldProfile::full()).unwrap(), ivms);
+    }
+
+    #[test]
+    fn test_project_builtin() {
+        let mut ivms = IVMS101::mock();
+        let person = ivms.originator_person_mut();
+        let OneToN::One(name) = &mut person.name else {
+            panic!("mock originator has a single name");
+        };
+        name.local_name_identifier = ZeroToN::One(name.name_identifier.first().clone());
+        name.phonetic_name_identifier = ZeroToN::One(name.name_identifier.first().clone());
+        let ZeroToN::One(address) = &mut person.geographic_address else {
+            panic!("mock originator has a single address");
+        };
+        address.floor = Some("3".try_into().unwrap());
+        address.room = Some("301".try_into().unwrap());
+        address.district_name = Some("Kreis 1".try_into().unwrap());
+        address.country_sub_division = Some("ZH".try_into().unwrap());
+
+        let fields = |profile: &FieldProfile| {
+            let projected = ivms.project(profile).unwrap();
+            projected.validate().unwrap();
+            let paths = projected.flatten();
+            [
+                "localName",
+                "phoneticName",
+                "floor",
+                "room",
+                "districtName",
+                "countrySubDivision",
+            ]
+            .map(|field| paths.keys().any(|path| path.contains(field)))
+        };
+        assert_eq!(
+            fields(&FieldProfile::without_local_names()),
+            [false, true, true, true, true, true]
+        );
+        assert_eq!(
+            fields(&FieldProfile::without_address_details()),
+            [true, true, false, false, false, false]
+        );
+        assert_eq!(
+            fields(&FieldProfile::restricted()),
+            [false, true, false, false, false, true]
+        );
+        assert_eq!(
+            fields(&FieldProfile::minimal()),
+            [false, false, false, false, false, false]
+        );
+        assert_eq!(fields(&FieldProfile::full()), [true; 6]);
+    }
+
+    #[test]
+    fn test_project_sections() {
+        let empty = IVMS101::from_json("{}").unwrap();
+        assert_eq!(empty.project(&FieldProfile::minimal()).unwrap(), empty);
+
+        let mut ivms = IVMS101::mock();
+        ivms.set_beneficiary_vasp_unknown();
+        let projected = ivms.project(&FieldProfile::minimal()).unwrap();
+        assert_eq!(projected.beneficiary_vasp_status(), VaspStatus::Unknown);
+        assert_eq!(projected, ivms);
+    }
+
+    #[test]
+    fn test_strip_local_phonetic() {
+        let mut ivms = IVMS101::mock();
+        let person = ivms.originator_person_mut();
+        let OneToN::One(name) = &mut person.name else {
+            panic!("mock originator has a single name");
+        };
+        name.local_name_identifier = ZeroToN::One(name.name_identifier.first().clone());
+        name.phonetic_name_identifier = ZeroToN::One(name.name_identifier.first().clone());
+
+        let stripped = ivms.strip_local_phonetic();
+        stripped.validate().unwrap();
+        assert_eq!(stripped, IVMS101::mock());
+        let paths = stripped.flatten();
+        assert!(!paths.keys().any(|path| path.contains("local")));
+        assert!(!paths.keys().any(|path| path.contains("phonetic")));
+        assert!(paths.keys().any(|path| path.ends_with("primaryIdentifier")));
+
+        // Payloads of any size are stripped.
+        let mut ivms = IVMS101::mock();
+        ivms.originator.as_mut().unwrap().account_number =
+            vec!["account".try_into().unwrap(); 101].into();
+        assert_eq!(ivms.strip_local_phonetic(), ivms);
+    }
+
+    #[test]
+    fn test_project_incomplete() {
+        let profile =
+            FieldProfile::minimal().allow("originator.originatorPersons.naturalPerson.name");
+        assert!(IVMS101::mock().project(&profile).is_ok());
+
+        let profile = FieldProfile::new(["originator.accountNumber"]);
+        let err = IVMS101::mock().project(&profile).unwrap_err();
+        assert!(err.to_string().contains("missing field"), "{err}");
+    }
+}
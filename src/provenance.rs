@@ -0,0 +1,254 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+
+use crate::IVMS101;
+
+/// Where a value of a payload came from.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Source {
+    /// A label for the source, e.g. "kyc" or "counterparty".
+    pub label: String,
+    /// When the value was taken from the source.
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Records where the values of a payload came from, keyed by the field
+/// paths of [`IVMS101::flatten`].
+///
+/// Provenance is kept alongside the payload and is never part of its
+/// IVMS101 serialization. Use [`Provenance::to_json`] to persist it.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Provenance {
+    sources: BTreeMap<String, Source>,
+}
+
+impl Provenance {
+    /// Creates an empty provenance record.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records that the value at `path` was just taken from `label`.
+    pub fn record(&mut self, path: impl Into<String>, label: &str) {
+        self.sources.insert(
+            path.into(),
+            Source {
+                label: label.into(),
+                timestamp: Utc::now(),
+            },
+        );
+    }
+
+    /// Records that all values of `ivms` below `prefix` were just taken
+    /// from `label`, e.g. `"originator"` for the whole originator.
+    pub fn record_all(&mut self, ivms: &IVMS101, prefix: &str, label: &str) {
+        let below_prefix = |path: &str| {
+            prefix.is_empty()
+                || path.strip_prefix(prefix).is_some_and(|rest| {
+                    rest.is_empty() || rest.starts_with('.') || rest.starts_with('[')
+                })
+        };
+        for path in ivms.flatten().into_keys().filter(|path| below_prefix(path)) {
+            self.record(path, label);
+        }
+    }
+
+    /// Applies `f` to `ivms`, e.g. calls of its setters, and records that
+    /// every value it sets or changes was just taken from `label`. The
+    /// sources of values it removes are forgotten.
+    ///
+    /// ```
+    /// use ivms101::{Beneficiary, Provenance, IVMS101};
+    ///
+    /// let mut ivms = IVMS101::default();
+    /// let mut provenance = Provenance::new();
+    /// provenance.apply(&mut ivms, "user-input", |ivms| {
+    ///     ivms.set_beneficiary(Beneficiary::unhosted("Jane", "Roe", "wallet-1").unwrap());
+    /// });
+    /// assert_eq!(
+    ///     provenance.get("beneficiary.accountNumber[0]").unwrap().label,
+    ///     "user-input"
+    /// );
+    /// ```
+    pub fn apply(&mut self, ivms: &mut IVMS101, label: &str, f: impl FnOnce(&mut IVMS101)) {
+        let before = ivms.flatten();
+        f(ivms);
+        let after = ivms.flatten();
+        for (path, value) in &after {
+            if before.get(path) != Some(value) {
+                self.record(path.as_str(), label);
+            }
+        }
+        self.sources.retain(|path, _| after.contains_key(path));
+    }
+
+    /// Returns the source of the value at `path`, if recorded.
+    pub fn get(&self, path: &str) -> Option<&Source> {
+        self.sources.get(path)
+    }
+
+    /// Returns an iterator over the recorded paths and their sources,
+    /// ordered by path.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Source)> {
+        self.sources
+            .iter()
+            .map(|(path, source)| (path.as_str(), source))
+    }
+
+    /// Merges the provenance of another payload into this one. For paths
+    /// recorded in both, the more recent source is kept.
+    pub fn merge(&mut self, other: Provenance) {
+        for (path, source) in other.sources {
+            match self.sources.get(&path) {
+                Some(existing) if existing.timestamp > source.timestamp => {}
+                _ => {
+                    self.sources.insert(path, source);
+                }
+            }
+        }
+    }
+
+    /// Forgets the sources of paths that no longer hold a value in `ivms`.
+    pub fn retain_present(&mut self, ivms: &IVMS101) {
+        let paths = ivms.flatten();
+        self.sources.retain(|path, _| paths.contains_key(path));
+    }
+
+    /// Serializes the provenance to JSON.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("provenance serializes to JSON")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payload() -> IVMS101 {
+        IVMS101::from_json(
+            r#"{
+                "originator": {
+                    "originatorPersons": {
+                        "naturalPerson": {
+                            "name": {
+                                "nameIdentifier": {
+                                    "primaryIdentifier": "Engels",
+                                    "nameIdentifierType": "LEGL"
+                                }
+                            }
+                        }
+                    },
+                    "accountNumber": "account-1"
+                },
+                "beneficiary": {
+                    "beneficiaryPersons": {
+                        "naturalPerson": {
+                            "name": {
+                                "nameIdentifier": {
+                                    "primaryIdentifier": "Marx",
+                                    "nameIdentifierType": "LEGL"
+                                }
+                            }
+                        }
+                    }
+                }
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_two_sources() {
+        let ivms = payload();
+        let mut provenance = Provenance::new();
+        provenance.record_all(&ivms, "originator", "kyc");
+        provenance.record_all(&ivms, "beneficiary", "user-input");
+
+        assert!(ivms
+            .flatten()
+            .keys()
+            .eq(provenance.iter().map(|(path, _)| path)));
+        assert_eq!(
+            provenance
+                .get("originator.originatorPersons[0].naturalPerson.name[0].nameIdentifier[0].primaryIdentifier")
+                .unwrap()
+                .label,
+            "kyc"
+        );
+        assert_eq!(
+            provenance.get("beneficiary.beneficiaryPersons[0].naturalPerson.name[0].nameIdentifier[0].primaryIdentifier")
+                .unwrap()
+                .label,
+            "user-input"
+        );
+        assert!(!ivms.to_json().contains("kyc"));
+        assert!(provenance.to_json().contains("\"label\":\"kyc\""));
+    }
+
+    #[test]
+    fn test_apply() {
+        let payload = payload();
+        let mut ivms = IVMS101::default();
+        let mut provenance = Provenance::new();
+        provenance.apply(&mut ivms, "kyc", |ivms| {
+            ivms.set_originator(payload.originator().unwrap().clone());
+        });
+        provenance.apply(&mut ivms, "user-input", |ivms| {
+            ivms.set_beneficiary(payload.beneficiary().unwrap().clone());
+        });
+        assert_eq!(ivms, payload);
+        assert!(ivms
+            .flatten()
+            .keys()
+            .eq(provenance.iter().map(|(path, _)| path)));
+
+        provenance.apply(&mut ivms, "counterparty", |ivms| {
+            let mut originator = ivms.originator().unwrap().clone();
+            originator.remove_account_number("account-1");
+            originator.add_account_number("account-2").unwrap();
+            ivms.set_originator(originator);
+            ivms.clear_beneficiary();
+        });
+        assert!(ivms
+            .flatten()
+            .keys()
+            .eq(provenance.iter().map(|(path, _)| path)));
+        assert_eq!(
+            provenance.get("originator.accountNumber[0]").unwrap().label,
+            "counterparty"
+        );
+        assert_eq!(
+            provenance
+                .get("originator.originatorPersons[0].naturalPerson.name[0].nameIdentifier[0].primaryIdentifier")
+                .unwrap()
+                .label,
+            "kyc"
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut kyc = Provenance::new();
+        kyc.record("originator.accountNumber[0]", "kyc");
+        kyc.record("originator.originatorPersons[0].naturalPerson.name[0].nameIdentifier[0].primaryIdentifier", "kyc");
+        let mut counterparty = Provenance::new();
+        counterparty.record("originator.accountNumber[0]", "counterparty");
+        counterparty.record("beneficiary.beneficiaryPersons[0].naturalPerson.name[0].nameIdentifier[0].primaryIdentifier", "counterparty");
+
+        kyc.merge(counterparty);
+        assert_eq!(kyc.iter().count(), 3);
+        assert_eq!(
+            kyc.get("originator.accountNumber[0]").unwrap().label,
+            "counterparty"
+        );
+
+        kyc.record("beneficiary.accountNumber[0]", "user-input");
+        kyc.retain_present(&payload());
+        assert_eq!(kyc.iter().count(), 3);
+        assert!(kyc.get("beneficiary.accountNumber[0]").is_none());
+    }
+}
@@ -0,0 +1,73 @@
+use lei::registration_authority::RegistrationAuthority;
+
+use crate::{CountryCode, Error};
+
+/// Jurisdictions of a selection of registers from the GLEIF
+/// registration authorities list, keyed by registration authority code.
+///
+/// The `lei` crate validates the codes against the list but does not
+/// expose their jurisdictions, so they are copied from
+/// <https://www.gleif.org/en/about-lei/code-lists/gleif-registration-authorities-list>.
+/// Registration authorities missing here are treated as unknown. The
+/// table covers the registers used by the tests and the test kit.
+const JURISDICTIONS: [(&str, &str); 7] = [
+    ("RA000001", "AF"),
+    ("RA000002", "AX"),
+    ("RA000189", "FR"),
+    ("RA000463", "NL"),
+    ("RA000548", "CH"),
+    ("RA000585", "GB"),
+    ("RA000602", "US"),
+];
+
+/// Deserializes an optional registration authority from a borrowed or an
+/// owned string, e.g. when reading from an [`std::io::Read`].
+pub(crate) fn deserialize<'de, D>(
+    deserializer: D,
+) -> Result<Option<RegistrationAuthority>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let Some(ra) = <Option<String> as serde::Deserialize>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+    parse(&ra).map(Some).map_err(serde::de::Error::custom)
+}
+
+/// Parses a registration authority code, accepting lowercase letters
+/// and canonicalizing them to uppercase.
+///
+/// The shape of the code, "RA" followed by six digits, is checked before
+/// looking the code up on the GLEIF list, such that malformed codes are
+/// reported as such.
+pub(crate) fn parse(ra: &str) -> Result<RegistrationAuthority, Error> {
+    let well_formed = match ra.as_bytes() {
+        [r, a, digits @ ..] => {
+            r.eq_ignore_ascii_case(&b'R')
+                && a.eq_ignore_ascii_case(&b'A')
+                && digits.len() == 6
+                && digits.iter().all(u8::is_ascii_digit)
+        }
+        _ => false,
+    };
+    if !well_formed {
+        return Err(Error::ValidationError(format!(
+            "Registration authority '{ra}' must consist of RA and six digits (IVMS101 C10)"
+        )));
+    }
+    RegistrationAuthority::try_from(ra.to_ascii_uppercase().as_str()).map_err(|e| {
+        format!("Invalid registration authority: {e} (IVMS101 C10)")
+            .as_str()
+            .into()
+    })
+}
+
+/// Returns the country whose register the registration authority
+/// maintains, or `None` if the registration authority is not known.
+pub(crate) fn jurisdiction(ra: &RegistrationAuthority) -> Option<CountryCode> {
+    let ra = ra.to_string();
+    JURISDICTIONS
+        .iter()
+        .find(|(code, _)| *code == ra)
+        .and_then(|(_, country)| CountryCode::try_from(*country).ok())
+}
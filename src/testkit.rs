@@ -0,0 +1,337 @@
+//! Test vectors for certifying counterparties, i.e. checking that they
+//! accept valid payloads, including edge cases, and reject invalid ones.
+//!
+//! The vectors are named by their expected outcome:
+//!
+//! - `valid-*` payloads pass validation,
+//! - `invalid-c<N>-*` payloads can be constructed, but violate the
+//!   IVMS101 constraint `C<N>` and only that one, and
+//! - `rejected-*` payloads cannot be constructed, because a value
+//!   exceeds its bounds or is malformed. They are represented by the
+//!   construction error, and named `rejected-c<N>-*` if the value
+//!   violates the IVMS101 constraint `C<N>`.
+//!
+//! The payloads can be written to JSON files to be sent to the
+//! counterparty:
+//!
+//! ```
+//! for (name, payload, description) in ivms101::testkit::certification_vectors() {
+//!     if let Ok(payload) = payload {
+//!         let json = payload.to_json();
+//!         // std::fs::write(format!("{name}.json"), json).unwrap();
+//!     }
+//! }
+//! ```
+
+use chrono::NaiveDate;
+
+use crate::{
+    Address, Beneficiary, BeneficiaryVASP, DateAndPlaceOfBirth, Error, LegalPerson,
+    LegalPersonNameID, NationalIdentification, NationalIdentifierTypeCode, NaturalPerson,
+    NaturalPersonName, NaturalPersonNameID, OneToN, OriginatingVASP, Originator, ZeroToN, IVMS101,
+};
+
+const LEI: &str = "2594007XIACKNMUAW223";
+
+/// Generates the certification vectors as tuples of the name, the
+/// payload or the error constructing it and a description of what the
+/// vector exercises.
+#[must_use]
+pub fn certification_vectors() -> Vec<(String, Result<IVMS101, Error>, &'static str)> {
+    let vectors: [(&str, Result<IVMS101, Error>, &str); 18] = [
+        (
+            "valid-minimal",
+            base(),
+            "A natural person originator and a legal person beneficiary",
+        ),
+        (
+            "valid-max-length",
+            max_length(),
+            "Names, account numbers and town names of the maximum length",
+        ),
+        (
+            "valid-multi-valued",
+            multi_valued(),
+            "Several originator and beneficiary persons, account numbers and addresses",
+        ),
+        (
+            "valid-unknown-country",
+            edit(|ivms| {
+                ivms.originator_person_mut().geographic_address =
+                    Some(Address::new(None, None, Some("Main street"), "", "Zurich", "XX")?).into();
+                Ok(())
+            }),
+            "An address in the unknown country XX",
+        ),
+        (
+            "valid-unhosted-wallet",
+            unhosted_wallet(),
+            "A withdrawal to an unhosted wallet without beneficiary VASP",
+        ),
+        (
+            "invalid-c1-originator-without-identification",
+            edit(|ivms| {
+                ivms.originator_person_mut().geographic_address = ZeroToN::None;
+                Ok(())
+            }),
+            "A natural person originator with neither address, customer id, national id nor date and place of birth",
+        ),
+        (
+            "invalid-c2-birth-in-future",
+            edit(|ivms| {
+                ivms.originator_person_mut().date_and_place_of_birth = Some(DateAndPlaceOfBirth {
+                    date_of_birth: NaiveDate::from_ymd_opt(2999, 1, 1).expect("valid date"),
+                    place_of_birth: "Zurich".try_into()?,
+                });
+                Ok(())
+            }),
+            "A date of birth in the future",
+        ),
+        (
+            "invalid-c4-legal-person-without-identification",
+            edit(|ivms| {
+                let person = ivms.beneficiary_person_mut();
+                person.geographic_address = ZeroToN::None;
+                person.customer_identification = None;
+                person.national_identification = None;
+                Ok(())
+            }),
+            "A legal person with neither address, customer id nor national id",
+        ),
+        (
+            "invalid-c5-legal-person-without-legal-name",
+            edit(|ivms| {
+                ivms.beneficiary_person_mut().name.name_identifier =
+                    LegalPersonNameID::trading_name("Company A")?.into();
+                Ok(())
+            }),
+            "A legal person with a trading name only",
+        ),
+        (
+            "invalid-c6-natural-person-without-legal-name",
+            edit(|ivms| {
+                ivms.originator_person_mut().name = NaturalPersonName {
+                    name_identifier: NaturalPersonNameID::alias("Engels", Some("Friedrich"))?
+                        .into(),
+                    local_name_identifier: ZeroToN::None,
+                    phonetic_name_identifier: ZeroToN::None,
+                }
+                .into();
+                Ok(())
+            }),
+            "A natural person with an alias only",
+        ),
+        (
+            "invalid-c7-legal-person-with-passport",
+            edit(|ivms| {
+                ivms.beneficiary_person_mut().national_identification = Some(NationalIdentification {
+                    national_identifier: "X1".try_into()?,
+                    national_identifier_type: NationalIdentifierTypeCode::PassportNumber,
+                    country_of_issue: None,
+                    registration_authority: None,
+                });
+                Ok(())
+            }),
+            "A legal person identified by a passport number",
+        ),
+        (
+            "invalid-c8-incomplete-address",
+            edit(|ivms| {
+                ivms.originator_person_mut().geographic_address =
+                    Some(Address::new(Some("Main street"), None, None, "8000", "Zurich", "CH")?)
+                        .into();
+                Ok(())
+            }),
+            "An address with a street name, but neither building number, building name nor address line",
+        ),
+        (
+            "invalid-c9-legal-person-with-country-of-issue",
+            edit(|ivms| {
+                let identification = ivms.beneficiary_person_mut()
+                    .national_identification
+                    .as_mut()
+                    .expect("beneficiary has an LEI");
+                identification.country_of_issue = Some("CH".try_into()?);
+                Ok(())
+            }),
+            "A legal person national identification with a country of issue",
+        ),
+        (
+            "invalid-c11-invalid-lei",
+            edit(|ivms| {
+                let identification = ivms.beneficiary_person_mut()
+                    .national_identification
+                    .as_mut()
+                    .expect("beneficiary has an LEI");
+                identification.national_identifier = "2594007XIACKNMUAW224".try_into()?;
+                Ok(())
+            }),
+            "An LEI with an invalid checksum",
+        ),
+        (
+            "rejected-name-too-long",
+            NaturalPerson::new("Friedrich", &"E".repeat(101), None, None).and_then(|_| base()),
+            "A last name exceeding 100 bytes",
+        ),
+        (
+            "rejected-account-number-too-long",
+            edit(|ivms| {
+                ivms.originator
+                    .as_mut()
+                    .expect("base has an originator")
+                    .account_number = Some("1".repeat(101).as_str().try_into()?).into();
+                Ok(())
+            }),
+            "An account number exceeding 100 bytes",
+        ),
+        (
+            "rejected-invalid-country",
+            Address::new(None, None, Some("Main street"), "", "Zurich", "ZZ").and_then(|_| base()),
+            "An address in the unassigned country ZZ",
+        ),
+        (
+            "rejected-c10-malformed-registration-authority",
+            edit(|ivms| {
+                let mut identification = NationalIdentification {
+                    national_identifier: "CHE-123.456.789".try_into()?,
+                    national_identifier_type:
+                        NationalIdentifierTypeCode::RegistrationAuthorityIdentifier,
+                    country_of_issue: None,
+                    registration_authority: None,
+                };
+                identification.set_registration_authority("RA00000A")?;
+                ivms.beneficiary_person_mut().national_identification = Some(identification);
+                Ok(())
+            }),
+            "A registration authority not consisting of RA and six digits",
+        ),
+    ];
+    vectors
+        .into_iter()
+        .map(|(name, payload, description)| (name.to_owned(), payload, description))
+        .collect()
+}
+
+/// A valid payload the other vectors are derived from.
+fn base() -> Result<IVMS101, Error> {
+    let lei = lei::LEI::try_from(LEI)?;
+    let address = Address::new(Some("Main street"), Some("1"), None, "8000", "Zurich", "CH")?;
+    let originator = NaturalPerson::new("Friedrich", "Engels", None, Some(address.clone()))?;
+    let beneficiary = LegalPerson::new("Company A", "customer-b", address, &lei)?;
+    Ok(IVMS101 {
+        originator: Some(Box::new(Originator::new(originator.into())?)),
+        beneficiary: Some(Box::new(Beneficiary::new(
+            beneficiary.into(),
+            Some("account-2"),
+        )?)),
+        originating_vasp: Some(Box::new(OriginatingVASP::new("VASP A", &lei)?)),
+        beneficiary_vasp: Some(Box::new(BeneficiaryVASP {
+            beneficiary_vasp: Some(LegalPerson::vasp("VASP B", &lei)?.into()),
+        })),
+    })
+}
+
+/// Derives a payload from [`base`].
+fn edit(f: impl FnOnce(&mut IVMS101) -> Result<(), Error>) -> Result<IVMS101, Error> {
+    let mut ivms = base()?;
+    f(&mut ivms)?;
+    Ok(ivms)
+}
+
+fn max_length() -> Result<IVMS101, Error> {
+    edit(|ivms| {
+        *ivms.originator_person_mut() = NaturalPerson::new(
+            &"F".repeat(100),
+            &"E".repeat(100),
+            Some(&"C".repeat(50)),
+            Some(Address::new(
+                None,
+                None,
+                Some(&"M".repeat(70)),
+                &"8".repeat(16),
+                &"Z".repeat(35),
+                "CH",
+            )?),
+        )?;
+        ivms.originator
+            .as_mut()
+            .expect("base has an originator")
+            .account_number = Some("1".repeat(100).as_str().try_into()?).into();
+        Ok(())
+    })
+}
+
+fn multi_valued() -> Result<IVMS101, Error> {
+    edit(|ivms| {
+        let home = Address::new(None, None, Some("Main street"), "8000", "Zurich", "CH")?;
+        let mut work = Address::new(Some("Station road"), Some("2"), None, "3000", "Bern", "CH")?;
+        work.address_type = crate::AddressTypeCode::Business;
+        let mut engels = NaturalPerson::new("Friedrich", "Engels", None, None)?;
+        engels.set_addresses(vec![home.clone(), work.clone()])?;
+        let mut marx = NaturalPerson::new("Karl", "Marx", Some("customer-a"), Some(home))?;
+        marx.name = NaturalPersonName {
+            name_identifier: NaturalPersonNameID::legal_name("Marx", Some("Karl"))?.into(),
+            local_name_identifier: vec![NaturalPersonNameID::alias("Mohr", None)?].into(),
+            phonetic_name_identifier: ZeroToN::None,
+        }
+        .into();
+        let persons = vec![engels.into(), marx.into()];
+
+        let originator = ivms.originator.as_mut().expect("base has an originator");
+        originator.originator_persons = OneToN::N(persons.try_into()?);
+        originator.account_number = vec!["account-1".try_into()?, "account-3".try_into()?].into();
+
+        ivms.beneficiary_person_mut()
+            .set_addresses(vec![work.clone(), work])?;
+        Ok(())
+    })
+}
+
+fn unhosted_wallet() -> Result<IVMS101, Error> {
+    let originator = NaturalPerson::new("Friedrich", "Engels", Some("customer-a"), None)?;
+    let mut ivms = IVMS101::for_unhosted_withdrawal(
+        originator.into(),
+        "account-1",
+        "Karl",
+        "Marx",
+        "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq",
+    )?;
+    ivms.originating_vasp = Some(Box::new(OriginatingVASP::new(
+        "VASP A",
+        &lei::LEI::try_from(LEI)?,
+    )?));
+    Ok(ivms)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+    use crate::Validatable;
+
+    #[test]
+    fn test_certification_vectors() {
+        let vectors = certification_vectors();
+        for (name, payload, description) in &vectors {
+            assert!(!description.is_empty());
+            if name.starts_with("valid-") {
+                payload.as_ref().unwrap().validate().unwrap();
+            } else if let Some(rest) = name.strip_prefix("invalid-c") {
+                let code = format!("C{}", rest.split('-').next().unwrap());
+                let errors = payload.as_ref().unwrap().validate_all().expect_err(name);
+                assert_eq!(errors.len(), 1, "{name}: {errors:?}");
+                assert_eq!(errors[0].constraint(), Some(code.as_str()), "{name}");
+            } else if let Some(rest) = name.strip_prefix("rejected-c") {
+                let code = format!("C{}", rest.split('-').next().unwrap());
+                let error = payload.as_ref().expect_err(name);
+                assert_eq!(error.constraint(), Some(code.as_str()), "{name}");
+            } else {
+                assert!(name.starts_with("rejected-"), "{name}");
+                assert!(payload.is_err(), "{name}");
+            }
+        }
+        let names: BTreeSet<_> = vectors.iter().map(|(name, ..)| name).collect();
+        assert_eq!(names.len(), vectors.len());
+    }
+}
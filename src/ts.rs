@@ -0,0 +1,107 @@
+//! TypeScript definitions of the types the data model is built from,
+//! complementing the derived ones.
+
+use ts_rs::{TypeVisitor, TS};
+
+use crate::{BoundedString, CountryCode, OneToN, ZeroToN};
+
+/// Implements `TS` for a collection serialized as a single element or
+/// an array of elements.
+macro_rules! impl_one_or_many {
+    ($ty:ident) => {
+        impl<T: Clone + TS> TS for $ty<T> {
+            type WithoutGenerics = Self;
+
+            fn name() -> String {
+                format!("{} | Array<{}>", T::name(), T::name())
+            }
+
+            fn inline() -> String {
+                format!("{} | Array<{}>", T::inline(), T::inline())
+            }
+
+            fn visit_dependencies(v: &mut impl TypeVisitor)
+            where
+                Self: 'static,
+            {
+                T::visit_dependencies(v);
+            }
+
+            fn visit_generics(v: &mut impl TypeVisitor)
+            where
+                Self: 'static,
+            {
+                T::visit_generics(v);
+                v.visit::<T>();
+            }
+
+            fn decl() -> String {
+                panic!("{} cannot be declared", Self::name())
+            }
+
+            fn decl_concrete() -> String {
+                panic!("{} cannot be declared", Self::name())
+            }
+
+            fn inline_flattened() -> String {
+                panic!("{} cannot be flattened", Self::name())
+            }
+        }
+    };
+}
+
+impl_one_or_many!(OneToN);
+impl_one_or_many!(ZeroToN);
+
+/// Implements `TS` for a type serialized as a string.
+macro_rules! impl_string {
+    ($ty:ty $(, const $max:ident)?) => {
+        impl$(<const $max: usize>)? TS for $ty {
+            type WithoutGenerics = Self;
+
+            fn name() -> String {
+                "string".to_owned()
+            }
+
+            fn inline() -> String {
+                Self::name()
+            }
+
+            fn decl() -> String {
+                panic!("{} cannot be declared", Self::name())
+            }
+
+            fn decl_concrete() -> String {
+                panic!("{} cannot be declared", Self::name())
+            }
+
+            fn inline_flattened() -> String {
+                panic!("{} cannot be flattened", Self::name())
+            }
+        }
+    };
+}
+
+impl_string!(BoundedString<MAX>, const MAX);
+impl_string!(CountryCode);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Address, AddressTypeCode, IntermediaryVASP};
+
+    #[test]
+    fn test_definitions() {
+        assert!(AddressTypeCode::decl().contains(r#""HOME" | "BIZZ" | "GEOG""#));
+
+        let address = Address::decl();
+        assert!(address.contains("addressType: AddressTypeCode"));
+        assert!(address.contains("townName: string"));
+        assert!(address.contains("postCode?: string"));
+        assert!(address.contains("addressLine?: string | Array<string>"));
+
+        let vasp = IntermediaryVASP::decl();
+        assert!(vasp.contains("intermediaryVasp: Person"));
+        assert!(vasp.contains("sequence: number"));
+    }
+}
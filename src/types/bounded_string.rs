@@ -0,0 +1,160 @@
+use crate::Error;
+
+/// A string of at most `MAX` bytes.
+///
+/// The IVMS101 data model bounds most strings, e.g. names to 100 bytes.
+/// The bound is checked on construction and deserialization.
+///
+/// ```
+/// use ivms101::BoundedString;
+///
+/// assert!(BoundedString::<4>::try_from("abcd").is_ok());
+/// assert!(BoundedString::<4>::try_from("abcde").is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+#[serde(try_from = "String")]
+pub struct BoundedString<const MAX: usize> {
+    inner: String,
+}
+
+// Serializes the borrowed inner string, avoiding the clone an
+// `into = "String"` conversion would incur.
+impl<const MAX: usize> serde::Serialize for BoundedString<MAX> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.inner)
+    }
+}
+
+impl<const MAX: usize> TryFrom<&str> for BoundedString<MAX> {
+    type Error = Error;
+    fn try_from(from: &str) -> Result<Self, Error> {
+        if from.len() <= MAX {
+            Ok(Self { inner: from.into() })
+        } else {
+            Err(format!(
+                "Cannot parse String of length {} into a {:?}",
+                from.len(),
+                std::any::type_name::<Self>()
+            )
+            .as_str()
+            .into())
+        }
+    }
+}
+
+impl<const MAX: usize> TryFrom<String> for BoundedString<MAX> {
+    type Error = Error;
+    fn try_from(from: String) -> Result<Self, Error> {
+        if from.len() <= MAX {
+            Ok(Self { inner: from })
+        } else {
+            Self::try_from(from.as_str())
+        }
+    }
+}
+
+impl<const MAX: usize> From<BoundedString<MAX>> for String {
+    fn from(value: BoundedString<MAX>) -> Self {
+        value.inner
+    }
+}
+
+impl<const MAX: usize> BoundedString<MAX> {
+    /// The maximum length in bytes.
+    pub const MAX: usize = MAX;
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.inner
+    }
+
+    /// Converts `value` of the field described by `field`, e.g. "Building
+    /// number", failing with an error naming the field if it is too long.
+    pub(crate) fn for_field(value: &str, field: &str) -> Result<Self, Error> {
+        Self::try_from(value)
+            .map_err(|_| Error::ValidationError(format!("{field} exceeds {MAX} bytes")))
+    }
+}
+
+impl<const MAX: usize> std::fmt::Display for BoundedString<MAX> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Deserializes a [`BoundedString`] from either a string or an integer,
+/// which is converted to its decimal form before the bound is checked.
+///
+/// Some producers serialize numeric account numbers as JSON numbers.
+pub(crate) struct StringOrInteger<const MAX: usize>(pub(crate) BoundedString<MAX>);
+
+impl<'de, const MAX: usize> serde::Deserialize<'de> for StringOrInteger<MAX> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor<const MAX: usize>;
+
+        impl<const MAX: usize> serde::de::Visitor<'_> for Visitor<MAX> {
+            type Value = BoundedString<MAX>;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "a string or an integer")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                BoundedString::try_from(v).map_err(E::custom)
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                self.visit_str(&v.to_string())
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                self.visit_str(&v.to_string())
+            }
+        }
+
+        deserializer.deserialize_any(Visitor).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounded_string() {
+        for len in [0, 1, 35] {
+            let s = "a".repeat(len);
+            let bounded = BoundedString::<35>::try_from(s.as_str()).unwrap();
+            assert_eq!(bounded.as_str(), s);
+            assert_eq!(bounded.to_string(), s);
+            assert_eq!(
+                serde_json::to_string(&bounded).unwrap(),
+                format!(r#""{s}""#)
+            );
+            assert_eq!(String::from(bounded), s);
+        }
+        for len in [36, 100] {
+            assert!(BoundedString::<35>::try_from("a".repeat(len).as_str()).is_err());
+        }
+
+        let json = format!(r#""{}""#, "a".repeat(36));
+        assert!(serde_json::from_str::<BoundedString<35>>(&json).is_err());
+        assert!(serde_json::from_str::<BoundedString<35>>(r#""Zurich""#).is_ok());
+        serde_test::assert_tokens(
+            &BoundedString::<4>::try_from("0123").unwrap(),
+            &[serde_test::Token::BorrowedStr("0123")],
+        );
+        assert_eq!(BoundedString::<35>::MAX, 35);
+    }
+
+    #[test]
+    fn test_string_or_integer() {
+        let parse = |json| serde_json::from_str::<StringOrInteger<5>>(json).map(|s| s.0);
+        assert_eq!(parse(r#""abc""#).unwrap().as_str(), "abc");
+        assert_eq!(parse("12345").unwrap().as_str(), "12345");
+        assert_eq!(parse("-1").unwrap().as_str(), "-1");
+        assert!(parse("123456").is_err());
+        assert!(parse("1.5").is_err());
+        assert!(parse("true").is_err());
+    }
+}
@@ -0,0 +1,168 @@
+use crate::Error;
+
+/// Limits applying while parsing payloads from untrusted parties, see
+/// [`crate::IVMS101::from_json_with`] and
+/// [`crate::IVMS101::from_json_bounded`].
+///
+/// Plain deserialization, e.g. with [`crate::IVMS101::from_json`], does
+/// not apply any limits. By default, collections hold at most 100
+/// elements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DeserializeLimits {
+    /// The maximum number of elements of a collection.
+    pub max_elements: usize,
+}
+
+impl Default for DeserializeLimits {
+    fn default() -> Self {
+        Self { max_elements: 100 }
+    }
+}
+
+/// A level of nesting entered by [`DeserializeLimits::check`].
+enum Level {
+    /// An object and the key of its current field.
+    Object { key: String, expects_key: bool },
+    /// An array and the number of its elements so far.
+    Array {
+        elements: usize,
+        expects_element: bool,
+    },
+}
+
+impl DeserializeLimits {
+    /// Checks that no array of the JSON payload exceeds the limits,
+    /// returning an error at the path of the first one that does, e.g.
+    /// `originator.accountNumber`.
+    ///
+    /// The payload is scanned without being parsed, such that an
+    /// oversized array is rejected before its elements are allocated.
+    /// Malformed JSON is left to the parser.
+    pub(crate) fn check(&self, json: &str) -> Result<(), Error> {
+        let mut levels: Vec<Level> = Vec::new();
+        let mut string: Option<String> = None;
+        let mut escaped = false;
+        for c in json.chars() {
+            if let Some(chars) = string.as_mut() {
+                match c {
+                    _ if escaped => {
+                        escaped = false;
+                        chars.push(c);
+                    }
+                    '\\' => {
+                        escaped = true;
+                        chars.push(c);
+                    }
+                    '"' => {
+                        let chars = string.take().unwrap_or_default();
+                        if let Some(Level::Object { key, expects_key }) = levels.last_mut() {
+                            if *expects_key {
+                                *key = chars;
+                                *expects_key = false;
+                            }
+                        }
+                    }
+                    _ => chars.push(c),
+                }
+                continue;
+            }
+            if c.is_whitespace() || matches!(c, ':' | ']' | '}' | ',') {
+                match (c, levels.last_mut()) {
+                    (']' | '}', _) => {
+                        levels.pop();
+                    }
+                    (',', Some(Level::Object { expects_key, .. })) => *expects_key = true,
+                    (
+                        ',',
+                        Some(Level::Array {
+                            expects_element, ..
+                        }),
+                    ) => *expects_element = true,
+                    _ => {}
+                }
+                continue;
+            }
+            // Any other character starts a value.
+            if let Some(Level::Array {
+                elements,
+                expects_element,
+            }) = levels.last_mut()
+            {
+                if *expects_element {
+                    *elements += 1;
+                    *expects_element = false;
+                }
+                if *elements > self.max_elements {
+                    levels.pop();
+                    return Err(Error::ParseError(format!(
+                        "must contain at most {} elements",
+                        self.max_elements
+                    ))
+                    .at(&path(&levels)));
+                }
+            }
+            match c {
+                '"' => string = Some(String::new()),
+                '{' => levels.push(Level::Object {
+                    key: String::new(),
+                    expects_key: true,
+                }),
+                '[' => levels.push(Level::Array {
+                    elements: 0,
+                    expects_element: true,
+                }),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The path of the value at the innermost of `levels`, e.g. `a.b[1]`.
+fn path(levels: &[Level]) -> String {
+    let mut path = String::new();
+    for level in levels {
+        match level {
+            Level::Object { key, .. } => {
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(key);
+            }
+            Level::Array { elements, .. } => {
+                path.push_str(&format!("[{}]", elements.saturating_sub(1)));
+            }
+        }
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let limits = DeserializeLimits { max_elements: 2 };
+        let json = r#"{"a": [1, 2], "b": {"c": [[1], [1, 2, 3]]}}"#;
+        let err = limits.check(json).unwrap_err();
+        assert_eq!(err.path(), Some("b.c[1]"));
+        assert!(err.to_string().contains("at most 2 elements"), "{err}");
+        limits
+            .check(r#"[[1, 2], {"a": [], "b,]": "[1, 2, 3]", "c": [{}, "\"]"]}]"#)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_check_before_parsing() {
+        // The oversized array is rejected while the payload is scanned,
+        // before the parser would find that it is truncated.
+        let json = format!(
+            r#"{{"originator": {{"accountNumber": [{}"#,
+            r#""1","#.repeat(100_000)
+        );
+        let err = DeserializeLimits::default().check(&json).unwrap_err();
+        assert_eq!(err.path(), Some("originator.accountNumber"));
+        assert!(serde_json::from_str::<serde_json::Value>(&json).is_err());
+    }
+}
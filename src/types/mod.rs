@@ -1,11 +1,17 @@
-mod constrained_string;
-mod non_empty_vec;
+pub(crate) mod bounded_string;
+pub(crate) mod deserialize_limits;
+pub(crate) mod non_empty_vec;
+pub(crate) mod one_or_many;
 pub(crate) mod one_to_n;
+#[cfg(feature = "binary")]
+pub mod seq;
+pub(crate) mod serialize_options;
 pub(crate) mod zero_to_n;
 
-use crate::Error;
-crate::constrained_string!(StringMax16, |l| l <= 16);
-crate::constrained_string!(StringMax35, |l| l <= 35);
-crate::constrained_string!(StringMax50, |l| l <= 50);
-crate::constrained_string!(StringMax70, |l| l <= 70);
-crate::constrained_string!(StringMax100, |l| l <= 100);
+use bounded_string::BoundedString;
+
+pub type StringMax16 = BoundedString<16>;
+pub type StringMax35 = BoundedString<35>;
+pub type StringMax50 = BoundedString<50>;
+pub type StringMax70 = BoundedString<70>;
+pub type StringMax100 = BoundedString<100>;
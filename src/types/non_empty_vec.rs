@@ -38,6 +38,20 @@ impl<T: Clone> NonEmptyVec<T> {
     pub(crate) fn first(&self) -> &T {
         self.inner.first().unwrap()
     }
+
+    pub(crate) fn as_slice(&self) -> &[T] {
+        &self.inner
+    }
+
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.inner
+    }
+
+    /// Bypasses the non-emptiness check to construct invalid states.
+    #[cfg(test)]
+    pub(crate) fn new_unchecked(inner: Vec<T>) -> Self {
+        Self { inner }
+    }
 }
 
 #[cfg(test)]
@@ -1,9 +1,9 @@
 use crate::types::non_empty_vec::NonEmptyVec;
+use crate::types::one_or_many::OneOrMany;
 
 /// `OneToN` is a helper enum to accept a singleton or non-empty list-enumerated
 /// field during deserialization.
-#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
-#[serde(untagged)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum OneToN<T: Clone> {
     One(T),
     N(NonEmptyVec<T>),
@@ -23,6 +23,196 @@ impl<T: Clone> OneToN<T> {
             OneToN::N(nev_t) => nev_t.first(),
         }
     }
+
+    /// Returns a reference to the element if there is exactly one, and
+    /// `None` if there are several.
+    ///
+    /// Unlike [`OneToN::first`], this does not silently ignore further
+    /// elements, so callers notice when a payload is richer than expected.
+    ///
+    /// ```
+    /// use ivms101::OneToN;
+    ///
+    /// assert_eq!(OneToN::from(8).as_single(), Some(&8));
+    /// ```
+    pub fn as_single(&self) -> Option<&T> {
+        match self.iter().as_slice() {
+            [t] => Some(t),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of elements, which is at least one.
+    ///
+    /// ```
+    /// use ivms101::OneToN;
+    ///
+    /// assert_eq!(OneToN::from(8).len(), 1);
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        match self {
+            OneToN::One(_) => 1,
+            OneToN::N(nev_t) => nev_t.as_slice().len(),
+        }
+    }
+
+    /// Returns an iterator over references to the elements.
+    ///
+    /// ```
+    /// use ivms101::OneToN;
+    ///
+    /// assert_eq!(OneToN::from(8).iter().sum::<u8>(), 8);
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        match self {
+            OneToN::One(t) => std::slice::from_ref(t).iter(),
+            OneToN::N(nev_t) => nev_t.as_slice().iter(),
+        }
+    }
+
+    /// Returns an iterator over mutable references to the elements.
+    ///
+    /// ```
+    /// use ivms101::OneToN;
+    ///
+    /// let mut one = OneToN::from(8);
+    /// one.iter_mut().for_each(|e| *e += 1);
+    /// assert_eq!(one, OneToN::from(9));
+    /// ```
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        match self {
+            OneToN::One(t) => std::slice::from_mut(t).iter_mut(),
+            OneToN::N(nev_t) => nev_t.as_mut_slice().iter_mut(),
+        }
+    }
+
+    /// Returns a vector of clones of all elements.
+    ///
+    /// ```
+    /// use ivms101::OneToN;
+    ///
+    /// assert_eq!(OneToN::from(8).to_vec(), vec![8]);
+    /// ```
+    pub fn to_vec(&self) -> Vec<T> {
+        match self {
+            OneToN::One(t) => vec![t.clone()],
+            OneToN::N(nev_t) => nev_t.as_slice().to_vec(),
+        }
+    }
+
+    /// Returns the canonical representation, which stores a single
+    /// element as `OneToN::One`.
+    ///
+    /// ```
+    /// use ivms101::OneToN;
+    ///
+    /// assert_eq!(OneToN::N(8.into()).normalize(), OneToN::One(8));
+    /// ```
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        match self {
+            OneToN::N(nev) if nev.as_slice().len() == 1 => OneToN::One(nev.first().clone()),
+            one_to_n => one_to_n,
+        }
+    }
+
+    /// Combines the elements of both in order, skipping duplicates, and
+    /// returns the normalized result.
+    ///
+    /// ```
+    /// use ivms101::OneToN;
+    ///
+    /// let union = OneToN::from(1).union(OneToN::N(vec![1, 2].try_into().unwrap()));
+    /// assert_eq!(union, OneToN::N(vec![1, 2].try_into().unwrap()));
+    /// ```
+    #[must_use]
+    pub fn union(self, other: Self) -> Self
+    where
+        T: PartialEq,
+    {
+        let mut elements: Vec<T> = Vec::new();
+        for element in self.into_iter().chain(other) {
+            if !elements.contains(&element) {
+                elements.push(element);
+            }
+        }
+        OneToN::N(NonEmptyVec::try_from(elements).expect("the union is not empty")).normalize()
+    }
+
+    /// Deserializes a name identifier field from either a single object
+    /// or an array and normalizes it, such that both shapes result in the
+    /// same value and re-serialize identically.
+    pub(crate) fn deserialize_name_identifier<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+        T: serde::Deserialize<'de>,
+    {
+        Self::deserialize_field(deserializer, "nameIdentifier").map(Self::normalize)
+    }
+
+    /// Deserializes the required field `field`, naming it in the error
+    /// if it is an empty array.
+    pub(crate) fn deserialize_field<'de, D>(deserializer: D, field: &str) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+        T: serde::Deserialize<'de>,
+    {
+        Self::deserialize_repr(deserializer, field)?.ok_or_else(|| {
+            serde::de::Error::custom(format!("{field} must contain at least one element"))
+        })
+    }
+
+    /// Deserializes a `OneToN`, returning `None` for an empty array.
+    fn deserialize_repr<'de, D>(deserializer: D, field: &str) -> Result<Option<Self>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+        T: serde::Deserialize<'de>,
+    {
+        Ok(match OneOrMany::deserialize(deserializer, field, false)? {
+            OneOrMany::None => None,
+            OneOrMany::One(t) => Some(OneToN::One(t)),
+            OneOrMany::Many(v) => NonEmptyVec::try_from(v).ok().map(OneToN::N),
+        })
+    }
+}
+
+impl<'de, T: Clone + serde::Deserialize<'de>> serde::Deserialize<'de> for OneToN<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::deserialize_repr(deserializer, "collection")?
+            .ok_or_else(|| serde::de::Error::invalid_length(0, &"at least one element"))
+    }
+}
+
+/// Deserializers for the required fields of the data model, naming the
+/// field if it is an empty array.
+pub(crate) mod fields {
+    use super::OneToN;
+
+    macro_rules! required_field {
+        ($fn:ident, $field:literal) => {
+            pub(crate) fn $fn<'de, D, T>(deserializer: D) -> Result<OneToN<T>, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+                T: Clone + serde::Deserialize<'de>,
+            {
+                OneToN::deserialize_field(deserializer, $field)
+            }
+        };
+    }
+
+    required_field!(originator_persons, "originatorPersons");
+    required_field!(beneficiary_persons, "beneficiaryPersons");
+    required_field!(name, "name");
+}
+
+impl<T: Clone + serde::Serialize> serde::Serialize for OneToN<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            OneToN::One(t) => t.serialize(serializer),
+            OneToN::N(_) => serializer.collect_seq(self.iter()),
+        }
+    }
 }
 
 impl<T: Clone> From<T> for OneToN<T> {
@@ -68,7 +258,39 @@ mod tests {
         );
         serde_test::assert_de_tokens_error::<OneToN<u8>>(
             &[Token::Seq { len: None }, Token::SeqEnd],
-            "data did not match any variant of untagged enum OneToN",
+            "invalid length 0, expected at least one element",
+        );
+        serde_test::assert_de_tokens_error::<OneToN<u8>>(
+            &[Token::Str("a")],
+            "invalid type: string \"a\", expected u8",
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(OneToN::<u8>::One(1).normalize(), OneToN::One(1));
+        assert_eq!(OneToN::<u8>::N(1.into()).normalize(), OneToN::One(1));
+        let n = OneToN::<u8>::N(vec![1, 2].try_into().unwrap());
+        assert_eq!(n.clone().normalize(), n);
+    }
+
+    #[test]
+    fn test_as_single() {
+        assert_eq!(OneToN::One(1).as_single(), Some(&1));
+        assert_eq!(OneToN::<u8>::N(1.into()).as_single(), Some(&1));
+        assert_eq!(
+            OneToN::<u8>::N(vec![1, 2].try_into().unwrap()).as_single(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_to_vec() {
+        assert_eq!(OneToN::<u8>::One(1).to_vec(), vec![1]);
+        assert_eq!(OneToN::<u8>::N(1.into()).to_vec(), vec![1]);
+        assert_eq!(
+            OneToN::<u8>::N(vec![1, 2].try_into().unwrap()).to_vec(),
+            vec![1, 2]
         );
     }
 }
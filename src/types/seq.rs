@@ -0,0 +1,153 @@
+//! Serde helpers encoding [`ZeroToN`](crate::ZeroToN) and
+//! [`OneToN`](crate::OneToN) as plain sequences.
+//!
+//! The default representation of these types follows IVMS101 JSON, where
+//! a single element may be given as a scalar. Telling a scalar from a
+//! sequence requires a self-describing format, so the default
+//! representation cannot be deserialized from formats such as bincode.
+//!
+//! When embedding `ZeroToN` or `OneToN` in your own types that are stored
+//! in such formats, use these helpers, which always encode a
+//! length-prefixed sequence:
+//!
+//! ```
+//! use ivms101::ZeroToN;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Record {
+//!     #[serde(with = "ivms101::seq::zero_to_n")]
+//!     accounts: ZeroToN<String>,
+//! }
+//! ```
+//!
+//! Deserialization yields the normalized value, i.e. `None` for no
+//! element and `One` for a single element. The IVMS101 data model itself
+//! keeps the JSON representation, so exchange messages as JSON.
+
+/// Encodes a [`ZeroToN`](crate::ZeroToN) as a sequence.
+pub mod zero_to_n {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::ZeroToN;
+
+    /// Serializes the elements as a sequence.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serializer's error if an element fails to serialize.
+    pub fn serialize<T, S>(value: &ZeroToN<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        serializer.collect_seq(value.iter())
+    }
+
+    /// Deserializes a sequence of any length.
+    ///
+    /// # Errors
+    ///
+    /// Returns the deserializer's error if the input is not a sequence.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<ZeroToN<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Vec::deserialize(deserializer).map(|v| ZeroToN::N(v).normalize())
+    }
+}
+
+/// Encodes a [`OneToN`](crate::OneToN) as a sequence.
+pub mod one_to_n {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::types::non_empty_vec::NonEmptyVec;
+    use crate::OneToN;
+
+    /// Serializes the elements as a sequence.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serializer's error if an element fails to serialize.
+    pub fn serialize<T, S>(value: &OneToN<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Clone + Serialize,
+        S: Serializer,
+    {
+        serializer.collect_seq(value.iter())
+    }
+
+    /// Deserializes a non-empty sequence.
+    ///
+    /// # Errors
+    ///
+    /// Returns the deserializer's error if the input is not a sequence
+    /// or if it is empty.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<OneToN<T>, D::Error>
+    where
+        T: Clone + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let v = Vec::deserialize(deserializer)?;
+        NonEmptyVec::try_from(v)
+            .map(|nev| OneToN::N(nev).normalize())
+            .map_err(|_| serde::de::Error::invalid_length(0, &"at least one element"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{OneToN, ZeroToN};
+
+    #[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+    struct Record {
+        #[serde(with = "super::zero_to_n")]
+        zero_to_n: ZeroToN<u32>,
+        #[serde(with = "super::one_to_n")]
+        one_to_n: OneToN<String>,
+    }
+
+    fn round_trip(record: &Record) -> Record {
+        let bytes = bincode::serialize(record).unwrap();
+        bincode::deserialize(&bytes).unwrap()
+    }
+
+    #[test]
+    fn test_bincode_round_trip() {
+        for record in [
+            Record {
+                zero_to_n: ZeroToN::None,
+                one_to_n: OneToN::One("a".into()),
+            },
+            Record {
+                zero_to_n: ZeroToN::One(1),
+                one_to_n: OneToN::N(vec!["a".to_owned(), "b".to_owned()].try_into().unwrap()),
+            },
+            Record {
+                zero_to_n: ZeroToN::N(vec![1, 2]),
+                one_to_n: OneToN::One("a".into()),
+            },
+        ] {
+            assert_eq!(round_trip(&record), record);
+        }
+    }
+
+    #[test]
+    fn test_bincode_normalizes() {
+        let record = Record {
+            zero_to_n: ZeroToN::N(vec![1]),
+            one_to_n: OneToN::N("a".to_owned().into()),
+        };
+        let expected = Record {
+            zero_to_n: ZeroToN::One(1),
+            one_to_n: OneToN::One("a".into()),
+        };
+        assert_eq!(round_trip(&record), expected);
+    }
+
+    #[test]
+    fn test_bincode_rejects_empty_one_to_n() {
+        let bytes = bincode::serialize(&(Vec::<u32>::new(), Vec::<String>::new())).unwrap();
+        assert!(bincode::deserialize::<Record>(&bytes).is_err());
+    }
+}
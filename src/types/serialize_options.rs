@@ -0,0 +1,584 @@
+use std::fmt;
+
+use serde::ser::{
+    self, Impossible, Serialize, SerializeMap, SerializeSeq, SerializeStruct,
+    SerializeStructVariant, SerializeTuple, SerializeTupleStruct, SerializeTupleVariant,
+    Serializer,
+};
+
+/// Options controlling how [`crate::OneToN`] and [`crate::ZeroToN`]
+/// fields are serialized, see [`crate::IVMS101::to_json_with`].
+///
+/// By default, single elements are serialized as scalars and empty
+/// fields are omitted. The options apply to the fields of the data
+/// model, not to collections of other types.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SerializeOptions {
+    /// Serializes single elements as arrays of one element.
+    pub always_array: bool,
+    /// Serializes empty [`crate::ZeroToN`] fields as `[]` instead of
+    /// omitting them.
+    pub empty_as_array: bool,
+}
+
+/// The collections of the data model.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Collection {
+    OneToN,
+    ZeroToN,
+}
+
+/// Returns the kind of collection stored in the field `key` of the struct
+/// `container`, if any.
+fn collection(container: &str, key: &str) -> Option<Collection> {
+    match (container, key) {
+        ("Originator", "originatorPersons")
+        | ("Beneficiary", "beneficiaryPersons")
+        | ("NaturalPerson", "name")
+        | ("NaturalPersonName" | "LegalPersonName", "nameIdentifier") => Some(Collection::OneToN),
+        ("Originator" | "Beneficiary", "accountNumber")
+        | ("NaturalPerson" | "LegalPerson", "geographicAddress")
+        | (
+            "NaturalPersonName" | "LegalPersonName",
+            "localNameIdentifier" | "phoneticNameIdentifier",
+        )
+        | ("Address", "addressLine") => Some(Collection::ZeroToN),
+        _ => None,
+    }
+}
+
+impl SerializeOptions {
+    /// Serializes `value` to a JSON string with these options.
+    pub(crate) fn to_string<T: Serialize + ?Sized>(self, value: &T) -> String {
+        let mut json = Vec::new();
+        // The data model only contains string-keyed maps, so
+        // serialization cannot fail.
+        value
+            .serialize(Shaper {
+                inner: &mut serde_json::Serializer::new(&mut json),
+                options: self,
+            })
+            .expect("IVMS101 serializes to JSON");
+        String::from_utf8(json).expect("serde_json emits UTF-8")
+    }
+
+    /// Serializes `value` to a JSON value with these options.
+    pub(crate) fn to_value<T: Serialize + ?Sized>(self, value: &T) -> serde_json::Value {
+        value
+            .serialize(Shaper {
+                inner: serde_json::value::Serializer,
+                options: self,
+            })
+            .expect("IVMS101 serializes to JSON")
+    }
+}
+
+/// A value serialized with options.
+struct Shaped<'a, T: ?Sized> {
+    value: &'a T,
+    options: SerializeOptions,
+}
+
+impl<T: Serialize + ?Sized> Serialize for Shaped<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(Shaper {
+            inner: serializer,
+            options: self.options,
+        })
+    }
+}
+
+/// Wraps a serializer to shape the collections of the data model
+/// according to the options, which are passed down to nested values.
+struct Shaper<S> {
+    inner: S,
+    options: SerializeOptions,
+}
+
+impl<S> Shaper<S> {
+    fn shaped<'a, T: ?Sized>(&self, value: &'a T) -> Shaped<'a, T> {
+        Shaped {
+            value,
+            options: self.options,
+        }
+    }
+}
+
+macro_rules! forward {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method(self, $($arg: $ty),*) -> Result<Self::Ok, Self::Error> {
+                self.inner.$method($($arg),*)
+            }
+        )*
+    };
+}
+
+impl<S: Serializer> Serializer for Shaper<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = Compound<S::SerializeSeq>;
+    type SerializeTuple = Compound<S::SerializeTuple>;
+    type SerializeTupleStruct = Compound<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = Compound<S::SerializeTupleVariant>;
+    type SerializeMap = Compound<S::SerializeMap>;
+    type SerializeStruct = Compound<S::SerializeStruct>;
+    type SerializeStructVariant = Compound<S::SerializeStructVariant>;
+
+    forward! {
+        serialize_bool(v: bool);
+        serialize_i8(v: i8);
+        serialize_i16(v: i16);
+        serialize_i32(v: i32);
+        serialize_i64(v: i64);
+        serialize_i128(v: i128);
+        serialize_u8(v: u8);
+        serialize_u16(v: u16);
+        serialize_u32(v: u32);
+        serialize_u64(v: u64);
+        serialize_u128(v: u128);
+        serialize_f32(v: f32);
+        serialize_f64(v: f64);
+        serialize_char(v: char);
+        serialize_str(v: &str);
+        serialize_bytes(v: &[u8]);
+        serialize_none();
+        serialize_unit();
+        serialize_unit_struct(name: &'static str);
+        serialize_unit_variant(name: &'static str, index: u32, variant: &'static str);
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<S::Ok, S::Error> {
+        let value = self.shaped(value);
+        self.inner.serialize_some(&value)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        let value = self.shaped(value);
+        self.inner.serialize_newtype_struct(name, &value)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        let value = self.shaped(value);
+        self.inner
+            .serialize_newtype_variant(name, index, variant, &value)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, S::Error> {
+        Ok(Compound {
+            inner: self.inner.serialize_seq(len)?,
+            options: self.options,
+            container: "",
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, S::Error> {
+        Ok(Compound {
+            inner: self.inner.serialize_tuple(len)?,
+            options: self.options,
+            container: "",
+        })
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, S::Error> {
+        Ok(Compound {
+            inner: self.inner.serialize_tuple_struct(name, len)?,
+            options: self.options,
+            container: name,
+        })
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, S::Error> {
+        Ok(Compound {
+            inner: self
+                .inner
+                .serialize_tuple_variant(name, index, variant, len)?,
+            options: self.options,
+            container: variant,
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, S::Error> {
+        Ok(Compound {
+            inner: self.inner.serialize_map(len)?,
+            options: self.options,
+            container: "",
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, S::Error> {
+        Ok(Compound {
+            inner: self.inner.serialize_struct(name, len)?,
+            options: self.options,
+            container: name,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, S::Error> {
+        Ok(Compound {
+            inner: self
+                .inner
+                .serialize_struct_variant(name, index, variant, len)?,
+            options: self.options,
+            container: variant,
+        })
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+/// Wraps the serializer of a compound value, e.g. a struct, to shape its
+/// fields and pass the options down to its elements.
+struct Compound<C> {
+    inner: C,
+    options: SerializeOptions,
+    /// The name of the struct or variant, which determines the fields
+    /// holding collections.
+    container: &'static str,
+}
+
+impl<C> Compound<C> {
+    fn shaped<'a, T: ?Sized>(&self, value: &'a T) -> Shaped<'a, T> {
+        Shaped {
+            value,
+            options: self.options,
+        }
+    }
+}
+
+impl<C: SerializeSeq> SerializeSeq for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        let value = self.shaped(value);
+        self.inner.serialize_element(&value)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeTuple> SerializeTuple for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        let value = self.shaped(value);
+        self.inner.serialize_element(&value)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeTupleStruct> SerializeTupleStruct for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        let value = self.shaped(value);
+        self.inner.serialize_field(&value)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeTupleVariant> SerializeTupleVariant for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        let value = self.shaped(value);
+        self.inner.serialize_field(&value)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeMap> SerializeMap for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), C::Error> {
+        let key = self.shaped(key);
+        self.inner.serialize_key(&key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        let value = self.shaped(value);
+        self.inner.serialize_value(&value)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C> Compound<C> {
+    /// Indicates whether `value` of the field `key` is a single element
+    /// of a collection to be serialized as an array.
+    fn wraps<T: Serialize + ?Sized>(&self, key: &str, value: &T) -> bool {
+        self.options.always_array && collection(self.container, key).is_some() && !is_seq(value)
+    }
+
+    /// Indicates whether the skipped field `key` is an empty collection
+    /// to be serialized as `[]`.
+    fn empty_as_array(&self, key: &str) -> bool {
+        self.options.empty_as_array && collection(self.container, key) == Some(Collection::ZeroToN)
+    }
+}
+
+/// The value of empty collections serialized as `[]`.
+const EMPTY: [(); 0] = [];
+
+impl<C: SerializeStruct> SerializeStruct for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), C::Error> {
+        let wraps = self.wraps(key, value);
+        let value = self.shaped(value);
+        if wraps {
+            self.inner
+                .serialize_field(key, std::slice::from_ref(&value))
+        } else {
+            self.inner.serialize_field(key, &value)
+        }
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), C::Error> {
+        if self.empty_as_array(key) {
+            self.inner.serialize_field(key, &EMPTY[..])
+        } else {
+            self.inner.skip_field(key)
+        }
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeStructVariant> SerializeStructVariant for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), C::Error> {
+        let wraps = self.wraps(key, value);
+        let value = self.shaped(value);
+        if wraps {
+            self.inner
+                .serialize_field(key, std::slice::from_ref(&value))
+        } else {
+            self.inner.serialize_field(key, &value)
+        }
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), C::Error> {
+        if self.empty_as_array(key) {
+            self.inner.serialize_field(key, &EMPTY[..])
+        } else {
+            self.inner.skip_field(key)
+        }
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+/// Indicates whether `value` serializes as a sequence, which is
+/// determined by the first call to the serializer.
+fn is_seq<T: Serialize + ?Sized>(value: &T) -> bool {
+    matches!(value.serialize(Probe), Err(Probed(true)))
+}
+
+/// The outcome of probing a value, see [`is_seq`].
+#[derive(Debug)]
+struct Probed(bool);
+
+impl fmt::Display for Probed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "probed whether the value is a sequence")
+    }
+}
+
+impl std::error::Error for Probed {}
+
+impl ser::Error for Probed {
+    fn custom<T: fmt::Display>(_: T) -> Self {
+        Probed(false)
+    }
+}
+
+/// A serializer stopping at the first call, see [`is_seq`].
+struct Probe;
+
+macro_rules! not_seq {
+    ($($method:ident($($ty:ty),*);)*) => {
+        $(
+            fn $method(self, $(_: $ty),*) -> Result<(), Probed> {
+                Err(Probed(false))
+            }
+        )*
+    };
+}
+
+impl Serializer for Probe {
+    type Ok = ();
+    type Error = Probed;
+    type SerializeSeq = Impossible<(), Probed>;
+    type SerializeTuple = Impossible<(), Probed>;
+    type SerializeTupleStruct = Impossible<(), Probed>;
+    type SerializeTupleVariant = Impossible<(), Probed>;
+    type SerializeMap = Impossible<(), Probed>;
+    type SerializeStruct = Impossible<(), Probed>;
+    type SerializeStructVariant = Impossible<(), Probed>;
+
+    not_seq! {
+        serialize_bool(bool);
+        serialize_i8(i8);
+        serialize_i16(i16);
+        serialize_i32(i32);
+        serialize_i64(i64);
+        serialize_u8(u8);
+        serialize_u16(u16);
+        serialize_u32(u32);
+        serialize_u64(u64);
+        serialize_f32(f32);
+        serialize_f64(f64);
+        serialize_char(char);
+        serialize_str(&str);
+        serialize_bytes(&[u8]);
+        serialize_none();
+        serialize_unit();
+        serialize_unit_struct(&'static str);
+        serialize_unit_variant(&'static str, u32, &'static str);
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), Probed> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), Probed> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<(), Probed> {
+        Err(Probed(false))
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Probed> {
+        Err(Probed(true))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Probed> {
+        Err(Probed(false))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, Probed> {
+        Err(Probed(false))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, Probed> {
+        Err(Probed(false))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Probed> {
+        Err(Probed(false))
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeStruct, Probed> {
+        Err(Probed(false))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Probed> {
+        Err(Probed(false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_seq() {
+        assert!(is_seq(&vec![1, 2]));
+        assert!(is_seq(&crate::OneToN::from(vec![1, 2])));
+        assert!(!is_seq(&crate::OneToN::from(1)));
+        assert!(!is_seq(&Some("a")));
+    }
+}
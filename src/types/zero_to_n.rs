@@ -1,3 +1,5 @@
+use crate::types::one_or_many::OneOrMany;
+
 /// `ZeroToN` is a helper enum to accept an absent, singleton or list-enumerated
 /// field during deserialization. It is used in the following way:
 ///
@@ -18,8 +20,7 @@
 ///
 /// As a consequence of the usage of serde attributes, `ZeroToN` cannot be
 /// applied to the root deserialization object.
-#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
-#[serde(untagged)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub enum ZeroToN<T> {
     #[default]
     None,
@@ -60,6 +61,184 @@ impl<T> ZeroToN<T> {
             ZeroToN::N(v) => v.first(),
         }
     }
+
+    /// Returns a reference to the element if there is exactly one, and
+    /// `None` if there is none or several.
+    ///
+    /// Unlike [`ZeroToN::first`], this does not silently ignore further
+    /// elements, so callers notice when a payload is richer than expected.
+    ///
+    /// ```
+    /// use ivms101::ZeroToN;
+    ///
+    /// assert_eq!(ZeroToN::from(Some(8)).as_option(), Some(&8));
+    /// assert_eq!(ZeroToN::from(vec![8, 9]).as_option(), None);
+    /// ```
+    pub fn as_option(&self) -> Option<&T> {
+        match self.iter().as_slice() {
+            [t] => Some(t),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over references to the elements.
+    ///
+    /// ```
+    /// use ivms101::ZeroToN;
+    ///
+    /// assert_eq!(ZeroToN::from(vec![1, 2]).iter().sum::<u8>(), 3);
+    /// assert_eq!(ZeroToN::<u8>::from(None).iter().count(), 0);
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        match self {
+            ZeroToN::None => [].iter(),
+            ZeroToN::One(t) => std::slice::from_ref(t).iter(),
+            ZeroToN::N(v) => v.iter(),
+        }
+    }
+
+    /// Returns a vector of clones of all elements.
+    ///
+    /// ```
+    /// use ivms101::ZeroToN;
+    ///
+    /// assert_eq!(ZeroToN::from(Some(8)).to_vec(), vec![8]);
+    /// assert!(ZeroToN::<u8>::from(None).to_vec().is_empty());
+    /// ```
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        match self {
+            ZeroToN::None => vec![],
+            ZeroToN::One(t) => vec![t.clone()],
+            ZeroToN::N(v) => v.clone(),
+        }
+    }
+
+    /// Returns the canonical representation, which stores no element as
+    /// `ZeroToN::None` and a single element as `ZeroToN::One`.
+    ///
+    /// ```
+    /// use ivms101::ZeroToN;
+    ///
+    /// assert_eq!(ZeroToN::from(vec![8]).normalize(), ZeroToN::One(8));
+    /// assert_eq!(ZeroToN::<u8>::from(vec![]).normalize(), ZeroToN::None);
+    /// ```
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        match self {
+            ZeroToN::N(mut v) if v.len() <= 1 => v.pop().into(),
+            zero_to_n => zero_to_n,
+        }
+    }
+
+    /// Combines the elements of both in order, skipping duplicates, and
+    /// returns the normalized result.
+    ///
+    /// ```
+    /// use ivms101::ZeroToN;
+    ///
+    /// let union = ZeroToN::from(Some(1)).union(ZeroToN::from(vec![1, 2]));
+    /// assert_eq!(union, ZeroToN::from(vec![1, 2]));
+    /// ```
+    #[must_use]
+    pub fn union(self, other: Self) -> Self
+    where
+        T: PartialEq,
+    {
+        let mut elements: Vec<T> = Vec::new();
+        for element in self.into_iter().chain(other) {
+            if !elements.contains(&element) {
+                elements.push(element);
+            }
+        }
+        ZeroToN::N(elements).normalize()
+    }
+
+    /// Deserializes the field `field`, naming it in the error if it
+    /// cannot be deserialized.
+    pub(crate) fn deserialize_field<'de, D>(deserializer: D, field: &str) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+        T: serde::Deserialize<'de>,
+    {
+        Ok(match OneOrMany::deserialize(deserializer, field, true)? {
+            OneOrMany::None => ZeroToN::None,
+            OneOrMany::One(t) => ZeroToN::One(t),
+            OneOrMany::Many(v) => ZeroToN::N(v),
+        })
+    }
+}
+
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for ZeroToN<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::deserialize_field(deserializer, "collection")
+    }
+}
+
+/// Deserializers for the optional collections of the data model, naming
+/// the field in the error.
+pub(crate) mod fields {
+    use super::ZeroToN;
+    use crate::types::bounded_string::StringOrInteger;
+    use crate::types::StringMax100;
+
+    macro_rules! optional_field {
+        ($fn:ident, $field:literal) => {
+            optional_field!($fn, $field, |z| z);
+        };
+        ($fn:ident, $field:literal, $map:expr) => {
+            pub(crate) fn $fn<'de, D, T>(deserializer: D) -> Result<ZeroToN<T>, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+                T: serde::Deserialize<'de>,
+            {
+                ZeroToN::deserialize_field(deserializer, $field).map($map)
+            }
+        };
+    }
+
+    /// Account numbers given as JSON integers are accepted as well and
+    /// converted to strings, see [`StringOrInteger`].
+    pub(crate) fn account_number<'de, D>(deserializer: D) -> Result<ZeroToN<StringMax100>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(
+            match ZeroToN::<StringOrInteger<100>>::deserialize_field(deserializer, "accountNumber")?
+            {
+                ZeroToN::None => ZeroToN::None,
+                ZeroToN::One(account) => ZeroToN::One(account.0),
+                ZeroToN::N(accounts) => ZeroToN::N(accounts.into_iter().map(|a| a.0).collect()),
+            },
+        )
+    }
+
+    optional_field!(geographic_address, "geographicAddress");
+    optional_field!(address_line, "addressLine");
+    // Name identifiers given as a single object or an array are
+    // normalized, such that both shapes re-serialize identically.
+    optional_field!(
+        local_name_identifier,
+        "localNameIdentifier",
+        ZeroToN::normalize
+    );
+    optional_field!(
+        phonetic_name_identifier,
+        "phoneticNameIdentifier",
+        ZeroToN::normalize
+    );
+}
+
+impl<T: serde::Serialize> serde::Serialize for ZeroToN<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ZeroToN::None => serializer.serialize_unit(),
+            ZeroToN::One(t) => t.serialize(serializer),
+            ZeroToN::N(v) => v.serialize(serializer),
+        }
+    }
 }
 
 impl<T> IntoIterator for ZeroToN<T> {
@@ -135,4 +314,43 @@ mod tests {
             &[Token::Seq { len: None }, Token::SeqEnd],
         );
     }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(ZeroToN::<u8>::None.normalize(), ZeroToN::None);
+        assert_eq!(ZeroToN::<u8>::N(vec![]).normalize(), ZeroToN::None);
+        assert_eq!(ZeroToN::<u8>::N(vec![1]).normalize(), ZeroToN::One(1));
+        assert_eq!(
+            ZeroToN::<u8>::N(vec![1, 2]).normalize(),
+            ZeroToN::N(vec![1, 2])
+        );
+    }
+
+    #[test]
+    fn test_union() {
+        assert_eq!(
+            ZeroToN::One(2).union(ZeroToN::N(vec![1, 2, 3])),
+            ZeroToN::N(vec![2, 1, 3])
+        );
+        assert_eq!(ZeroToN::One(1).union(ZeroToN::N(vec![1])), ZeroToN::One(1));
+        assert_eq!(ZeroToN::<u8>::None.union(ZeroToN::N(vec![])), ZeroToN::None);
+        assert_eq!(ZeroToN::N(vec![1, 1]).union(ZeroToN::None), ZeroToN::One(1));
+    }
+
+    #[test]
+    fn test_as_option() {
+        assert_eq!(ZeroToN::<u8>::None.as_option(), None);
+        assert_eq!(ZeroToN::One(1).as_option(), Some(&1));
+        assert_eq!(ZeroToN::<u8>::N(vec![]).as_option(), None);
+        assert_eq!(ZeroToN::N(vec![1]).as_option(), Some(&1));
+        assert_eq!(ZeroToN::N(vec![1, 2]).as_option(), None);
+    }
+
+    #[test]
+    fn test_to_vec() {
+        assert!(ZeroToN::<u8>::None.to_vec().is_empty());
+        assert_eq!(ZeroToN::<u8>::One(1).to_vec(), vec![1]);
+        assert!(ZeroToN::<u8>::N(vec![]).to_vec().is_empty());
+        assert_eq!(ZeroToN::<u8>::N(vec![1, 2]).to_vec(), vec![1, 2]);
+    }
 }
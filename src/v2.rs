@@ -0,0 +1,600 @@
+//! A preview of the data model of the next major version, in which the
+//! fields are private.
+//!
+//! The types wrap those of the crate root and have the same accessors,
+//! such that code reading payloads through accessors carries over. They
+//! serialize and validate like the types they wrap. Until the next major
+//! version, values are constructed with the current data model and
+//! converted, which moves the value without copying it:
+//!
+//! ```
+//! use ivms101::{v2, NaturalPerson, Validatable};
+//!
+//! let current = NaturalPerson::new("John", "Doe", None, None).unwrap();
+//! let person = v2::NaturalPerson::from(current.clone());
+//! assert!(person.validate().is_ok());
+//! let name = person.names().next().unwrap().name_identifiers().next().unwrap();
+//! assert_eq!(name.primary_identifier(), "Doe");
+//! assert_eq!(NaturalPerson::from(person), current);
+//! ```
+
+use lei::registration_authority::RegistrationAuthority;
+
+use crate::{
+    AddressTypeCode, CountryCode, Error, LegalPersonNameTypeCode, NationalIdentifierTypeCode,
+    NaturalPersonNameTypeCode, PersonKind, Validatable, ValidationFailures, ValidationOptions,
+};
+
+/// Defines a wrapper of a type of the current data model, which converts
+/// from and to it and serializes like it.
+macro_rules! wrap {
+    ($($(#[$attr:meta])* $ty:ident;)*) => {
+        $(
+            $(#[$attr])*
+            #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+            #[serde(transparent)]
+            #[repr(transparent)]
+            pub struct $ty(crate::$ty);
+
+            impl $ty {
+                /// Borrows a value of the current data model as its wrapper.
+                #[allow(dead_code)]
+                fn wrap(inner: &crate::$ty) -> &Self {
+                    // SAFETY: `Self` is a `repr(transparent)` wrapper of
+                    // `crate::$ty`, so both have the same layout.
+                    unsafe { &*(inner as *const crate::$ty).cast::<Self>() }
+                }
+            }
+
+            impl From<crate::$ty> for $ty {
+                fn from(inner: crate::$ty) -> Self {
+                    Self(inner)
+                }
+            }
+
+            impl From<$ty> for crate::$ty {
+                fn from(wrapper: $ty) -> Self {
+                    wrapper.0
+                }
+            }
+        )*
+    };
+}
+
+/// Implements [`Validatable`] by validating the wrapped value.
+macro_rules! validate_wrapped {
+    ($($ty:ident),*) => {
+        $(
+            impl Validatable for $ty {
+                fn validate_with(&self, options: &ValidationOptions) -> Result<(), Error> {
+                    self.0.validate_with(options)
+                }
+
+                fn validate_all_with(
+                    &self,
+                    options: &ValidationOptions,
+                ) -> Result<(), ValidationFailures> {
+                    self.0.validate_all_with(options)
+                }
+            }
+        )*
+    };
+}
+
+wrap! {
+    /// The main IVMS101 data structure, see [`crate::IVMS101`].
+    #[derive(Default)]
+    IVMS101;
+    /// The transaction originator, see [`crate::Originator`].
+    Originator;
+    /// The transaction beneficiary, see [`crate::Beneficiary`].
+    Beneficiary;
+    /// The originating VASP, see [`crate::OriginatingVASP`].
+    OriginatingVASP;
+    /// The beneficiary VASP, see [`crate::BeneficiaryVASP`].
+    BeneficiaryVASP;
+    /// A natural or legal person, see [`crate::Person`].
+    Person;
+    /// A natural person, see [`crate::NaturalPerson`].
+    NaturalPerson;
+    /// The name of a natural person, see [`crate::NaturalPersonName`].
+    NaturalPersonName;
+    /// A name identifier of a natural person, see
+    /// [`crate::NaturalPersonNameID`].
+    NaturalPersonNameID;
+    /// A geographic address, see [`crate::Address`].
+    Address;
+    /// The date and place of birth, see [`crate::DateAndPlaceOfBirth`].
+    DateAndPlaceOfBirth;
+    /// A national identification, see [`crate::NationalIdentification`].
+    NationalIdentification;
+    /// A legal person, see [`crate::LegalPerson`].
+    LegalPerson;
+    /// The name of a legal person, see [`crate::LegalPersonName`].
+    LegalPersonName;
+    /// A name identifier of a legal person, see
+    /// [`crate::LegalPersonNameID`].
+    LegalPersonNameID;
+    /// An intermediary VASP, see [`crate::IntermediaryVASP`].
+    IntermediaryVASP;
+}
+
+validate_wrapped!(
+    IVMS101,
+    Originator,
+    Beneficiary,
+    OriginatingVASP,
+    BeneficiaryVASP,
+    Person,
+    NaturalPerson,
+    NaturalPersonName,
+    Address,
+    DateAndPlaceOfBirth,
+    LegalPerson,
+    LegalPersonName,
+    IntermediaryVASP
+);
+
+impl IVMS101 {
+    /// The originator of the transaction.
+    #[must_use]
+    pub fn originator(&self) -> Option<&Originator> {
+        self.0.originator().map(Originator::wrap)
+    }
+
+    /// The beneficiary of the transaction.
+    #[must_use]
+    pub fn beneficiary(&self) -> Option<&Beneficiary> {
+        self.0.beneficiary().map(Beneficiary::wrap)
+    }
+
+    /// The originating VASP.
+    #[must_use]
+    pub fn originating_vasp(&self) -> Option<&OriginatingVASP> {
+        self.0.originating_vasp().map(OriginatingVASP::wrap)
+    }
+
+    /// The beneficiary VASP.
+    #[must_use]
+    pub fn beneficiary_vasp(&self) -> Option<&BeneficiaryVASP> {
+        self.0.beneficiary_vasp().map(BeneficiaryVASP::wrap)
+    }
+}
+
+impl Originator {
+    /// Returns an iterator over the persons forming the originator.
+    pub fn persons(&self) -> impl Iterator<Item = &Person> + '_ {
+        self.0.persons().iter().map(Person::wrap)
+    }
+
+    /// Returns an iterator over the account numbers of the originator.
+    pub fn account_numbers(&self) -> impl Iterator<Item = &str> + '_ {
+        self.0.account_numbers()
+    }
+}
+
+impl Beneficiary {
+    /// Returns an iterator over the persons forming the beneficiary.
+    pub fn persons(&self) -> impl Iterator<Item = &Person> + '_ {
+        self.0.persons().iter().map(Person::wrap)
+    }
+
+    /// Returns an iterator over the account numbers of the beneficiary.
+    pub fn account_numbers(&self) -> impl Iterator<Item = &str> + '_ {
+        self.0.account_numbers()
+    }
+}
+
+impl OriginatingVASP {
+    /// The person of the originating VASP.
+    #[must_use]
+    pub fn person(&self) -> &Person {
+        Person::wrap(self.0.person())
+    }
+}
+
+impl BeneficiaryVASP {
+    /// The person of the beneficiary VASP, if known.
+    #[must_use]
+    pub fn person(&self) -> Option<&Person> {
+        self.0.person().map(Person::wrap)
+    }
+}
+
+impl Person {
+    /// Whether this is a natural or a legal person.
+    #[must_use]
+    pub fn kind(&self) -> PersonKind {
+        self.0.kind()
+    }
+
+    /// The natural person, if this is one.
+    #[must_use]
+    pub fn as_natural_person(&self) -> Option<&NaturalPerson> {
+        match &self.0 {
+            crate::Person::NaturalPerson(person) => Some(NaturalPerson::wrap(person)),
+            crate::Person::LegalPerson(_) => None,
+        }
+    }
+
+    /// The legal person, if this is one.
+    #[must_use]
+    pub fn as_legal_person(&self) -> Option<&LegalPerson> {
+        match &self.0 {
+            crate::Person::NaturalPerson(_) => None,
+            crate::Person::LegalPerson(person) => Some(LegalPerson::wrap(person)),
+        }
+    }
+}
+
+impl NaturalPerson {
+    /// Returns an iterator over the names.
+    pub fn names(&self) -> impl Iterator<Item = &NaturalPersonName> + '_ {
+        self.0.names().map(NaturalPersonName::wrap)
+    }
+
+    /// Returns an iterator over the geographic addresses.
+    pub fn addresses(&self) -> impl Iterator<Item = &Address> + '_ {
+        self.0.addresses().map(Address::wrap)
+    }
+
+    /// The national identification.
+    #[must_use]
+    pub fn national_identification(&self) -> Option<&NationalIdentification> {
+        self.0
+            .national_identification()
+            .map(NationalIdentification::wrap)
+    }
+
+    /// The customer identification.
+    #[must_use]
+    pub fn customer_identification(&self) -> Option<&str> {
+        self.0.customer_identification()
+    }
+
+    /// The date and place of birth.
+    #[must_use]
+    pub fn date_and_place_of_birth(&self) -> Option<&DateAndPlaceOfBirth> {
+        self.0
+            .date_and_place_of_birth()
+            .map(DateAndPlaceOfBirth::wrap)
+    }
+
+    /// The country of residence.
+    #[must_use]
+    pub fn country_of_residence(&self) -> Option<&CountryCode> {
+        self.0.country_of_residence()
+    }
+}
+
+impl NaturalPersonName {
+    /// Returns an iterator over the name identifiers.
+    pub fn name_identifiers(&self) -> impl Iterator<Item = &NaturalPersonNameID> + '_ {
+        self.0.name_identifiers().map(NaturalPersonNameID::wrap)
+    }
+
+    /// Returns an iterator over the local name identifiers.
+    pub fn local_name_identifiers(&self) -> impl Iterator<Item = &NaturalPersonNameID> + '_ {
+        self.0
+            .local_name_identifiers()
+            .map(NaturalPersonNameID::wrap)
+    }
+
+    /// Returns an iterator over the phonetic name identifiers.
+    pub fn phonetic_name_identifiers(&self) -> impl Iterator<Item = &NaturalPersonNameID> + '_ {
+        self.0
+            .phonetic_name_identifiers()
+            .map(NaturalPersonNameID::wrap)
+    }
+}
+
+impl NaturalPersonNameID {
+    /// The primary identifier, e.g. the family name.
+    #[must_use]
+    pub fn primary_identifier(&self) -> &str {
+        self.0.primary_identifier()
+    }
+
+    /// The secondary identifier, e.g. the given names.
+    #[must_use]
+    pub fn secondary_identifier(&self) -> Option<&str> {
+        self.0.secondary_identifier()
+    }
+
+    /// The type of the name.
+    #[must_use]
+    pub fn name_identifier_type(&self) -> &NaturalPersonNameTypeCode {
+        self.0.name_identifier_type()
+    }
+}
+
+impl Address {
+    /// The type of the address.
+    #[must_use]
+    pub fn address_type(&self) -> &AddressTypeCode {
+        self.0.address_type()
+    }
+
+    /// The department.
+    #[must_use]
+    pub fn department(&self) -> Option<&str> {
+        self.0.department()
+    }
+
+    /// The sub-department.
+    #[must_use]
+    pub fn sub_department(&self) -> Option<&str> {
+        self.0.sub_department()
+    }
+
+    /// The street name.
+    #[must_use]
+    pub fn street_name(&self) -> Option<&str> {
+        self.0.street_name()
+    }
+
+    /// The building number.
+    #[must_use]
+    pub fn building_number(&self) -> Option<&str> {
+        self.0.building_number()
+    }
+
+    /// The building name.
+    #[must_use]
+    pub fn building_name(&self) -> Option<&str> {
+        self.0.building_name()
+    }
+
+    /// The floor.
+    #[must_use]
+    pub fn floor(&self) -> Option<&str> {
+        self.0.floor()
+    }
+
+    /// The post box.
+    #[must_use]
+    pub fn post_box(&self) -> Option<&str> {
+        self.0.post_box()
+    }
+
+    /// The room.
+    #[must_use]
+    pub fn room(&self) -> Option<&str> {
+        self.0.room()
+    }
+
+    /// The postal code.
+    #[must_use]
+    pub fn post_code(&self) -> Option<&str> {
+        self.0.post_code()
+    }
+
+    /// The name of the town.
+    #[must_use]
+    pub fn town_name(&self) -> &str {
+        self.0.town_name()
+    }
+
+    /// The town location name.
+    #[must_use]
+    pub fn town_location_name(&self) -> Option<&str> {
+        self.0.town_location_name()
+    }
+
+    /// The district name.
+    #[must_use]
+    pub fn district_name(&self) -> Option<&str> {
+        self.0.district_name()
+    }
+
+    /// The country sub-division.
+    #[must_use]
+    pub fn country_sub_division(&self) -> Option<&str> {
+        self.0.country_sub_division()
+    }
+
+    /// Returns an iterator over the address lines.
+    pub fn address_line(&self) -> impl Iterator<Item = &str> + '_ {
+        self.0.address_line()
+    }
+
+    /// The country.
+    #[must_use]
+    pub fn country(&self) -> &CountryCode {
+        self.0.country()
+    }
+}
+
+impl DateAndPlaceOfBirth {
+    /// The date of birth.
+    #[must_use]
+    pub fn date_of_birth(&self) -> chrono::NaiveDate {
+        self.0.date_of_birth()
+    }
+
+    /// The place of birth.
+    #[must_use]
+    pub fn place_of_birth(&self) -> &str {
+        self.0.place_of_birth()
+    }
+}
+
+impl NationalIdentification {
+    /// The national identifier.
+    #[must_use]
+    pub fn national_identifier(&self) -> &str {
+        self.0.national_identifier()
+    }
+
+    /// The type of the national identifier.
+    #[must_use]
+    pub fn national_identifier_type(&self) -> &NationalIdentifierTypeCode {
+        self.0.national_identifier_type()
+    }
+
+    /// The country of issue.
+    #[must_use]
+    pub fn country_of_issue(&self) -> Option<&CountryCode> {
+        self.0.country_of_issue()
+    }
+
+    /// The registration authority.
+    #[must_use]
+    pub fn registration_authority(&self) -> Option<&RegistrationAuthority> {
+        self.0.registration_authority()
+    }
+}
+
+impl LegalPerson {
+    /// The name.
+    #[must_use]
+    pub fn name(&self) -> &LegalPersonName {
+        LegalPersonName::wrap(self.0.name())
+    }
+
+    /// Returns an iterator over the geographic addresses.
+    pub fn addresses(&self) -> impl Iterator<Item = &Address> + '_ {
+        self.0.addresses().map(Address::wrap)
+    }
+
+    /// The customer identification.
+    #[must_use]
+    pub fn customer_identification(&self) -> Option<&str> {
+        self.0.customer_identification()
+    }
+
+    /// The national identification.
+    #[must_use]
+    pub fn national_identification(&self) -> Option<&NationalIdentification> {
+        self.0
+            .national_identification()
+            .map(NationalIdentification::wrap)
+    }
+
+    /// The country of registration.
+    #[must_use]
+    pub fn country_of_registration(&self) -> Option<&CountryCode> {
+        self.0.country_of_registration()
+    }
+}
+
+impl LegalPersonName {
+    /// Returns an iterator over the name identifiers.
+    pub fn name_identifiers(&self) -> impl Iterator<Item = &LegalPersonNameID> + '_ {
+        self.0.name_identifiers().map(LegalPersonNameID::wrap)
+    }
+
+    /// Returns an iterator over the local name identifiers.
+    pub fn local_name_identifiers(&self) -> impl Iterator<Item = &LegalPersonNameID> + '_ {
+        self.0.local_name_identifiers().map(LegalPersonNameID::wrap)
+    }
+
+    /// Returns an iterator over the phonetic name identifiers.
+    pub fn phonetic_name_identifiers(&self) -> impl Iterator<Item = &LegalPersonNameID> + '_ {
+        self.0
+            .phonetic_name_identifiers()
+            .map(LegalPersonNameID::wrap)
+    }
+}
+
+impl LegalPersonNameID {
+    /// The name of the legal person.
+    #[must_use]
+    pub fn legal_person_name(&self) -> &str {
+        self.0.legal_person_name()
+    }
+
+    /// The type of the name.
+    #[must_use]
+    pub fn legal_person_name_identifier_type(&self) -> &LegalPersonNameTypeCode {
+        self.0.legal_person_name_identifier_type()
+    }
+}
+
+impl IntermediaryVASP {
+    /// The person of the intermediary VASP.
+    #[must_use]
+    pub fn person(&self) -> &Person {
+        Person::wrap(self.0.person())
+    }
+
+    /// The position of the VASP in the chain of intermediaries.
+    #[must_use]
+    pub fn sequence(&self) -> u32 {
+        self.0.sequence()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let current = crate::IVMS101::mock();
+        let ivms = IVMS101::from(current.clone());
+        assert_eq!(crate::IVMS101::from(ivms.clone()), current);
+        assert_eq!(
+            serde_json::to_value(&ivms).unwrap(),
+            serde_json::to_value(&current).unwrap()
+        );
+        let json = serde_json::to_string(&current).unwrap();
+        assert_eq!(serde_json::from_str::<IVMS101>(&json).unwrap(), ivms);
+
+        let vasp = crate::IntermediaryVASP::new(
+            "VASP C",
+            &lei::LEI::try_from("2594007XIACKNMUAW223").unwrap(),
+            1,
+        )
+        .unwrap();
+        assert_eq!(
+            crate::IntermediaryVASP::from(IntermediaryVASP::from(vasp.clone())),
+            vasp
+        );
+    }
+
+    #[test]
+    fn test_accessors() {
+        let ivms = IVMS101::from(crate::IVMS101::mock());
+        let originator = ivms.originator().unwrap();
+        assert!(originator.account_numbers().eq(["account-1"]));
+        let person = originator.persons().next().unwrap();
+        assert!(person.as_legal_person().is_none());
+        let person = person.as_natural_person().unwrap();
+        let id = person
+            .names()
+            .next()
+            .unwrap()
+            .name_identifiers()
+            .next()
+            .unwrap();
+        assert_eq!(id.primary_identifier(), "Engels");
+        assert_eq!(id.secondary_identifier(), Some("Friedrich"));
+        let address = person.addresses().next().unwrap();
+        assert_eq!(address.town_name(), "Zurich");
+        assert!(address.address_line().eq(["Main street"]));
+
+        let vasp = ivms.beneficiary_vasp().unwrap().person().unwrap();
+        let vasp = vasp.as_legal_person().unwrap();
+        let name = vasp.name().name_identifiers().next().unwrap();
+        assert_eq!(name.legal_person_name(), "VASP B");
+        assert_eq!(vasp.customer_identification(), Some("vasp-id"));
+        assert_eq!(
+            ivms.originating_vasp().unwrap().person().kind(),
+            PersonKind::Legal
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut current = crate::IVMS101::mock();
+        assert!(IVMS101::from(current.clone()).validate().is_ok());
+
+        *current.originator_person_mut() = crate::NaturalPerson::mock();
+        assert_eq!(
+            IVMS101::from(current.clone()).validate(),
+            current.validate()
+        );
+        assert!(IVMS101::from(current).validate().is_err());
+    }
+}
@@ -0,0 +1,532 @@
+use crate::problem::Violation;
+use crate::{Address, CountryCode, Error, Validatable, ZeroToN, IVMS101};
+
+/// The placeholder values rejected by [`ValidationLevel::Strict`]
+/// validation unless overridden in [`ValidationOptions::placeholders`].
+pub const DEFAULT_PLACEHOLDERS: &[&str] = &[
+    "N/A", "UNKNOWN", "NONE", "NULL", "XXX", "TBD", ".", "-", "?",
+];
+
+/// How rigorously data is checked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ValidationLevel {
+    /// Checks the IVMS101 constraints.
+    #[default]
+    Standard,
+    /// Checks the IVMS101 constraints and additionally rejects data
+    /// that is valid but useless, such as placeholder values.
+    Strict,
+}
+
+/// Options tweaking the rules applied by [`crate::Validatable::validate_with`].
+///
+/// The [`Default`] options apply the IVMS101 constraints as written.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationOptions {
+    /// Accepts an address consisting only of a post box, town and country
+    /// as complete with respect to C8. Some jurisdictions consider a post
+    /// box a deliverable address. Disabled by default, such that C8 applies
+    /// as written.
+    pub allow_post_box_only: bool,
+    /// Rejects addresses combining address lines with structured street
+    /// fields, see [`crate::Address::validate_no_mixing`].
+    pub reject_mixed_address: bool,
+    /// The validation level.
+    pub level: ValidationLevel,
+    /// Values which are rejected, ignoring case, as names, town names
+    /// and address lines by [`ValidationLevel::Strict`] validation.
+    /// Defaults to [`DEFAULT_PLACEHOLDERS`].
+    pub placeholders: Vec<String>,
+}
+
+impl Default for ValidationOptions {
+    fn default() -> Self {
+        Self {
+            allow_post_box_only: false,
+            reject_mixed_address: false,
+            level: ValidationLevel::default(),
+            placeholders: DEFAULT_PLACEHOLDERS.iter().map(|&p| p.into()).collect(),
+        }
+    }
+}
+
+impl ValidationOptions {
+    /// The default options at [`ValidationLevel::Strict`].
+    #[must_use]
+    pub fn strict() -> Self {
+        Self {
+            level: ValidationLevel::Strict,
+            ..Self::default()
+        }
+    }
+
+    /// Rejects placeholder values at the given field path when
+    /// validating strictly.
+    pub(crate) fn check_placeholder(&self, path: &str, value: &str) -> Result<(), Error> {
+        if self.level == ValidationLevel::Strict {
+            let value = value.trim();
+            let lowercase = value.to_lowercase();
+            if self
+                .placeholders
+                .iter()
+                .any(|p| p.to_lowercase() == lowercase)
+            {
+                return Err(
+                    Error::ValidationError(format!("Placeholder value '{value}'")).at(path),
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Checks that an IVMS101 constraint holds, failing with `message`
+/// suffixed by the constraint code otherwise.
+pub(crate) fn ensure(constraint: &'static str, holds: bool, message: &str) -> Result<(), Error> {
+    let result = if holds {
+        Ok(())
+    } else {
+        Err(Error::ValidationError(format!(
+            "{message} (IVMS101 {constraint})"
+        )))
+    };
+    traced(constraint, result)
+}
+
+/// Emits the outcome of checking an IVMS101 constraint as a `tracing`
+/// event if the `tracing` feature is enabled.
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+pub(crate) fn traced(constraint: &'static str, result: Result<(), Error>) -> Result<(), Error> {
+    #[cfg(feature = "tracing")]
+    match &result {
+        Ok(()) => tracing::trace!(constraint, "IVMS101 constraint satisfied"),
+        Err(error) => tracing::warn!(constraint, %error, "IVMS101 constraint violated"),
+    }
+    result
+}
+
+/// An advisory finding that does not violate an IVMS101 constraint
+/// but points at questionable data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Warning {
+    /// A legal person only has residential (`HOME`) addresses rather
+    /// than a geographic (`GEOG`) or business (`BIZZ`) one.
+    LegalPersonResidentialAddressOnly,
+    /// None of the addresses of a person is in its country of residence
+    /// or registration.
+    AddressCountryMismatch,
+    /// A name is given in a non-Latin script only, without a phonetic
+    /// name identifier that counterparties can match against.
+    NonLatinNameWithoutPhoneticName,
+    /// A natural person is identified by the number of a passport,
+    /// identity card or driver's license without its country of issue.
+    IdentityDocumentWithoutCountryOfIssue,
+    /// A passport number is not made of 5 to 15 letters and digits,
+    /// which all passport numbers are regardless of the issuing country.
+    MalformedPassportNumber,
+    /// The registration authority of a legal person maintains a register
+    /// in another country than its country of registration.
+    RegistrationAuthorityJurisdictionMismatch,
+}
+
+/// The outcome of [`IVMS101::check`]: the violated constraints as well as
+/// the advisory findings, both attributed to their field paths.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[must_use]
+pub struct ValidationReport {
+    errors: Vec<Error>,
+    warnings: Vec<(String, Warning)>,
+}
+
+impl ValidationReport {
+    /// Indicates whether no constraint is violated. There may still be
+    /// warnings.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// The violated constraints, see [`crate::Validatable::validate_all`].
+    pub fn errors(&self) -> &[Error] {
+        &self.errors
+    }
+
+    /// The advisory findings along with the path of the person they
+    /// apply to.
+    pub fn warnings(&self) -> impl Iterator<Item = (&str, &Warning)> {
+        self.warnings
+            .iter()
+            .map(|(path, warning)| (path.as_str(), warning))
+    }
+}
+
+impl IVMS101 {
+    /// Validates the payload and collects the advisory findings of all
+    /// persons.
+    pub fn check(&self) -> ValidationReport {
+        let errors = self.validate_all().err().map(Vec::from).unwrap_or_default();
+        let warnings = self
+            .persons_with_path()
+            .flat_map(|(path, person)| {
+                person
+                    .warnings()
+                    .into_iter()
+                    .map(move |w| (path.clone(), w))
+            })
+            .collect();
+        ValidationReport { errors, warnings }
+    }
+
+    /// Checks that the beneficiary has an account number if the
+    /// originator has one, as expected by some travel rule regimes.
+    ///
+    /// This is not an IVMS101 constraint and therefore not part of
+    /// [`Validatable::validate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::ValidationError`] at `beneficiary.accountNumber`
+    /// if only the originator has an account number.
+    pub fn validate_account_symmetry(&self) -> Result<(), Error> {
+        let originator_has_account = self
+            .originator()
+            .is_some_and(|o| !o.account_number.is_empty());
+        let beneficiary_has_account = self
+            .beneficiary()
+            .is_some_and(|b| !b.account_number.is_empty());
+        if originator_has_account && !beneficiary_has_account {
+            return Err(Error::from(
+                "Beneficiary must have an account number if the originator has one",
+            )
+            .at("beneficiary.accountNumber"));
+        }
+        Ok(())
+    }
+
+    /// Checks that the originating and the beneficiary VASP are not
+    /// identified by the same LEI, which almost always means that the
+    /// same VASP was filled in on both sides.
+    ///
+    /// This is not an IVMS101 constraint and therefore not part of
+    /// [`Validatable::validate`]. Missing or invalid LEIs pass this check.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::ValidationError`] at
+    /// `beneficiaryVASP.beneficiaryVASP` if both VASPs have the same LEI.
+    pub fn validate_distinct_vasps(&self) -> Result<(), Error> {
+        let originating = self.originating_vasp().and_then(|v| v.lei().ok().flatten());
+        let beneficiary = self
+            .beneficiary_vasp()
+            .and_then(|v| v.person())
+            .and_then(|p| p.lei().ok().flatten());
+        if originating.is_some() && originating == beneficiary {
+            return Err(Error::from(
+                "Beneficiary VASP must not have the same LEI as the originating VASP",
+            )
+            .at("beneficiaryVASP.beneficiaryVASP"));
+        }
+        Ok(())
+    }
+}
+
+/// Indicates whether there are addresses but none is in `country`.
+pub(crate) fn address_country_mismatch(
+    addresses: &ZeroToN<Address>,
+    country: Option<&CountryCode>,
+) -> bool {
+    country.is_some_and(|country| {
+        !addresses.is_empty() && addresses.iter().all(|addr| addr.country != *country)
+    })
+}
+
+/// Indicates whether `s` contains letters outside of the Latin script.
+pub(crate) fn is_non_latin(s: &str) -> bool {
+    s.chars().any(|c| {
+        c.is_alphabetic()
+            && !matches!(c, '\u{0}'..='\u{24f}' | '\u{1e00}'..='\u{1eff}' | '\u{2c60}'..='\u{2c7f}')
+    })
+}
+
+/// The errors reported by [`crate::Validatable::validate_all`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ValidationFailures(Vec<Error>);
+
+impl ValidationFailures {
+    /// The individual errors.
+    #[must_use]
+    pub fn errors(&self) -> &[Error] {
+        &self.0
+    }
+
+    /// Serializes the errors as a JSON array of their constraint, message
+    /// and path, as in [`crate::problem::ProblemDetails`].
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let violations: Vec<Violation> = self.0.iter().map(Violation::from).collect();
+        serde_json::to_string(&violations).expect("violations serialize to JSON")
+    }
+}
+
+impl std::ops::Deref for ValidationFailures {
+    type Target = [Error];
+
+    fn deref(&self) -> &[Error] {
+        &self.0
+    }
+}
+
+impl From<Vec<Error>> for ValidationFailures {
+    fn from(errors: Vec<Error>) -> Self {
+        Self(errors)
+    }
+}
+
+impl IntoIterator for ValidationFailures {
+    type Item = Error;
+    type IntoIter = std::vec::IntoIter<Error>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl From<ValidationFailures> for Vec<Error> {
+    fn from(failures: ValidationFailures) -> Self {
+        failures.0
+    }
+}
+
+impl From<ValidationFailures> for Error {
+    /// Keeps the first error, e.g. to report the failures with `?` in a
+    /// function returning [`Error`].
+    fn from(failures: ValidationFailures) -> Self {
+        failures
+            .0
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| Error::ValidationError("Validation failed".to_owned()))
+    }
+}
+
+/// Renders the errors for human review, grouped by the section they
+/// occurred in and with elements numbered from one:
+///
+/// ```text
+/// Originator:
+///   person 1: Validation error: ... (IVMS101 C1)
+/// Beneficiary:
+///   person 1, address 2: Validation error: ... (IVMS101 C8)
+/// ```
+impl std::fmt::Display for ValidationFailures {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut sections: Vec<(&str, Vec<&Error>)> = Vec::new();
+        for error in &self.0 {
+            let section = error
+                .path()
+                .map_or("", |path| path.split(['.', '[']).next().unwrap_or_default());
+            match sections.iter_mut().find(|(s, _)| *s == section) {
+                Some((_, errors)) => errors.push(error),
+                None => sections.push((section, vec![error])),
+            }
+        }
+        for (i, (section, errors)) in sections.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}:", section_title(section))?;
+            for error in errors {
+                write!(f, "\n  ")?;
+                let location = error
+                    .path()
+                    .and_then(|path| path.strip_prefix(section))
+                    .map(describe_location)
+                    .unwrap_or_default();
+                if !location.is_empty() {
+                    write!(f, "{location}: ")?;
+                }
+                write!(f, "{}", error.without_path())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn section_title(section: &str) -> &str {
+    match section {
+        "originator" => "Originator",
+        "beneficiary" => "Beneficiary",
+        "originatingVASP" => "Originating VASP",
+        "beneficiaryVASP" => "Beneficiary VASP",
+        "" => "Payload",
+        other => other,
+    }
+}
+
+/// Describes the path within a section for humans, e.g.
+/// `.originatorPersons[0].naturalPerson.geographicAddress[1]` as
+/// "person 1, address 2".
+fn describe_location(path: &str) -> String {
+    path.split('.')
+        .filter(|segment| !segment.is_empty())
+        .filter(|segment| !matches!(*segment, "naturalPerson" | "legalPerson"))
+        .map(|segment| {
+            let (field, index) = match segment.split_once('[') {
+                Some((field, index)) => (field, index.trim_end_matches(']').parse::<usize>().ok()),
+                None => (segment, None),
+            };
+            let label = match field {
+                "originatorPersons" | "beneficiaryPersons" => "person",
+                "geographicAddress" => "address",
+                "nameIdentifier" | "localNameIdentifier" | "phoneticNameIdentifier" => "name",
+                "accountNumber" => "account number",
+                "addressLine" => "address line",
+                other => other,
+            };
+            match index {
+                Some(index) => format!("{label} {}", index + 1),
+                None => label.to_owned(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl std::error::Error for ValidationFailures {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Person;
+
+    #[test]
+    fn test_validation_failures_report() {
+        let mut ivms = IVMS101::mock();
+        ivms.originator_person_mut().geographic_address = None.into();
+        let mut incomplete = Address::mock();
+        incomplete.address_line = None.into();
+        ivms.beneficiary_person_mut().geographic_address = vec![Address::mock(), incomplete].into();
+        let vasp = ivms.originating_vasp.as_mut().unwrap();
+        let Person::LegalPerson(vasp) = &mut vasp.originating_vasp else {
+            panic!("originating VASP is a legal person");
+        };
+        vasp.national_identification = None;
+
+        let failures = ivms.validate_all().unwrap_err();
+        assert_eq!(failures.len(), 3);
+        assert_eq!(
+            failures.to_string(),
+            "Originator:
+  person 1: Validation error: Natural person: one of 1) geographic address 2) customer id 3) national id 4) date and place of birth is required (IVMS101 C1)
+Beneficiary:
+  person 1, address 2: Validation error: Either 1) address line or 2) street name and either building name or building number are required (IVMS101 C8)
+Originating VASP:
+  Validation error: Legal person needs either geographic address, customer number or national identification (IVMS101 C4)"
+        );
+        assert_eq!(
+            failures.to_json(),
+            r#"[{"constraint":"C1","message":"Validation error: Natural person: one of 1) geographic address 2) customer id 3) national id 4) date and place of birth is required (IVMS101 C1)","path":"originator.originatorPersons[0]"},{"constraint":"C8","message":"Validation error: Either 1) address line or 2) street name and either building name or building number are required (IVMS101 C8)","path":"beneficiary.beneficiaryPersons[0].geographicAddress[1]"},{"constraint":"C4","message":"Validation error: Legal person needs either geographic address, customer number or national identification (IVMS101 C4)","path":"originatingVASP"}]"#
+        );
+        assert_eq!(
+            describe_location(".beneficiaryPersons[0].legalPerson.geographicAddress[1].country"),
+            "person 1, address 2, country"
+        );
+    }
+
+    #[test]
+    fn test_validate_account_symmetry() {
+        let mut ivms = IVMS101::mock();
+        ivms.validate_account_symmetry().unwrap();
+
+        ivms.beneficiary.as_mut().unwrap().account_number = ZeroToN::None;
+        let err = ivms.validate_account_symmetry().unwrap_err();
+        assert_eq!(err.path(), Some("beneficiary.accountNumber"));
+        ivms.validate().unwrap();
+
+        ivms.originator.as_mut().unwrap().account_number = ZeroToN::None;
+        ivms.validate_account_symmetry().unwrap();
+    }
+
+    #[test]
+    fn test_validate_distinct_vasps() {
+        let mut ivms = IVMS101::mock();
+        ivms.validate_distinct_vasps().unwrap();
+
+        let set_lei = |ivms: &mut IVMS101, lei: &str| {
+            let vasp = ivms.beneficiary_vasp.as_mut().unwrap();
+            let Some(Person::LegalPerson(vasp)) = &mut vasp.beneficiary_vasp else {
+                panic!("mock beneficiary VASP is a legal person");
+            };
+            vasp.customer_identification = None;
+            vasp.national_identification = Some((&lei::LEI::try_from(lei).unwrap()).into());
+        };
+        set_lei(&mut ivms, "549300MLUDYVRQOOXS22");
+        ivms.validate().unwrap();
+        ivms.validate_distinct_vasps().unwrap();
+
+        set_lei(&mut ivms, "2594007XIACKNMUAW223");
+        ivms.validate().unwrap();
+        let err = ivms.validate_distinct_vasps().unwrap_err();
+        assert_eq!(err.path(), Some("beneficiaryVASP.beneficiaryVASP"));
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_tracing() {
+        use std::sync::{Arc, Mutex};
+        use tracing::{field, span, Event, Level, Metadata};
+
+        /// Records the level and constraint of every event.
+        struct Capture(Arc<Mutex<Vec<(Level, String)>>>);
+
+        struct Constraint(String);
+
+        impl field::Visit for Constraint {
+            fn record_str(&mut self, field: &field::Field, value: &str) {
+                if field.name() == "constraint" {
+                    self.0 = value.into();
+                }
+            }
+
+            fn record_debug(&mut self, _: &field::Field, _: &dyn std::fmt::Debug) {}
+        }
+
+        impl tracing::Subscriber for Capture {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+                span::Id::from_u64(1)
+            }
+
+            fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+
+            fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                let mut constraint = Constraint(String::new());
+                event.record(&mut constraint);
+                let level = *event.metadata().level();
+                self.0.lock().unwrap().push((level, constraint.0));
+            }
+
+            fn enter(&self, _: &span::Id) {}
+
+            fn exit(&self, _: &span::Id) {}
+        }
+
+        let mut person = crate::LegalPerson::mock();
+        let mut id = crate::NationalIdentification::mock();
+        id.national_identifier_type = crate::NationalIdentifierTypeCode::PassportNumber;
+        person.national_identification = Some(id);
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let result =
+            tracing::subscriber::with_default(Capture(events.clone()), || person.validate());
+        assert_eq!(result.unwrap_err().constraint(), Some("C7"));
+        let events = events.lock().unwrap();
+        assert!(events.contains(&(Level::TRACE, "C4".into())));
+        assert_eq!(events.last(), Some(&(Level::WARN, "C7".into())));
+    }
+}
@@ -0,0 +1,214 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    Address, LegalPerson, NationalIdentification, NaturalPerson, Person, PersonKind, IVMS101,
+};
+
+/// The section of an IVMS101 message a person appears in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PartyRole {
+    /// One of the originator persons.
+    Originator,
+    /// One of the beneficiary persons.
+    Beneficiary,
+    /// The originating VASP.
+    OriginatingVASP,
+    /// The beneficiary VASP.
+    BeneficiaryVASP,
+}
+
+impl PartyRole {
+    /// Indicates whether the role is that of a VASP, which is always a
+    /// legal person.
+    #[must_use]
+    pub fn is_vasp(self) -> bool {
+        matches!(self, Self::OriginatingVASP | Self::BeneficiaryVASP)
+    }
+}
+
+/// A visitor of the persons in an IVMS101 message and their parts,
+/// driven by [`IVMS101::accept`] or [`Person::accept`].
+///
+/// All methods do nothing by default, so implementors only need to
+/// override those they are interested in.
+pub trait PersonVisitor {
+    /// Visits a natural person.
+    fn visit_natural(&mut self, _person: &NaturalPerson) {}
+
+    /// Visits a legal person.
+    fn visit_legal(&mut self, _person: &LegalPerson) {}
+
+    /// Visits an address of a natural or legal person.
+    fn visit_address(&mut self, _address: &Address) {}
+
+    /// Visits the national identification of a natural or legal person.
+    fn visit_national_identification(&mut self, _identification: &NationalIdentification) {}
+}
+
+impl IVMS101 {
+    /// Walks all persons of the message in the order originator,
+    /// beneficiary, originating VASP and beneficiary VASP.
+    pub fn accept(&self, visitor: &mut impl PersonVisitor) {
+        for (_, person) in self.persons_with_role() {
+            person.accept(visitor);
+        }
+    }
+
+    /// Returns the number of persons in the message, including the VASPs,
+    /// without allocating.
+    #[must_use]
+    pub fn person_count(&self) -> usize {
+        let mut counter = Counter::default();
+        self.accept(&mut counter);
+        counter.persons
+    }
+
+    /// Returns an iterator over all persons of the message along with
+    /// their role, in the order originator, beneficiary, originating VASP
+    /// and beneficiary VASP.
+    pub fn persons_with_role(&self) -> impl Iterator<Item = (PartyRole, &Person)> + '_ {
+        let originators = self
+            .originator()
+            .into_iter()
+            .flat_map(|o| o.originator_persons.iter())
+            .map(|p| (PartyRole::Originator, p));
+        let beneficiaries = self
+            .beneficiary()
+            .into_iter()
+            .flat_map(|b| b.beneficiary_persons.iter())
+            .map(|p| (PartyRole::Beneficiary, p));
+        let originating_vasp = self
+            .originating_vasp()
+            .map(|v| (PartyRole::OriginatingVASP, &v.originating_vasp));
+        let beneficiary_vasp = self
+            .beneficiary_vasp()
+            .and_then(|v| v.beneficiary_vasp.as_ref())
+            .map(|p| (PartyRole::BeneficiaryVASP, p));
+        originators
+            .chain(beneficiaries)
+            .chain(originating_vasp)
+            .chain(beneficiary_vasp)
+    }
+
+    /// Returns an iterator over all persons of the message along with
+    /// their field path, e.g. `beneficiary.beneficiaryPersons[1]`,
+    /// in the order of [`IVMS101::persons_with_role`].
+    pub(crate) fn persons_with_path(&self) -> impl Iterator<Item = (String, &Person)> + '_ {
+        let (mut originators, mut beneficiaries) = (0, 0);
+        self.persons_with_role().map(move |(role, person)| {
+            let path = match role {
+                PartyRole::Originator => {
+                    originators += 1;
+                    format!("originator.originatorPersons[{}]", originators - 1)
+                }
+                PartyRole::Beneficiary => {
+                    beneficiaries += 1;
+                    format!("beneficiary.beneficiaryPersons[{}]", beneficiaries - 1)
+                }
+                PartyRole::OriginatingVASP => "originatingVASP.originatingVASP".into(),
+                PartyRole::BeneficiaryVASP => "beneficiaryVASP.beneficiaryVASP".into(),
+            };
+            (path, person)
+        })
+    }
+
+    /// Indicates whether the message contains a legal person, e.g. to
+    /// route corporate transfers. The VASPs, which are legal persons,
+    /// only count if `include_vasps` is set.
+    #[must_use]
+    pub fn has_legal_person(&self, include_vasps: bool) -> bool {
+        self.has_person_of_kind(PersonKind::Legal, include_vasps)
+    }
+
+    /// Indicates whether the message contains a natural person. The
+    /// VASPs only count if `include_vasps` is set.
+    #[must_use]
+    pub fn has_natural_person(&self, include_vasps: bool) -> bool {
+        self.has_person_of_kind(PersonKind::Natural, include_vasps)
+    }
+
+    fn has_person_of_kind(&self, kind: PersonKind, include_vasps: bool) -> bool {
+        self.persons_with_role()
+            .any(|(role, person)| (include_vasps || !role.is_vasp()) && person.kind() == kind)
+    }
+
+    /// Indicates whether any originator person is a legal person.
+    #[must_use]
+    pub fn originator_is_corporate(&self) -> bool {
+        self.persons_with_role().any(|(role, person)| {
+            role == PartyRole::Originator && person.kind() == PersonKind::Legal
+        })
+    }
+
+    /// Indicates whether any beneficiary person is a legal person.
+    #[must_use]
+    pub fn beneficiary_is_corporate(&self) -> bool {
+        self.persons_with_role().any(|(role, person)| {
+            role == PartyRole::Beneficiary && person.kind() == PersonKind::Legal
+        })
+    }
+
+    /// Returns the number of persons by role and kind. Combinations
+    /// without persons are omitted.
+    #[must_use]
+    pub fn person_count_by_role(&self) -> BTreeMap<(PartyRole, PersonKind), usize> {
+        let mut counts = BTreeMap::new();
+        for (role, person) in self.persons_with_role() {
+            *counts.entry((role, person.kind())).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Returns the number of addresses of all persons in the message,
+    /// without allocating.
+    #[must_use]
+    pub fn address_count(&self) -> usize {
+        let mut counter = Counter::default();
+        self.accept(&mut counter);
+        counter.addresses
+    }
+}
+
+/// Counts the persons and addresses visited.
+#[derive(Default)]
+struct Counter {
+    persons: usize,
+    addresses: usize,
+}
+
+impl PersonVisitor for Counter {
+    fn visit_natural(&mut self, _person: &NaturalPerson) {
+        self.persons += 1;
+    }
+
+    fn visit_legal(&mut self, _person: &LegalPerson) {
+        self.persons += 1;
+    }
+
+    fn visit_address(&mut self, _address: &Address) {
+        self.addresses += 1;
+    }
+}
+
+impl Person {
+    /// Walks the person, followed by its addresses and its national
+    /// identification.
+    pub fn accept(&self, visitor: &mut impl PersonVisitor) {
+        let (addresses, national_identification) = match self {
+            Self::NaturalPerson(p) => {
+                visitor.visit_natural(p);
+                (&p.geographic_address, &p.national_identification)
+            }
+            Self::LegalPerson(p) => {
+                visitor.visit_legal(p);
+                (&p.geographic_address, &p.national_identification)
+            }
+        };
+        for address in addresses.iter() {
+            visitor.visit_address(address);
+        }
+        if let Some(ni) = national_identification {
+            visitor.visit_national_identification(ni);
+        }
+    }
+}
@@ -0,0 +1,776 @@
+//! A protobuf encoding of the data model for long-term archival,
+//! independent of the JSON representation.
+//!
+//! The messages mirror the IVMS101 structures field by field. Their field
+//! numbers are frozen: fields are only ever added with new numbers, and
+//! the numbers of removed fields are not reused. Type codes such as
+//! `LEGL`, country codes and dates (`YYYY-MM-DD`) are encoded as strings.
+//! Single elements and empty fields are encoded like any other repeated
+//! field, so decoding yields the canonical shape, see
+//! [`crate::OneToN::normalize`].
+//!
+//! Unknown fields, e.g. added by a later version, are skipped when
+//! decoding.
+//!
+//! ```
+//! use ivms101::{wire, IVMS101};
+//! use prost::Message;
+//!
+//! let ivms = IVMS101::from_json("{}").unwrap();
+//! let bytes = wire::Ivms101::from(&ivms).encode_to_vec();
+//! let decoded = IVMS101::try_from(wire::Ivms101::decode(bytes.as_slice()).unwrap());
+//! assert_eq!(decoded, Ok(ivms));
+//! ```
+
+use serde::de::value::{BorrowedStrDeserializer, Error as DeError};
+use serde::Deserialize;
+
+use crate::{Error, OneToN, ZeroToN};
+
+/// The IVMS101 message, see [`crate::IVMS101`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Ivms101 {
+    #[prost(message, optional, tag = "1")]
+    pub originator: Option<Originator>,
+    #[prost(message, optional, tag = "2")]
+    pub beneficiary: Option<Beneficiary>,
+    #[prost(message, optional, tag = "3")]
+    pub originating_vasp: Option<OriginatingVasp>,
+    #[prost(message, optional, tag = "4")]
+    pub beneficiary_vasp: Option<BeneficiaryVasp>,
+}
+
+/// See [`crate::Originator`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Originator {
+    #[prost(message, repeated, tag = "1")]
+    pub originator_persons: Vec<Person>,
+    #[prost(string, repeated, tag = "2")]
+    pub account_number: Vec<String>,
+}
+
+/// See [`crate::Beneficiary`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Beneficiary {
+    #[prost(message, repeated, tag = "1")]
+    pub beneficiary_persons: Vec<Person>,
+    #[prost(string, repeated, tag = "2")]
+    pub account_number: Vec<String>,
+}
+
+/// See [`crate::OriginatingVASP`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct OriginatingVasp {
+    #[prost(message, optional, tag = "1")]
+    pub originating_vasp: Option<Person>,
+}
+
+/// See [`crate::BeneficiaryVASP`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct BeneficiaryVasp {
+    #[prost(message, optional, tag = "1")]
+    pub beneficiary_vasp: Option<Person>,
+}
+
+/// See [`crate::Person`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Person {
+    #[prost(oneof = "PersonKind", tags = "1, 2")]
+    pub kind: Option<PersonKind>,
+}
+
+/// The variants of a [`Person`].
+#[derive(Clone, PartialEq, prost::Oneof)]
+pub enum PersonKind {
+    #[prost(message, tag = "1")]
+    NaturalPerson(NaturalPerson),
+    #[prost(message, tag = "2")]
+    LegalPerson(LegalPerson),
+}
+
+/// See [`crate::NaturalPerson`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct NaturalPerson {
+    #[prost(message, repeated, tag = "1")]
+    pub name: Vec<NaturalPersonName>,
+    #[prost(message, repeated, tag = "2")]
+    pub geographic_address: Vec<Address>,
+    #[prost(message, optional, tag = "3")]
+    pub national_identification: Option<NationalIdentification>,
+    #[prost(string, optional, tag = "4")]
+    pub customer_identification: Option<String>,
+    #[prost(message, optional, tag = "5")]
+    pub date_and_place_of_birth: Option<DateAndPlaceOfBirth>,
+    #[prost(string, optional, tag = "6")]
+    pub country_of_residence: Option<String>,
+}
+
+/// See [`crate::NaturalPersonName`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct NaturalPersonName {
+    #[prost(message, repeated, tag = "1")]
+    pub name_identifier: Vec<NaturalPersonNameId>,
+    #[prost(message, repeated, tag = "2")]
+    pub local_name_identifier: Vec<NaturalPersonNameId>,
+    #[prost(message, repeated, tag = "3")]
+    pub phonetic_name_identifier: Vec<NaturalPersonNameId>,
+}
+
+/// See [`crate::NaturalPersonNameID`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct NaturalPersonNameId {
+    #[prost(string, tag = "1")]
+    pub primary_identifier: String,
+    #[prost(string, optional, tag = "2")]
+    pub secondary_identifier: Option<String>,
+    #[prost(string, tag = "3")]
+    pub name_identifier_type: String,
+}
+
+/// See [`crate::Address`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Address {
+    #[prost(string, tag = "1")]
+    pub address_type: String,
+    #[prost(string, optional, tag = "2")]
+    pub department: Option<String>,
+    #[prost(string, optional, tag = "3")]
+    pub sub_department: Option<String>,
+    #[prost(string, optional, tag = "4")]
+    pub street_name: Option<String>,
+    #[prost(string, optional, tag = "5")]
+    pub building_number: Option<String>,
+    #[prost(string, optional, tag = "6")]
+    pub building_name: Option<String>,
+    #[prost(string, optional, tag = "7")]
+    pub floor: Option<String>,
+    #[prost(string, optional, tag = "8")]
+    pub post_box: Option<String>,
+    #[prost(string, optional, tag = "9")]
+    pub room: Option<String>,
+    #[prost(string, optional, tag = "10")]
+    pub post_code: Option<String>,
+    #[prost(string, tag = "11")]
+    pub town_name: String,
+    #[prost(string, optional, tag = "12")]
+    pub town_location_name: Option<String>,
+    #[prost(string, optional, tag = "13")]
+    pub district_name: Option<String>,
+    #[prost(string, optional, tag = "14")]
+    pub country_sub_division: Option<String>,
+    #[prost(string, repeated, tag = "15")]
+    pub address_line: Vec<String>,
+    #[prost(string, tag = "16")]
+    pub country: String,
+}
+
+/// See [`crate::NationalIdentification`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct NationalIdentification {
+    #[prost(string, tag = "1")]
+    pub national_identifier: String,
+    #[prost(string, tag = "2")]
+    pub national_identifier_type: String,
+    #[prost(string, optional, tag = "3")]
+    pub country_of_issue: Option<String>,
+    #[prost(string, optional, tag = "4")]
+    pub registration_authority: Option<String>,
+}
+
+/// See [`crate::DateAndPlaceOfBirth`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct DateAndPlaceOfBirth {
+    #[prost(string, tag = "1")]
+    pub date_of_birth: String,
+    #[prost(string, tag = "2")]
+    pub place_of_birth: String,
+}
+
+/// See [`crate::LegalPerson`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct LegalPerson {
+    #[prost(message, optional, tag = "1")]
+    pub name: Option<LegalPersonName>,
+    #[prost(message, repeated, tag = "2")]
+    pub geographic_address: Vec<Address>,
+    #[prost(string, optional, tag = "3")]
+    pub customer_identification: Option<String>,
+    #[prost(message, optional, tag = "4")]
+    pub national_identification: Option<NationalIdentification>,
+    #[prost(string, optional, tag = "5")]
+    pub country_of_registration: Option<String>,
+}
+
+/// See [`crate::LegalPersonName`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct LegalPersonName {
+    #[prost(message, repeated, tag = "1")]
+    pub name_identifier: Vec<LegalPersonNameId>,
+    #[prost(message, repeated, tag = "2")]
+    pub local_name_identifier: Vec<LegalPersonNameId>,
+    #[prost(message, repeated, tag = "3")]
+    pub phonetic_name_identifier: Vec<LegalPersonNameId>,
+}
+
+/// See [`crate::LegalPersonNameID`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct LegalPersonNameId {
+    #[prost(string, tag = "1")]
+    pub legal_person_name: String,
+    #[prost(string, tag = "2")]
+    pub legal_person_name_identifier_type: String,
+}
+
+/// Encodes a value serializing as a string, such as a type code.
+fn encode<T: serde::Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(s)) => s,
+        _ => unreachable!("value serializes as a string"),
+    }
+}
+
+/// Decodes a value from its string encoding, applying the same checks
+/// as when parsing JSON.
+fn decode_str<'a, T: Deserialize<'a>>(s: &'a str) -> Result<T, Error> {
+    T::deserialize(BorrowedStrDeserializer::<DeError>::new(s))
+        .map_err(|e| Error::ParseError(e.to_string()))
+}
+
+/// Decodes the value of `field` like [`decode_str`], attributing errors
+/// to the field.
+fn decode<'a, T: Deserialize<'a>>(field: &str, s: &'a str) -> Result<T, Error> {
+    decode_str(s).map_err(|e| e.at(field))
+}
+
+fn decode_opt<'a, T: Deserialize<'a>>(
+    field: &str,
+    s: &'a Option<String>,
+) -> Result<Option<T>, Error> {
+    s.as_deref().map(|s| decode(field, s)).transpose()
+}
+
+fn encode_opt<T: std::fmt::Display>(value: &Option<T>) -> Option<String> {
+    value.as_ref().map(ToString::to_string)
+}
+
+fn encode_all<T, U>(values: impl IntoIterator<Item = T>, f: impl Fn(T) -> U) -> Vec<U> {
+    values.into_iter().map(f).collect()
+}
+
+/// Decodes the elements of `field`, attributing errors to the index of
+/// the element, e.g. `addressLine[1]`.
+fn decode_all<T, U>(
+    field: &str,
+    values: Vec<U>,
+    f: impl Fn(U) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    values
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| f(value).map_err(|e| e.at(&format!("{field}[{i}]"))))
+        .collect()
+}
+
+fn decode_zero_to_n<T, U>(
+    field: &str,
+    values: Vec<U>,
+    f: impl Fn(U) -> Result<T, Error>,
+) -> Result<ZeroToN<T>, Error> {
+    Ok(ZeroToN::N(decode_all(field, values, f)?).normalize())
+}
+
+fn decode_one_to_n<T: Clone, U>(
+    field: &str,
+    values: Vec<U>,
+    f: impl Fn(U) -> Result<T, Error>,
+) -> Result<OneToN<T>, Error> {
+    let values = decode_all(field, values, f)?.try_into().map_err(|_| {
+        Error::ParseError(format!("{field} must contain at least one element")).at(field)
+    })?;
+    Ok(OneToN::N(values).normalize())
+}
+
+fn encode_strings<T: std::fmt::Display>(values: &ZeroToN<T>) -> Vec<String> {
+    encode_all(values.iter(), ToString::to_string)
+}
+
+impl From<&crate::IVMS101> for Ivms101 {
+    fn from(ivms: &crate::IVMS101) -> Self {
+        Self {
+            originator: ivms.originator().map(|o| Originator {
+                originator_persons: encode_all(o.originator_persons.iter(), Person::from),
+                account_number: encode_strings(&o.account_number),
+            }),
+            beneficiary: ivms.beneficiary().map(|b| Beneficiary {
+                beneficiary_persons: encode_all(b.beneficiary_persons.iter(), Person::from),
+                account_number: encode_strings(&b.account_number),
+            }),
+            originating_vasp: ivms.originating_vasp().map(|v| OriginatingVasp {
+                originating_vasp: Some((&v.originating_vasp).into()),
+            }),
+            beneficiary_vasp: ivms.beneficiary_vasp().map(|v| BeneficiaryVasp {
+                beneficiary_vasp: v.beneficiary_vasp.as_ref().map(Person::from),
+            }),
+        }
+    }
+}
+
+impl TryFrom<Ivms101> for crate::IVMS101 {
+    type Error = Error;
+
+    /// Converts a decoded message into the data model, checking the
+    /// field lengths, codes and cardinalities as when parsing JSON. The
+    /// IVMS101 constraints are not validated.
+    fn try_from(ivms: Ivms101) -> Result<Self, Error> {
+        Ok(Self {
+            originator: ivms
+                .originator
+                .map(|o| {
+                    Ok::<_, Error>(Box::new(crate::Originator {
+                        originator_persons: decode_one_to_n(
+                            "originatorPersons",
+                            o.originator_persons,
+                            crate::Person::try_from,
+                        )?,
+                        account_number: decode_zero_to_n("accountNumber", o.account_number, |a| {
+                            decode_str(&a)
+                        })?,
+                    }))
+                })
+                .transpose()
+                .map_err(|e| e.at("originator"))?,
+            beneficiary: ivms
+                .beneficiary
+                .map(|b| {
+                    Ok::<_, Error>(Box::new(crate::Beneficiary {
+                        beneficiary_persons: decode_one_to_n(
+                            "beneficiaryPersons",
+                            b.beneficiary_persons,
+                            crate::Person::try_from,
+                        )?,
+                        account_number: decode_zero_to_n("accountNumber", b.account_number, |a| {
+                            decode_str(&a)
+                        })?,
+                    }))
+                })
+                .transpose()
+                .map_err(|e| e.at("beneficiary"))?,
+            originating_vasp: ivms
+                .originating_vasp
+                .map(|v| {
+                    let person = v.originating_vasp.ok_or_else(|| {
+                        Error::ParseError("missing field `originatingVASP`".into())
+                    })?;
+                    Ok::<_, Error>(Box::new(crate::OriginatingVASP {
+                        originating_vasp: person
+                            .try_into()
+                            .map_err(|e: Error| e.at("originatingVASP"))?,
+                    }))
+                })
+                .transpose()
+                .map_err(|e| e.at("originatingVASP"))?,
+            beneficiary_vasp: ivms
+                .beneficiary_vasp
+                .map(|v| {
+                    Ok::<_, Error>(Box::new(crate::BeneficiaryVASP {
+                        beneficiary_vasp: v
+                            .beneficiary_vasp
+                            .map(TryInto::try_into)
+                            .transpose()
+                            .map_err(|e: Error| e.at("beneficiaryVASP"))?,
+                    }))
+                })
+                .transpose()
+                .map_err(|e| e.at("beneficiaryVASP"))?,
+        })
+    }
+}
+
+impl From<&crate::Person> for Person {
+    fn from(person: &crate::Person) -> Self {
+        let kind = match person {
+            crate::Person::NaturalPerson(p) => PersonKind::NaturalPerson(NaturalPerson {
+                name: encode_all(p.name.iter(), NaturalPersonName::from),
+                geographic_address: encode_all(p.geographic_address.iter(), Address::from),
+                national_identification: p.national_identification.as_ref().map(Into::into),
+                customer_identification: encode_opt(&p.customer_identification),
+                date_and_place_of_birth: p.date_and_place_of_birth.as_ref().map(|d| {
+                    DateAndPlaceOfBirth {
+                        date_of_birth: encode(&d.date_of_birth),
+                        place_of_birth: d.place_of_birth.to_string(),
+                    }
+                }),
+                country_of_residence: p.country_of_residence.as_ref().map(|c| c.as_str().into()),
+            }),
+            crate::Person::LegalPerson(p) => PersonKind::LegalPerson(LegalPerson {
+                name: Some(LegalPersonName {
+                    name_identifier: encode_all(p.name.name_identifier.iter(), Into::into),
+                    local_name_identifier: encode_all(
+                        p.name.local_name_identifier.iter(),
+                        Into::into,
+                    ),
+                    phonetic_name_identifier: encode_all(
+                        p.name.phonetic_name_identifier.iter(),
+                        Into::into,
+                    ),
+                }),
+                geographic_address: encode_all(p.geographic_address.iter(), Address::from),
+                customer_identification: encode_opt(&p.customer_identification),
+                national_identification: p.national_identification.as_ref().map(Into::into),
+                country_of_registration: p
+                    .country_of_registration
+                    .as_ref()
+                    .map(|c| c.as_str().into()),
+            }),
+        };
+        Self { kind: Some(kind) }
+    }
+}
+
+impl TryFrom<Person> for crate::Person {
+    type Error = Error;
+
+    fn try_from(person: Person) -> Result<Self, Error> {
+        match person.kind {
+            Some(PersonKind::NaturalPerson(p)) => p
+                .try_into()
+                .map(crate::Person::NaturalPerson)
+                .map_err(|e| e.at("naturalPerson")),
+            Some(PersonKind::LegalPerson(p)) => p
+                .try_into()
+                .map(crate::Person::LegalPerson)
+                .map_err(|e| e.at("legalPerson")),
+            None => Err(Error::ParseError(
+                "expected a natural or legal person".into(),
+            )),
+        }
+    }
+}
+
+impl TryFrom<NaturalPerson> for crate::NaturalPerson {
+    type Error = Error;
+
+    fn try_from(p: NaturalPerson) -> Result<Self, Error> {
+        Ok(Self {
+            name: decode_one_to_n("name", p.name, TryInto::try_into)?,
+            geographic_address: decode_zero_to_n(
+                "geographicAddress",
+                p.geographic_address,
+                TryInto::try_into,
+            )?,
+            national_identification: p
+                .national_identification
+                .map(TryInto::try_into)
+                .transpose()
+                .map_err(|e: Error| e.at("nationalIdentification"))?,
+            customer_identification: decode_opt(
+                "customerIdentification",
+                &p.customer_identification,
+            )?,
+            date_and_place_of_birth: p
+                .date_and_place_of_birth
+                .map(|d| {
+                    Ok::<_, Error>(crate::DateAndPlaceOfBirth {
+                        date_of_birth: decode("dateOfBirth", &d.date_of_birth)?,
+                        place_of_birth: decode("placeOfBirth", &d.place_of_birth)?,
+                    })
+                })
+                .transpose()
+                .map_err(|e| e.at("dateAndPlaceOfBirth"))?,
+            country_of_residence: decode_opt("countryOfResidence", &p.country_of_residence)?,
+        })
+    }
+}
+
+impl TryFrom<LegalPerson> for crate::LegalPerson {
+    type Error = Error;
+
+    fn try_from(p: LegalPerson) -> Result<Self, Error> {
+        let name = p
+            .name
+            .ok_or_else(|| Error::ParseError("missing field `name`".into()))?;
+        Ok(Self {
+            name: name.try_into().map_err(|e: Error| e.at("name"))?,
+            geographic_address: decode_zero_to_n(
+                "geographicAddress",
+                p.geographic_address,
+                TryInto::try_into,
+            )?,
+            customer_identification: decode_opt(
+                "customerIdentification",
+                &p.customer_identification,
+            )?,
+            national_identification: p
+                .national_identification
+                .map(TryInto::try_into)
+                .transpose()
+                .map_err(|e: Error| e.at("nationalIdentification"))?,
+            country_of_registration: decode_opt(
+                "countryOfRegistration",
+                &p.country_of_registration,
+            )?,
+        })
+    }
+}
+
+impl From<&crate::NaturalPersonName> for NaturalPersonName {
+    fn from(name: &crate::NaturalPersonName) -> Self {
+        Self {
+            name_identifier: encode_all(name.name_identifier.iter(), Into::into),
+            local_name_identifier: encode_all(name.local_name_identifier.iter(), Into::into),
+            phonetic_name_identifier: encode_all(name.phonetic_name_identifier.iter(), Into::into),
+        }
+    }
+}
+
+impl TryFrom<NaturalPersonName> for crate::NaturalPersonName {
+    type Error = Error;
+
+    fn try_from(name: NaturalPersonName) -> Result<Self, Error> {
+        Ok(Self {
+            name_identifier: decode_one_to_n(
+                "nameIdentifier",
+                name.name_identifier,
+                TryInto::try_into,
+            )?,
+            local_name_identifier: decode_zero_to_n(
+                "localNameIdentifier",
+                name.local_name_identifier,
+                TryInto::try_into,
+            )?,
+            phonetic_name_identifier: decode_zero_to_n(
+                "phoneticNameIdentifier",
+                name.phonetic_name_identifier,
+                TryInto::try_into,
+            )?,
+        })
+    }
+}
+
+impl TryFrom<LegalPersonName> for crate::LegalPersonName {
+    type Error = Error;
+
+    fn try_from(name: LegalPersonName) -> Result<Self, Error> {
+        Ok(Self {
+            name_identifier: decode_one_to_n(
+                "nameIdentifier",
+                name.name_identifier,
+                TryInto::try_into,
+            )?,
+            local_name_identifier: decode_zero_to_n(
+                "localNameIdentifier",
+                name.local_name_identifier,
+                TryInto::try_into,
+            )?,
+            phonetic_name_identifier: decode_zero_to_n(
+                "phoneticNameIdentifier",
+                name.phonetic_name_identifier,
+                TryInto::try_into,
+            )?,
+        })
+    }
+}
+
+impl From<&crate::NaturalPersonNameID> for NaturalPersonNameId {
+    fn from(id: &crate::NaturalPersonNameID) -> Self {
+        Self {
+            primary_identifier: id.primary_identifier.to_string(),
+            secondary_identifier: encode_opt(&id.secondary_identifier),
+            name_identifier_type: encode(&id.name_identifier_type),
+        }
+    }
+}
+
+impl TryFrom<NaturalPersonNameId> for crate::NaturalPersonNameID {
+    type Error = Error;
+
+    fn try_from(id: NaturalPersonNameId) -> Result<Self, Error> {
+        Ok(Self {
+            primary_identifier: decode("primaryIdentifier", &id.primary_identifier)?,
+            secondary_identifier: decode_opt("secondaryIdentifier", &id.secondary_identifier)?,
+            name_identifier_type: decode("nameIdentifierType", &id.name_identifier_type)?,
+        })
+    }
+}
+
+impl From<&crate::LegalPersonNameID> for LegalPersonNameId {
+    fn from(id: &crate::LegalPersonNameID) -> Self {
+        Self {
+            legal_person_name: id.legal_person_name.to_string(),
+            legal_person_name_identifier_type: encode(&id.legal_person_name_identifier_type),
+        }
+    }
+}
+
+impl TryFrom<LegalPersonNameId> for crate::LegalPersonNameID {
+    type Error = Error;
+
+    fn try_from(id: LegalPersonNameId) -> Result<Self, Error> {
+        Ok(Self {
+            legal_person_name: decode("legalPersonName", &id.legal_person_name)?,
+            legal_person_name_identifier_type: decode(
+                "legalPersonNameIdentifierType",
+                &id.legal_person_name_identifier_type,
+            )?,
+        })
+    }
+}
+
+impl From<&crate::Address> for Address {
+    fn from(address: &crate::Address) -> Self {
+        Self {
+            address_type: encode(&address.address_type),
+            department: encode_opt(&address.department),
+            sub_department: encode_opt(&address.sub_department),
+            street_name: encode_opt(&address.street_name),
+            building_number: encode_opt(&address.building_number),
+            building_name: encode_opt(&address.building_name),
+            floor: encode_opt(&address.floor),
+            post_box: encode_opt(&address.post_box),
+            room: encode_opt(&address.room),
+            post_code: encode_opt(&address.post_code),
+            town_name: address.town_name.to_string(),
+            town_location_name: encode_opt(&address.town_location_name),
+            district_name: encode_opt(&address.district_name),
+            country_sub_division: encode_opt(&address.country_sub_division),
+            address_line: encode_strings(&address.address_line),
+            country: address.country.as_str().into(),
+        }
+    }
+}
+
+impl TryFrom<Address> for crate::Address {
+    type Error = Error;
+
+    fn try_from(address: Address) -> Result<Self, Error> {
+        Ok(Self {
+            address_type: decode("addressType", &address.address_type)?,
+            department: decode_opt("department", &address.department)?,
+            sub_department: decode_opt("subDepartment", &address.sub_department)?,
+            street_name: decode_opt("streetName", &address.street_name)?,
+            building_number: decode_opt("buildingNumber", &address.building_number)?,
+            building_name: decode_opt("buildingName", &address.building_name)?,
+            floor: decode_opt("floor", &address.floor)?,
+            post_box: decode_opt("postBox", &address.post_box)?,
+            room: decode_opt("room", &address.room)?,
+            post_code: decode_opt("postCode", &address.post_code)?,
+            town_name: decode("townName", &address.town_name)?,
+            town_location_name: decode_opt("townLocationName", &address.town_location_name)?,
+            district_name: decode_opt("districtName", &address.district_name)?,
+            country_sub_division: decode_opt("countrySubDivision", &address.country_sub_division)?,
+            address_line: decode_zero_to_n("addressLine", address.address_line, |line| {
+                decode_str(&line)
+            })?,
+            country: decode("country", &address.country)?,
+        })
+    }
+}
+
+impl From<&crate::NationalIdentification> for NationalIdentification {
+    fn from(id: &crate::NationalIdentification) -> Self {
+        Self {
+            national_identifier: id.national_identifier.to_string(),
+            national_identifier_type: encode(&id.national_identifier_type),
+            country_of_issue: id.country_of_issue.as_ref().map(|c| c.as_str().into()),
+            registration_authority: id.registration_authority.as_ref().map(encode),
+        }
+    }
+}
+
+impl TryFrom<NationalIdentification> for crate::NationalIdentification {
+    type Error = Error;
+
+    fn try_from(id: NationalIdentification) -> Result<Self, Error> {
+        Ok(Self {
+            national_identifier: decode("nationalIdentifier", &id.national_identifier)?,
+            national_identifier_type: decode(
+                "nationalIdentifierType",
+                &id.national_identifier_type,
+            )?,
+            country_of_issue: decode_opt("countryOfIssue", &id.country_of_issue)?,
+            registration_authority: id
+                .registration_authority
+                .as_deref()
+                .map(crate::registration_authorities::parse)
+                .transpose()
+                .map_err(|e| e.at("registrationAuthority"))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+
+    #[test]
+    fn test_round_trip() {
+        let mut ivms = crate::IVMS101::mock();
+        let person = ivms.originator_person_mut();
+        person.date_and_place_of_birth = Some(crate::DateAndPlaceOfBirth::mock());
+        person.national_identification = Some(crate::NationalIdentification::mock());
+
+        let bytes = Ivms101::from(&ivms).encode_to_vec();
+        let decoded = Ivms101::decode(bytes.as_slice()).unwrap();
+        assert_eq!(crate::IVMS101::try_from(decoded).unwrap(), ivms);
+    }
+
+    #[test]
+    fn test_unknown_field() {
+        let message = Ivms101::from(&crate::IVMS101::mock());
+        let mut bytes = message.encode_to_vec();
+        // Field number 99 with wire type 2 (length-delimited), as a later
+        // version might add.
+        bytes.extend_from_slice(&[0x9a, 0x06, 3, b'n', b'e', b'w']);
+
+        let decoded = Ivms101::decode(bytes.as_slice()).unwrap();
+        assert_eq!(decoded, message);
+        let reencoded = Ivms101::decode(decoded.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(reencoded, message);
+    }
+
+    #[test]
+    fn test_invalid_message() {
+        let mut message = Ivms101::from(&crate::IVMS101::mock());
+        message
+            .originator
+            .as_mut()
+            .unwrap()
+            .originator_persons
+            .clear();
+        let err = crate::IVMS101::try_from(message.clone()).unwrap_err();
+        assert_eq!(err.path(), Some("originator.originatorPersons"));
+
+        let mut message = Ivms101::from(&crate::IVMS101::mock());
+        let Some(PersonKind::LegalPerson(person)) = message
+            .beneficiary
+            .as_mut()
+            .and_then(|b| b.beneficiary_persons[0].kind.as_mut())
+        else {
+            panic!("mock beneficiary is a legal person");
+        };
+        person.country_of_registration = Some("QQ".into());
+        let err = crate::IVMS101::try_from(message).unwrap_err();
+        assert_eq!(
+            err.path(),
+            Some("beneficiary.beneficiaryPersons[0].legalPerson.countryOfRegistration")
+        );
+
+        let mut message = Ivms101::from(&crate::IVMS101::mock());
+        let Some(PersonKind::NaturalPerson(person)) = message
+            .originator
+            .as_mut()
+            .and_then(|o| o.originator_persons[0].kind.as_mut())
+        else {
+            panic!("mock originator is a natural person");
+        };
+        person.geographic_address[0].address_line = vec!["Main street".into(), "x".repeat(71)];
+        let err = crate::IVMS101::try_from(message).unwrap_err();
+        assert_eq!(
+            err.path(),
+            Some(
+                "originator.originatorPersons[0].naturalPerson.geographicAddress[0].addressLine[1]"
+            )
+        );
+    }
+}